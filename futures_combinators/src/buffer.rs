@@ -0,0 +1,239 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use futures::Stream;
+use tokio::sync::Notify;
+
+
+/// What a full buffer does with the next item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Overflow {
+    /// The sender waits until the receiver takes an item.
+    #[default]
+    Block,
+    /// The item replaces the oldest buffered one.
+    DropOldest,
+    /// The item is discarded.
+    DropNewest,
+    /// The channel fails and the receiver gets `Overflowed` after the buffered items.
+    Error,
+}
+
+/// The buffer was full under `Overflow::Error`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflowed;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SendError<T> {
+    /// The receiver was dropped. Holds the unsent item.
+    Closed(T),
+    Overflowed,
+}
+
+struct State<T> {
+    queue: VecDeque<T>,
+    dropped: usize,
+    overflowed: bool,
+    /// The receiver has yielded `Overflowed`.
+    reported: bool,
+    sender_closed: bool,
+    receiver_closed: bool,
+    receiver: Option<Waker>,
+}
+
+struct Shared<T> {
+    state: Mutex<State<T>>,
+    /// Signalled when space frees up or the receiver goes away.
+    space: Notify,
+    capacity: usize,
+    overflow: Overflow,
+}
+
+impl<T> Shared<T> {
+    fn lock(&self) -> std::sync::MutexGuard<'_, State<T>> {
+        self.state.lock().expect("Buffer lock poisoned")
+    }
+}
+
+pub struct BoundedSender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+pub struct BoundedReceiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+/// A single producer, single consumer channel holding up to `capacity` items (at
+/// least one) and applying `overflow` when full.
+pub fn bounded<T>(capacity: usize, overflow: Overflow) -> (BoundedSender<T>, BoundedReceiver<T>) {
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            queue: VecDeque::new(),
+            dropped: 0,
+            overflowed: false,
+            reported: false,
+            sender_closed: false,
+            receiver_closed: false,
+            receiver: None,
+        }),
+        space: Notify::new(),
+        capacity: capacity.max(1),
+        overflow,
+    });
+    (BoundedSender { shared: shared.clone() }, BoundedReceiver { shared })
+}
+
+impl<T> BoundedSender<T> {
+    pub async fn send(&self, item: T) -> Result<(), SendError<T>> {
+        let mut item = Some(item);
+        loop {
+            {
+                let mut state = self.shared.lock();
+                if state.receiver_closed {
+                    return Err(SendError::Closed(item.take().expect("Item is sent once")))
+                }
+                if state.overflowed {
+                    return Err(SendError::Overflowed)
+                }
+
+                let full = state.queue.len() >= self.shared.capacity;
+                match self.shared.overflow {
+                    _ if !full => {}
+                    Overflow::Block => {}
+                    Overflow::DropOldest => {
+                        state.queue.pop_front();
+                        state.dropped += 1;
+                    }
+                    Overflow::DropNewest => {
+                        state.dropped += 1;
+                        return Ok(())
+                    }
+                    Overflow::Error => {
+                        state.overflowed = true;
+                        if let Some(waker) = state.receiver.take() {
+                            waker.wake();
+                        }
+                        return Err(SendError::Overflowed)
+                    }
+                }
+
+                if state.queue.len() < self.shared.capacity {
+                    state.queue.push_back(item.take().expect("Item is sent once"));
+                    if let Some(waker) = state.receiver.take() {
+                        waker.wake();
+                    }
+                    return Ok(())
+                }
+            }
+            self.shared.space.notified().await;
+        }
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.shared.lock().receiver_closed
+    }
+
+    /// Resolves once the receiver is dropped.
+    pub async fn closed(&self) {
+        while !self.is_closed() {
+            self.shared.space.notified().await;
+        }
+    }
+}
+
+impl<T> Drop for BoundedSender<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.sender_closed = true;
+        if let Some(waker) = state.receiver.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> BoundedReceiver<T> {
+    /// Items discarded so far by `DropOldest` or `DropNewest`.
+    pub fn dropped(&self) -> usize {
+        self.shared.lock().dropped
+    }
+}
+
+impl<T> Stream for BoundedReceiver<T> {
+    type Item = Result<T, Overflowed>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut state = self.shared.lock();
+        if let Some(item) = state.queue.pop_front() {
+            drop(state);
+            self.shared.space.notify_one();
+            return Poll::Ready(Some(Ok(item)))
+        }
+        if state.overflowed && !state.reported {
+            state.reported = true;
+            return Poll::Ready(Some(Err(Overflowed)))
+        }
+        if state.sender_closed || state.overflowed {
+            return Poll::Ready(None)
+        }
+        state.receiver = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl<T> Drop for BoundedReceiver<T> {
+    fn drop(&mut self) {
+        self.shared.lock().receiver_closed = true;
+        self.shared.space.notify_one();
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use tokio::time::{timeout, Duration};
+
+    use crate::buffer::{bounded, Overflow, Overflowed, SendError};
+
+    async fn fill(overflow: Overflow) -> (Vec<Result<u32, SendError<u32>>>, Vec<Result<u32, Overflowed>>, usize) {
+        let (tx, mut rx) = bounded(2, overflow);
+        let mut sent = Vec::new();
+        for i in 0..4 {
+            sent.push(tx.send(i).await.map(|()| i));
+        }
+        drop(tx);
+        let dropped = rx.dropped();
+        (sent, rx.by_ref().collect().await, dropped)
+    }
+
+    #[tokio::test]
+    async fn full_buffer_applies_overflow_policy() {
+        let (_, received, dropped) = fill(Overflow::DropOldest).await;
+        assert_eq!((received, dropped), (vec![Ok(2), Ok(3)], 2));
+
+        let (_, received, dropped) = fill(Overflow::DropNewest).await;
+        assert_eq!((received, dropped), (vec![Ok(0), Ok(1)], 2));
+
+        let (sent, received, _) = fill(Overflow::Error).await;
+        assert_eq!(sent, vec![Ok(0), Ok(1), Err(SendError::Overflowed), Err(SendError::Overflowed)]);
+        assert_eq!(received, vec![Ok(0), Ok(1), Err(Overflowed)]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn blocking_sender_waits_for_receiver() {
+        let (tx, mut rx) = bounded(1, Overflow::Block);
+        tx.send(1).await.unwrap();
+        assert!(timeout(Duration::from_secs(1), tx.send(2)).await.is_err());
+
+        assert_eq!(rx.next().await, Some(Ok(1)));
+        tx.send(3).await.unwrap();
+        drop(rx);
+        assert_eq!(tx.send(4).await, Err(SendError::Closed(4)));
+        tx.closed().await;
+    }
+}
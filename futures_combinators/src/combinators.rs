@@ -1,10 +1,12 @@
-use std::{cmp::{Ordering, Reverse}, collections::{BinaryHeap, HashMap, HashSet}, fmt::Debug, marker::PhantomData, pin::{pin, Pin}, task::Poll};
+use std::{cmp::{Ordering, Reverse}, collections::{BinaryHeap, HashMap}, fmt::Debug, marker::PhantomData, pin::Pin, task::Poll};
 
-use futures::{future::{Either, Select}, stream::{self, Peekable, PollNext}, FutureExt, Stream, StreamExt};
+use futures::{future::Either, stream::{BoxStream, Peekable}, FutureExt, Stream, StreamExt};
 use pin_project_lite::pin_project;
-use tokio::{sync::mpsc::{channel, Receiver}, time::{sleep, sleep_until, Sleep}, time::{Duration, Instant}};
+use tokio::{sync::mpsc::{channel, unbounded_channel, UnboundedReceiver, UnboundedSender, WeakUnboundedSender}, time::{sleep_until, Sleep}, time::{Duration, Instant}};
 use tokio_stream::wrappers::ReceiverStream;
 
+use crate::recurring::Series;
+
 
 
 
@@ -36,7 +38,7 @@ impl<T> Eq for Timed<T> {}
 
 impl<T> PartialOrd for Timed<T> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.timestamp.cmp(&other.timestamp))
+        Some(self.cmp(other))
     }
 }
 
@@ -78,7 +80,7 @@ impl<T> Eq for Event<T> {}
 
 impl<T> PartialOrd for Event<T> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.value.cmp(&other.value))
+        Some(self.cmp(other))
     }
 }
 
@@ -101,6 +103,7 @@ impl<F, X, Y, U> Traced<F, X, Y, U>
 where 
     F: Fn(X, U) -> (Y, U),
 {
+    #[allow(clippy::new_ret_no_self)]
     pub fn new(f: F, u_0: U) -> impl FnMut(X) -> Y {
         let mut traced = Traced {
             f,
@@ -121,7 +124,7 @@ where
 
         self.state = Some(new_state);
 
-        return y
+        y
     }
 }
 
@@ -228,11 +231,177 @@ where
 
 
 
+/// Merges any number of `Timed` streams in timestamp order. An item is held back
+/// until every live source has an item buffered, unless `lateness` is set, in which
+/// case it is let through once it is that old even if a source is still silent.
+/// Items older than one already emitted still come out, as soon as they are the
+/// oldest buffered.
+///
+/// Ties go to the source added first. The merge ends when every source has ended
+/// and no `TimedMergeHandle` is left to add more. The slot of an ended source is
+/// reused once its last item is out.
+pub struct TimedMerge<T> {
+    sources: Vec<Option<BoxStream<'static, Timed<T>>>>,
+    heads: Vec<Option<Timed<T>>>,
+    /// When each slot's source was added, for breaking ties.
+    ranks: Vec<u64>,
+    next_rank: u64,
+    order: BinaryHeap<Reverse<(Instant, u64, usize)>>,
+    lateness: Option<Duration>,
+    sleep: Option<Pin<Box<Sleep>>>,
+    added: Option<UnboundedReceiver<BoxStream<'static, Timed<T>>>>,
+    handle: Option<WeakUnboundedSender<BoxStream<'static, Timed<T>>>>,
+}
+
+/// Adds sources to a running `TimedMerge`.
+pub struct TimedMergeHandle<T> {
+    sender: UnboundedSender<BoxStream<'static, Timed<T>>>,
+}
+
+impl<T> Clone for TimedMergeHandle<T> {
+    fn clone(&self) -> Self {
+        TimedMergeHandle { sender: self.sender.clone() }
+    }
+}
+
+impl<T> TimedMergeHandle<T> {
+    /// Returns `false` if the merge is gone.
+    pub fn add(&self, source: impl Stream<Item = Timed<T>> + Send + 'static) -> bool {
+        self.sender.send(source.boxed()).is_ok()
+    }
+}
+
+pub fn merge_timed<T, S>(sources: impl IntoIterator<Item = S>, lateness: Option<Duration>) -> TimedMerge<T>
+where
+    S: Stream<Item = Timed<T>> + Send + 'static,
+{
+    let mut merge = TimedMerge {
+        sources: Vec::new(),
+        heads: Vec::new(),
+        ranks: Vec::new(),
+        next_rank: 0,
+        order: BinaryHeap::new(),
+        lateness,
+        sleep: None,
+        added: None,
+        handle: None,
+    };
+    for source in sources {
+        merge.push(source);
+    }
+    merge
+}
+
+impl<T> TimedMerge<T> {
+    pub fn push(&mut self, source: impl Stream<Item = Timed<T>> + Send + 'static) {
+        self.insert(source.boxed());
+    }
+
+    fn insert(&mut self, source: BoxStream<'static, Timed<T>>) {
+        let rank = self.next_rank;
+        self.next_rank += 1;
+        let free = (0..self.sources.len()).find(|&index| self.sources[index].is_none() && self.heads[index].is_none());
+        match free {
+            Some(index) => {
+                self.sources[index] = Some(source);
+                self.ranks[index] = rank;
+            }
+            None => {
+                self.sources.push(Some(source));
+                self.heads.push(None);
+                self.ranks.push(rank);
+            }
+        }
+    }
+
+    /// A handle for adding sources after the merge has moved, e.g. into a task.
+    pub fn handle(&mut self) -> TimedMergeHandle<T> {
+        if let Some(sender) = self.handle.as_ref().and_then(WeakUnboundedSender::upgrade) {
+            return TimedMergeHandle { sender }
+        }
+        let (sender, receiver) = unbounded_channel();
+        self.added = Some(receiver);
+        self.handle = Some(sender.downgrade());
+        TimedMergeHandle { sender }
+    }
+
+    fn live_sources(&self) -> usize {
+        self.sources.iter().filter(|source| source.is_some()).count()
+    }
+}
+
+// No field is pinned structurally: sources are boxed and items are only moved out.
+impl<T> Unpin for TimedMerge<T> {}
+
+impl<T> Stream for TimedMerge<T> {
+    type Item = Timed<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        loop {
+            while let Some(added) = this.added.as_mut() {
+                match added.poll_recv(cx) {
+                    Poll::Ready(Some(source)) => this.insert(source),
+                    Poll::Ready(None) => this.added = None,
+                    Poll::Pending => break,
+                }
+            }
+
+            let mut waiting = false;
+            for (index, slot) in this.sources.iter_mut().enumerate() {
+                let Some(source) = slot else { continue };
+                if this.heads[index].is_some() {
+                    continue
+                }
+                match source.poll_next_unpin(cx) {
+                    Poll::Ready(Some(item)) => {
+                        this.order.push(Reverse((item.timestamp, this.ranks[index], index)));
+                        this.heads[index] = Some(item);
+                    }
+                    Poll::Ready(None) => *slot = None,
+                    Poll::Pending => waiting = true,
+                }
+            }
+
+            let Some(&Reverse((timestamp, _, index))) = this.order.peek() else {
+                if this.live_sources() == 0 && this.added.is_none() {
+                    return Poll::Ready(None)
+                }
+                return Poll::Pending
+            };
+
+            let due = this.lateness.map(|lateness| timestamp + lateness);
+            if !waiting || due.is_some_and(|due| due <= Instant::now()) {
+                this.order.pop();
+                this.sleep = None;
+                return Poll::Ready(this.heads[index].take())
+            }
+
+            let Some(due) = due else { return Poll::Pending };
+            let sleep = this.sleep.get_or_insert_with(|| Box::pin(sleep_until(due)));
+            sleep.as_mut().reset(due);
+            match sleep.poll_unpin(cx) {
+                Poll::Ready(()) => continue,
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+
+
+
 
-type Id = usize;
+pub type Id = usize;
 
 pub enum ScheduleCommand<T> {
     Schedule(Event<T>),
+    /// Fires on every occurrence of the series. Replaces a series with the same id.
+    Recurring(Series<T>),
+    /// Drops every pending event with the id. Events scheduled after the cancel
+    /// are kept, so an id can be cancelled and re-armed. Also ends the series with
+    /// the id.
     Cancel(Id),
 }
 
@@ -242,7 +411,7 @@ pin_project! {
         St: Stream<Item = ScheduleCommand<T>>
     {
         heap: BinaryHeap<Reverse<Event<T>>>,
-        cancelled: HashSet<Id>,
+        series: HashMap<Id, Series<T>>,
         #[pin]
         inner: St,
         #[pin]
@@ -258,7 +427,7 @@ where
     pub fn new(inner: St) -> Self {
         Scheduler { 
             heap: BinaryHeap::new(), 
-            cancelled: HashSet::new(), 
+            series: HashMap::new(),
             inner,
             sleep: None,
         }
@@ -282,26 +451,41 @@ where
                     ScheduleCommand::Schedule(event) => {
                         this.heap.push(Reverse(event));
                     }
+                    ScheduleCommand::Recurring(mut series) => {
+                        if let Some(old) = this.series.remove(&series.id) {
+                            this.heap.retain(|Reverse(event)| event.id != old.id || event.value.timestamp != old.next);
+                        }
+                        if let Some(event) = series.advance() {
+                            this.heap.push(Reverse(event));
+                            this.series.insert(series.id, series);
+                        }
+                    }
                     ScheduleCommand::Cancel(id) => {
-                        this.cancelled.insert(id);
+                        this.heap.retain(|Reverse(event)| event.id != id);
+                        this.series.remove(&id);
                     }
                 }
             }
 
-            while let Some(Reverse(next_event)) = this.heap.peek() {
-                if this.cancelled.remove(&next_event.id) {
-                    this.heap.pop();
-                    continue
-                } 
+            if let Some(Reverse(next_event)) = this.heap.peek() {
                 let when = next_event.value.timestamp;
                 if when <= Instant::now() {
                     let Reverse(event) = this.heap.pop().unwrap();
+                    if let Some(series) = this.series.get_mut(&event.id)
+                        && series.next == event.value.timestamp
+                    {
+                        match series.advance() {
+                            Some(next) => this.heap.push(Reverse(next)),
+                            None => {
+                                this.series.remove(&event.id);
+                            }
+                        }
+                    }
                     return Poll::Ready(Some(event))
                 } else {
                     *this.sleep = Some(Box::pin(sleep_until(when)));
-                    break
                 }
-            };
+            }
 
 
             if let Some(mut sleep) = this.sleep.as_mut().as_pin_mut() {
@@ -396,7 +580,9 @@ mod tests {
     use futures::{future::Either, stream::{self, Iter, StreamExt}};
     use tokio::time::{sleep, timeout, Instant, Duration};
 
-    use crate::combinators::{merge_sort, Event, ScheduleCommand, Scheduler, Timed, StreamExtSplit};
+    use tokio_stream::wrappers::ReceiverStream;
+
+    use crate::combinators::{merge_sort, merge_timed, Event, ScheduleCommand, Scheduler, Timed, StreamExtSplit};
     
     #[tokio::test]
     async fn test_traced_scan() {
@@ -444,6 +630,67 @@ mod tests {
             assert!(out[i-1].timestamp <= out[i].timestamp);
         }
     }
+    #[tokio::test(start_paused = true)]
+    async fn timed_merge_orders_sources_added_at_runtime() {
+        let now = Instant::now();
+        let at = |ms| now + Duration::from_millis(ms);
+
+        let (late_tx, late_rx) = tokio::sync::mpsc::channel(4);
+        let mut merged = merge_timed([
+            stream::iter(vec![Timed::new("a", at(1)), Timed::new("d", at(4))]).boxed(),
+            stream::iter(vec![Timed::new("b", at(2))]).boxed(),
+        ], None);
+        let handle = merged.handle();
+        assert!(handle.add(ReceiverStream::new(late_rx)));
+        late_tx.send(Timed::new("c", at(3))).await.unwrap();
+        drop((late_tx, handle));
+
+        let values: Vec<_> = merged.map(|timed| timed.value).collect().await;
+        assert_eq!(values, vec!["a", "b", "c", "d"]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn timed_merge_reuses_ended_slots_and_keeps_ties_by_age() {
+        let now = Instant::now();
+        let at = |ms| now + Duration::from_millis(ms);
+
+        let mut merged = merge_timed([stream::iter(vec![Timed::new("a", at(1))]).boxed()], None);
+        let handle = merged.handle();
+        assert_eq!(merged.next().await.map(|timed| timed.value), Some("a"));
+
+        let (b_tx, b_rx) = tokio::sync::mpsc::channel(1);
+        handle.add(ReceiverStream::new(b_rx));
+        assert!(timeout(Duration::from_millis(1), merged.next()).await.is_err());
+
+        // Takes the slot of the first source, but still loses the tie to "b".
+        handle.add(stream::iter(vec![Timed::new("c", at(5))]));
+        b_tx.send(Timed::new("b", at(5))).await.unwrap();
+        drop((b_tx, handle));
+
+        let values: Vec<_> = merged.by_ref().map(|timed| timed.value).collect().await;
+        assert_eq!(values, vec!["b", "c"]);
+        assert_eq!(merged.sources.len(), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn timed_merge_lateness_unblocks_silent_source() {
+        let (_silent_tx, silent_rx) = tokio::sync::mpsc::channel(1);
+        let mut stalled = merge_timed([
+            stream::iter(vec![Timed::tag_now("a")]).boxed(),
+            ReceiverStream::new(silent_rx).boxed(),
+        ], None);
+        assert!(timeout(Duration::from_secs(60), stalled.next()).await.is_err());
+
+        let (_silent_tx, silent_rx) = tokio::sync::mpsc::channel(1);
+        let start = Instant::now();
+        let mut merged = merge_timed([
+            stream::iter(vec![Timed::tag_now("a")]).boxed(),
+            ReceiverStream::new(silent_rx).boxed(),
+        ], Some(Duration::from_secs(1)));
+        assert_eq!(merged.next().await.map(|timed| timed.value), Some("a"));
+        assert_eq!(start.elapsed(), Duration::from_secs(1));
+    }
+
     #[tokio::test]
     async fn scheduler_emits_events_in_order_and_respects_cancellation() {
         tokio::time::pause();
@@ -476,6 +723,21 @@ mod tests {
         }
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn cancelled_id_can_be_rearmed() {
+        let start = Instant::now();
+        let cmds = vec![
+            ScheduleCommand::Schedule(Event::event_in("ack", Duration::from_secs(5), 1)),
+            ScheduleCommand::Cancel(1),
+            ScheduleCommand::Schedule(Event::event_in("ack again", Duration::from_secs(8), 1)),
+        ];
+        let mut sched = Scheduler::new(stream::iter(cmds));
+
+        let fired = sched.next().await.unwrap();
+        assert_eq!((fired.value.value, fired.value.timestamp - start), ("ack again", Duration::from_secs(8)));
+        assert!(timeout(Duration::from_secs(60), sched.next()).await.is_err());
+    }
+
     #[tokio::test]
     async fn test_split_either() {
         // Create a stream of mixed Either<L, R> values
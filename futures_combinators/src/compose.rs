@@ -0,0 +1,377 @@
+//! Operators for building a protocol out of smaller `TimedAutomata`.
+//!
+//! Every composed automaton can arm timers with any id. A pair keeps its two
+//! children's timers apart by giving each child and id its own timer id, handed
+//! out in order of first use, so nesting composes and large ids cannot overflow.
+
+use std::{
+    collections::HashMap,
+    marker::PhantomData,
+    sync::{self, Arc},
+};
+
+use futures::{
+    future::{join, ready, BoxFuture, Ready},
+    lock::Mutex,
+};
+
+use crate::{InEvent, OutEvent, TimedAutomata, TimerId};
+
+
+const FIRST: TimerId = 0;
+const SECOND: TimerId = 1;
+
+/// The timer ids a pair has handed out. Every child id ever used keeps its entry.
+#[derive(Default)]
+struct TimerIds {
+    ids: HashMap<(TimerId, TimerId), TimerId>,
+    children: Vec<(TimerId, TimerId)>,
+}
+
+type SharedTimerIds = Arc<sync::Mutex<TimerIds>>;
+
+impl TimerIds {
+    fn namespace(&mut self, side: TimerId, id: TimerId) -> TimerId {
+        let children = &mut self.children;
+        *self.ids.entry((side, id)).or_insert_with(|| {
+            children.push((side, id));
+            children.len() - 1
+        })
+    }
+
+    /// Which child a namespaced timer belongs to, and its id there.
+    fn split(&self, id: TimerId) -> Option<(TimerId, TimerId)> {
+        self.children.get(id).copied()
+    }
+}
+
+fn namespaced<Out>(events: Vec<OutEvent<Out>>, side: TimerId, ids: &SharedTimerIds) -> Vec<OutEvent<Out>> {
+    let mut ids = ids.lock().expect("Timer ids lock poisoned");
+    events
+        .into_iter()
+        .map(|event| match event {
+            OutEvent::Timer(id, dur) => OutEvent::Timer(ids.namespace(side, id), dur),
+            OutEvent::CancelTimer(id) => OutEvent::CancelTimer(ids.namespace(side, id)),
+            output => output,
+        })
+        .collect()
+}
+
+fn split(ids: &SharedTimerIds, id: TimerId) -> Option<(TimerId, TimerId)> {
+    ids.lock().expect("Timer ids lock poisoned").split(id)
+}
+
+
+/// Feeds the outputs of `a` into `b` as inputs.
+pub struct Chain<A, B, Mid> {
+    a: A,
+    b: Arc<Mutex<B>>,
+    ids: SharedTimerIds,
+    _marker: PhantomData<fn() -> Mid>,
+}
+
+pub fn chain<A, B, Mid>(a: A, b: B) -> Chain<A, B, Mid> {
+    Chain { a, b: Arc::new(Mutex::new(b)), ids: SharedTimerIds::default(), _marker: PhantomData }
+}
+
+impl<In, Mid, Out, A, B> TimedAutomata<In, Out> for Chain<A, B, Mid>
+where
+    A: TimedAutomata<In, Mid>,
+    A::Fut: 'static,
+    B: TimedAutomata<Mid, Out> + Send + 'static,
+    B::Fut: 'static,
+    Mid: Send + 'static,
+    Out: Send + 'static,
+{
+    type Fut = BoxFuture<'static, Vec<OutEvent<Out>>>;
+
+    fn on_event(&mut self, event: InEvent<In>) -> Self::Fut {
+        let b = self.b.clone();
+        let ids = self.ids.clone();
+        let first = match event {
+            InEvent::Input(at, input) => self.a.on_event(InEvent::Input(at, input)),
+            InEvent::Timeout(at, id) => match split(&ids, id) {
+                Some((FIRST, id)) => self.a.on_event(InEvent::Timeout(at, id)),
+                Some((_, id)) => {
+                    return Box::pin(async move {
+                        let second = b.lock().await.on_event(InEvent::Timeout(at, id));
+                        namespaced(second.await, SECOND, &ids)
+                    })
+                }
+                None => return Box::pin(ready(Vec::new())),
+            },
+        };
+
+        Box::pin(async move {
+            let mut out_events = Vec::new();
+            for event in first.await {
+                match event {
+                    OutEvent::Output(at, mid) => {
+                        let second = b.lock().await.on_event(InEvent::Input(at, mid));
+                        out_events.extend(namespaced(second.await, SECOND, &ids));
+                    }
+                    OutEvent::Timer(id, dur) => {
+                        out_events.push(OutEvent::Timer(ids.lock().expect("Timer ids lock poisoned").namespace(FIRST, id), dur));
+                    }
+                    OutEvent::CancelTimer(id) => {
+                        out_events.push(OutEvent::CancelTimer(ids.lock().expect("Timer ids lock poisoned").namespace(FIRST, id)));
+                    }
+                }
+            }
+            out_events
+        })
+    }
+}
+
+
+/// Gives every input to both `a` and `b` and merges their outputs, `a`'s first.
+pub struct Parallel<A, B> {
+    a: A,
+    b: B,
+    ids: SharedTimerIds,
+}
+
+pub fn parallel<A, B>(a: A, b: B) -> Parallel<A, B> {
+    Parallel { a, b, ids: SharedTimerIds::default() }
+}
+
+impl<In, Out, A, B> TimedAutomata<In, Out> for Parallel<A, B>
+where
+    In: Clone,
+    A: TimedAutomata<In, Out>,
+    A::Fut: 'static,
+    B: TimedAutomata<In, Out>,
+    B::Fut: 'static,
+    Out: Send + 'static,
+{
+    type Fut = BoxFuture<'static, Vec<OutEvent<Out>>>;
+
+    fn on_event(&mut self, event: InEvent<In>) -> Self::Fut {
+        let ids = self.ids.clone();
+        match event {
+            InEvent::Input(at, input) => {
+                let a = self.a.on_event(InEvent::Input(at, input.clone()));
+                let b = self.b.on_event(InEvent::Input(at, input));
+                Box::pin(async move {
+                    let (a, b) = join(a, b).await;
+                    let mut out_events = namespaced(a, FIRST, &ids);
+                    out_events.extend(namespaced(b, SECOND, &ids));
+                    out_events
+                })
+            }
+            InEvent::Timeout(at, id) => match split(&ids, id) {
+                Some((FIRST, id)) => {
+                    let a = self.a.on_event(InEvent::Timeout(at, id));
+                    Box::pin(async move { namespaced(a.await, FIRST, &ids) })
+                }
+                Some((_, id)) => {
+                    let b = self.b.on_event(InEvent::Timeout(at, id));
+                    Box::pin(async move { namespaced(b.await, SECOND, &ids) })
+                }
+                None => Box::pin(ready(Vec::new())),
+            },
+        }
+    }
+}
+
+
+/// Converts inputs before `inner` sees them.
+pub struct MapIn<A, F, X> {
+    inner: A,
+    f: F,
+    _marker: PhantomData<fn(X)>,
+}
+
+pub fn map_in<A, F, X>(inner: A, f: F) -> MapIn<A, F, X> {
+    MapIn { inner, f, _marker: PhantomData }
+}
+
+impl<X, In, Out, A, F> TimedAutomata<X, Out> for MapIn<A, F, In>
+where
+    A: TimedAutomata<In, Out>,
+    F: FnMut(X) -> In,
+{
+    type Fut = A::Fut;
+
+    fn on_event(&mut self, event: InEvent<X>) -> Self::Fut {
+        match event {
+            InEvent::Input(at, input) => self.inner.on_event(InEvent::Input(at, (self.f)(input))),
+            InEvent::Timeout(at, id) => self.inner.on_event(InEvent::Timeout(at, id)),
+        }
+    }
+}
+
+
+/// Converts the outputs of `inner`.
+pub struct MapOut<A, F, Out> {
+    inner: A,
+    f: Arc<F>,
+    _marker: PhantomData<fn() -> Out>,
+}
+
+pub fn map_out<A, F, Out>(inner: A, f: F) -> MapOut<A, F, Out> {
+    MapOut { inner, f: Arc::new(f), _marker: PhantomData }
+}
+
+impl<In, Out, Y, A, F> TimedAutomata<In, Y> for MapOut<A, F, Out>
+where
+    A: TimedAutomata<In, Out>,
+    A::Fut: 'static,
+    F: Fn(Out) -> Y + Send + Sync + 'static,
+    Y: Send + 'static,
+{
+    type Fut = BoxFuture<'static, Vec<OutEvent<Y>>>;
+
+    fn on_event(&mut self, event: InEvent<In>) -> Self::Fut {
+        let inner = self.inner.on_event(event);
+        let f = self.f.clone();
+        Box::pin(async move {
+            inner
+                .await
+                .into_iter()
+                .map(|event| match event {
+                    OutEvent::Output(at, out) => OutEvent::Output(at, f(out)),
+                    OutEvent::Timer(id, dur) => OutEvent::Timer(id, dur),
+                    OutEvent::CancelTimer(id) => OutEvent::CancelTimer(id),
+                })
+                .collect()
+        })
+    }
+}
+
+
+/// An automaton from a function threading its state through each event, the
+/// automata version of `Traced`.
+pub struct WithState<S, F> {
+    f: F,
+    state: Option<S>,
+}
+
+pub fn with_state<S, F>(initial: S, f: F) -> WithState<S, F> {
+    WithState { f, state: Some(initial) }
+}
+
+impl<In, Out, S, F> TimedAutomata<In, Out> for WithState<S, F>
+where
+    F: Fn(InEvent<In>, S) -> (Vec<OutEvent<Out>>, S),
+    Out: Send,
+{
+    type Fut = Ready<Vec<OutEvent<Out>>>;
+
+    fn on_event(&mut self, event: InEvent<In>) -> Self::Fut {
+        let state = self.state.take().expect("Must have state");
+        let (out_events, state) = (self.f)(event, state);
+        self.state = Some(state);
+        ready(out_events)
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use tokio::time::{Duration, Instant};
+
+    use crate::{
+        compose::{chain, map_in, map_out, parallel, with_state},
+        simulation::Simulation,
+        InEvent, OutEvent, TimedAutomata,
+    };
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Gateway {
+        Hello,
+        Ready,
+    }
+
+    /// Each of these uses timer 0, which the composition must keep apart.
+    fn heartbeat() -> impl TimedAutomata<Gateway, &'static str> {
+        with_state((), |event, ()| {
+            let out_events = match event {
+                InEvent::Input(_, Gateway::Hello) => vec![OutEvent::Timer(0, Duration::from_secs(10))],
+                InEvent::Input(_, Gateway::Ready) => vec![],
+                InEvent::Timeout(at, _) => vec![OutEvent::Output(at, "heartbeat"), OutEvent::Timer(0, Duration::from_secs(10))],
+            };
+            (out_events, ())
+        })
+    }
+
+    fn identify() -> impl TimedAutomata<Gateway, &'static str> {
+        with_state((), |event, ()| {
+            let out_events = match event {
+                InEvent::Input(at, Gateway::Hello) | InEvent::Timeout(at, _) => {
+                    vec![OutEvent::Output(at, "identify"), OutEvent::Timer(0, Duration::from_secs(3))]
+                }
+                InEvent::Input(_, Gateway::Ready) => vec![OutEvent::CancelTimer(0)],
+            };
+            (out_events, ())
+        })
+    }
+
+    /// Lets one frame through per 15 seconds and queues the rest.
+    fn rate_limit() -> impl TimedAutomata<&'static str, &'static str> {
+        with_state((false, VecDeque::new()), |event, (mut limited, mut queue): (bool, VecDeque<&'static str>)| {
+            let at = match event {
+                InEvent::Input(at, frame) => {
+                    queue.push_back(frame);
+                    at
+                }
+                InEvent::Timeout(at, _) => {
+                    limited = false;
+                    at
+                }
+            };
+            let mut out_events = Vec::new();
+            if !limited && let Some(frame) = queue.pop_front() {
+                out_events = vec![OutEvent::Output(at, frame), OutEvent::Timer(0, Duration::from_secs(15))];
+                limited = true;
+            }
+            (out_events, (limited, queue))
+        })
+    }
+
+    #[test]
+    fn stacked_gateway_keeps_timers_apart() {
+        let t0 = Instant::now();
+        let secs = |s| t0 + Duration::from_secs(s);
+
+        let gateway = chain(parallel(heartbeat(), identify()), rate_limit());
+        let trace = Simulation::new(gateway, t0).run([(secs(0), Gateway::Hello), (secs(1), Gateway::Ready)], secs(35));
+        assert_eq!(trace, vec![(secs(0), "identify"), (secs(15), "heartbeat"), (secs(30), "heartbeat")]);
+    }
+
+    #[test]
+    fn nested_pairs_accept_any_timer_id() {
+        let t0 = Instant::now();
+        let secs = |s| t0 + Duration::from_secs(s);
+        let alarm = |name: &'static str, after: u64| with_state((), move |event, ()| {
+            let out_events = match event {
+                InEvent::Input(_, _) => vec![OutEvent::Timer(usize::MAX, Duration::from_secs(after))],
+                InEvent::Timeout(at, id) => vec![OutEvent::Output(at, (name, id))],
+            };
+            (out_events, ())
+        });
+
+        let nested = parallel(parallel(alarm("a", 1), alarm("b", 2)), parallel(alarm("c", 3), alarm("d", 4)));
+        let trace = Simulation::new(nested, t0).run([(secs(0), Gateway::Hello)], secs(10));
+        assert_eq!(trace, vec![
+            (secs(1), ("a", usize::MAX)),
+            (secs(2), ("b", usize::MAX)),
+            (secs(3), ("c", usize::MAX)),
+            (secs(4), ("d", usize::MAX)),
+        ]);
+    }
+
+    #[test]
+    fn adapters_convert_inputs_and_outputs() {
+        let t0 = Instant::now();
+        let secs = |s| t0 + Duration::from_secs(s);
+
+        let ready = |text: &str| if text == "READY" { Gateway::Ready } else { Gateway::Hello };
+        let identify = map_out(map_in(identify(), ready), str::len);
+        let trace = Simulation::new(identify, t0).run([(secs(0), "HELLO"), (secs(4), "READY")], secs(10));
+        assert_eq!(trace, vec![(secs(0), 8), (secs(3), 8)]);
+    }
+}
@@ -0,0 +1,355 @@
+//! A `Scheduler` whose events survive restarts.
+//!
+//! Every command is appended to a journal of JSON lines before the scheduler sees
+//! it, and every fired event is marked done before it is yielded. Opening the
+//! journal replays what was still pending, then rewrites the file with just those
+//! events. Writes while running go through `spawn_blocking`; opening reads and
+//! compacts the file on the calling thread.
+
+use std::{
+    collections::BTreeMap,
+    fs::{self, File, OpenOptions},
+    future::Future,
+    io::{self, BufRead, BufReader, Write},
+    marker::PhantomData,
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::Arc,
+    task::{ready, Context, Poll},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use futures::{Stream, StreamExt};
+use pin_project_lite::pin_project;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use tokio::{
+    sync::mpsc::{unbounded_channel, UnboundedSender},
+    task::{spawn_blocking, JoinHandle},
+    time::{Duration, Instant},
+};
+use tokio_stream::wrappers::UnboundedReceiverStream;
+
+use crate::combinators::{Event, Id, ScheduleCommand, Scheduler};
+
+
+/// What happens to an event whose time passed while the process was down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Misfire {
+    #[default]
+    FireNow,
+    Skip,
+}
+
+pub enum DurableCommand<T> {
+    Schedule(Event<T>, Misfire),
+    Cancel(Id),
+}
+
+/// Recurring series are not journaled and come back as the error.
+impl<T> TryFrom<ScheduleCommand<T>> for DurableCommand<T> {
+    type Error = ScheduleCommand<T>;
+
+    fn try_from(command: ScheduleCommand<T>) -> Result<Self, Self::Error> {
+        match command {
+            ScheduleCommand::Schedule(event) => Ok(DurableCommand::Schedule(event, Misfire::default())),
+            ScheduleCommand::Cancel(id) => Ok(DurableCommand::Cancel(id)),
+            series @ ScheduleCommand::Recurring(_) => Err(series),
+        }
+    }
+}
+
+/// One journal line. `seq` numbers scheduled events across the whole journal, since
+/// ids can be re-armed.
+#[derive(Serialize, Deserialize)]
+enum Record<T> {
+    Schedule { seq: u64, id: Id, due_ms: u64, misfire: Misfire, value: T },
+    Cancel { id: Id },
+    Fired { seq: u64 },
+}
+
+struct Pending<T> {
+    id: Id,
+    due_ms: u64,
+    misfire: Misfire,
+    value: T,
+}
+
+fn unix_ms(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64
+}
+
+/// Wall clock time of `when`, as seen from `now`.
+fn wall_ms(when: Instant, now: SystemTime) -> u64 {
+    let now_ms = unix_ms(now);
+    let instant_now = Instant::now();
+    if when >= instant_now {
+        now_ms + (when - instant_now).as_millis() as u64
+    } else {
+        now_ms.saturating_sub((instant_now - when).as_millis() as u64)
+    }
+}
+
+
+struct Journal<T> {
+    file: Arc<File>,
+    next_seq: u64,
+    _marker: PhantomData<fn(T)>,
+}
+
+impl<T: Serialize + DeserializeOwned> Journal<T> {
+    /// Reads the pending events, by `seq`, and compacts the file down to them. A
+    /// torn last line, from a crash mid write, is dropped.
+    fn open(path: &Path) -> io::Result<(Self, BTreeMap<u64, Pending<T>>)> {
+        let mut pending = BTreeMap::new();
+        let mut next_seq = 0;
+
+        if path.exists() {
+            let lines: Vec<String> = BufReader::new(File::open(path)?).lines().collect::<io::Result<_>>()?;
+            for (index, line) in lines.iter().enumerate() {
+                let record = match serde_json::from_str(line) {
+                    Ok(record) => record,
+                    Err(_) if index + 1 == lines.len() => break,
+                    Err(e) => return Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+                };
+                match record {
+                    Record::Schedule { seq, id, due_ms, misfire, value } => {
+                        next_seq = next_seq.max(seq + 1);
+                        pending.insert(seq, Pending { id, due_ms, misfire, value });
+                    }
+                    Record::Cancel { id } => pending.retain(|_, event| event.id != id),
+                    Record::Fired { seq } => {
+                        pending.remove(&seq);
+                    }
+                }
+            }
+        }
+
+        let compacted = PathBuf::from(format!("{}.tmp", path.display()));
+        let mut file = File::create(&compacted)?;
+        for (&seq, event) in &pending {
+            let record = Record::Schedule { seq, id: event.id, due_ms: event.due_ms, misfire: event.misfire, value: &event.value };
+            writeln!(file, "{}", serde_json::to_string(&record)?)?;
+        }
+        file.sync_all()?;
+        fs::rename(&compacted, path)?;
+
+        let file = Arc::new(OpenOptions::new().append(true).open(path)?);
+        Ok((Journal { file, next_seq, _marker: PhantomData }, pending))
+    }
+}
+
+fn line<V: Serialize>(record: &Record<V>) -> io::Result<Vec<u8>> {
+    let mut line = serde_json::to_vec(record)?;
+    line.push(b'\n');
+    Ok(line)
+}
+
+fn write_line(mut file: &File, line: &[u8]) -> io::Result<()> {
+    file.write_all(line)?;
+    file.sync_data()
+}
+
+impl<T> Journal<T> {
+    fn append<V: Serialize>(&mut self, record: &Record<V>) -> io::Result<()> {
+        write_line(&self.file, &line(record)?)
+    }
+
+    /// Appends `record` on the blocking pool.
+    fn append_blocking<V: Serialize>(&self, record: &Record<V>) -> io::Result<JoinHandle<io::Result<()>>> {
+        let line = line(record)?;
+        let file = self.file.clone();
+        Ok(spawn_blocking(move || write_line(&file, &line)))
+    }
+}
+
+/// What is released once the journal write in flight has finished.
+enum Then<T> {
+    Schedule(ScheduleCommand<(u64, T)>),
+    Yield(Event<T>),
+}
+
+struct PendingWrite<T> {
+    task: JoinHandle<io::Result<()>>,
+    then: Then<T>,
+}
+
+
+pin_project! {
+    /// A `Scheduler` journaling to a file. Yields an error when the journal cannot
+    /// be written, after which the failed command or event may not be durable.
+    pub struct DurableScheduler<St, T> {
+        #[pin]
+        commands: St,
+        scheduler: Scheduler<UnboundedReceiverStream<ScheduleCommand<(u64, T)>>, (u64, T)>,
+        tx: UnboundedSender<ScheduleCommand<(u64, T)>>,
+        journal: Journal<T>,
+        writing: Option<PendingWrite<T>>,
+    }
+}
+
+impl<St, T> DurableScheduler<St, T>
+where
+    St: Stream<Item = DurableCommand<T>>,
+    T: Serialize + DeserializeOwned,
+{
+    /// Opens or creates the journal at `path` and schedules its pending events.
+    pub fn open(path: impl AsRef<Path>, commands: St) -> io::Result<Self> {
+        Self::open_at(path.as_ref(), commands, SystemTime::now())
+    }
+
+    fn open_at(path: &Path, commands: St, now: SystemTime) -> io::Result<Self> {
+        let (mut journal, pending) = Journal::open(path)?;
+        let (tx, rx) = unbounded_channel();
+        let now_ms = unix_ms(now);
+
+        for (seq, event) in pending {
+            let when = if event.due_ms > now_ms {
+                Instant::now() + Duration::from_millis(event.due_ms - now_ms)
+            } else if event.misfire == Misfire::FireNow {
+                Instant::now()
+            } else {
+                journal.append(&Record::<T>::Fired { seq })?;
+                continue
+            };
+            let _ = tx.send(ScheduleCommand::Schedule(Event::new((seq, event.value), when, event.id)));
+        }
+
+        Ok(DurableScheduler {
+            commands,
+            scheduler: Scheduler::new(UnboundedReceiverStream::new(rx)),
+            tx,
+            journal,
+            writing: None,
+        })
+    }
+}
+
+impl<St, T> Stream for DurableScheduler<St, T>
+where
+    St: Stream<Item = DurableCommand<T>>,
+    T: Serialize,
+{
+    type Item = io::Result<Event<T>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            // One write at a time, so records land in the order they were made.
+            if let Some(write) = this.writing.as_mut() {
+                let written = ready!(Pin::new(&mut write.task).poll(cx)).unwrap_or_else(|e| Err(io::Error::other(e)));
+                let then = this.writing.take().map(|write| write.then);
+                match (written, then) {
+                    (Err(e), _) => return Poll::Ready(Some(Err(e))),
+                    (Ok(()), Some(Then::Schedule(command))) => {
+                        let _ = this.tx.send(command);
+                    }
+                    (Ok(()), Some(Then::Yield(event))) => return Poll::Ready(Some(Ok(event))),
+                    (Ok(()), None) => {}
+                }
+            }
+
+            if let Poll::Ready(Some(command)) = this.commands.as_mut().poll_next(cx) {
+                let write = match command {
+                    DurableCommand::Schedule(event, misfire) => {
+                        let seq = this.journal.next_seq;
+                        this.journal.next_seq += 1;
+                        let due_ms = wall_ms(event.value.timestamp, SystemTime::now());
+                        let record = Record::Schedule { seq, id: event.id, due_ms, misfire, value: &event.value.value };
+                        this.journal.append_blocking(&record).map(|task| PendingWrite {
+                            task,
+                            then: Then::Schedule(ScheduleCommand::Schedule(Event::new((seq, event.value.value), event.value.timestamp, event.id))),
+                        })
+                    }
+                    DurableCommand::Cancel(id) => this.journal.append_blocking(&Record::<T>::Cancel { id }).map(|task| PendingWrite {
+                        task,
+                        then: Then::Schedule(ScheduleCommand::Cancel(id)),
+                    }),
+                };
+                match write {
+                    Ok(write) => *this.writing = Some(write),
+                    Err(e) => return Poll::Ready(Some(Err(e))),
+                }
+                continue
+            }
+
+            match this.scheduler.poll_next_unpin(cx) {
+                Poll::Ready(Some(Event { value, id })) => {
+                    let (seq, inner) = value.value;
+                    match this.journal.append_blocking(&Record::<T>::Fired { seq }) {
+                        Ok(task) => *this.writing = Some(PendingWrite { task, then: Then::Yield(Event::new(inner, value.timestamp, id)) }),
+                        Err(e) => return Poll::Ready(Some(Err(e))),
+                    }
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::{path::PathBuf, time::SystemTime};
+
+    use futures::StreamExt;
+    use tokio::{
+        sync::mpsc::unbounded_channel,
+        time::{timeout, Duration},
+    };
+    use tokio_stream::wrappers::UnboundedReceiverStream;
+
+    use crate::{
+        combinators::Event,
+        durable::{DurableCommand, DurableScheduler, Misfire},
+    };
+
+    fn journal(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("futures-combinators-{name}-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn pending_events_survive_restart() {
+        let path = journal("restart");
+        let (tx, rx) = unbounded_channel();
+        let mut scheduler = DurableScheduler::open(&path, UnboundedReceiverStream::new(rx)).unwrap();
+        tx.send(DurableCommand::Schedule(Event::event_in("unban".to_string(), Duration::from_secs(1), 1), Misfire::FireNow)).unwrap();
+        tx.send(DurableCommand::Schedule(Event::event_in("reminder".to_string(), Duration::from_secs(3600), 2), Misfire::FireNow)).unwrap();
+        tx.send(DurableCommand::Schedule(Event::event_in("cancelled".to_string(), Duration::from_secs(60), 3), Misfire::FireNow)).unwrap();
+        tx.send(DurableCommand::Cancel(3)).unwrap();
+
+        let fired = scheduler.next().await.unwrap().unwrap();
+        assert_eq!((fired.value.value.as_str(), fired.id), ("unban", 1));
+        drop(scheduler);
+
+        let (_tx, rx) = unbounded_channel::<DurableCommand<String>>();
+        let mut scheduler = DurableScheduler::open(&path, UnboundedReceiverStream::new(rx)).unwrap();
+        assert!(timeout(Duration::from_secs(3000), scheduler.next()).await.is_err());
+        let fired = scheduler.next().await.unwrap().unwrap();
+        assert_eq!((fired.value.value.as_str(), fired.id), ("reminder", 2));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn missed_events_follow_misfire_policy() {
+        let path = journal("misfire");
+        let (tx, rx) = unbounded_channel();
+        let scheduler = DurableScheduler::open(&path, UnboundedReceiverStream::new(rx)).unwrap();
+        tx.send(DurableCommand::Schedule(Event::event_in(1, Duration::from_secs(10), 1), Misfire::Skip)).unwrap();
+        tx.send(DurableCommand::Schedule(Event::event_in(2, Duration::from_secs(20), 2), Misfire::FireNow)).unwrap();
+        tx.send(DurableCommand::Schedule(Event::event_in(3, Duration::from_secs(600), 3), Misfire::Skip)).unwrap();
+        // Journals the commands without firing anything.
+        assert!(timeout(Duration::from_millis(1), scheduler.take(1).next()).await.is_err());
+
+        let (_tx, rx) = unbounded_channel::<DurableCommand<u32>>();
+        let restart = SystemTime::now() + Duration::from_secs(60);
+        let scheduler = DurableScheduler::open_at(&path, UnboundedReceiverStream::new(rx), restart).unwrap();
+        let fired: Vec<_> = scheduler.take(2).map(|event| event.unwrap().value.value).collect().await;
+        assert_eq!(fired, vec![2, 3]);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
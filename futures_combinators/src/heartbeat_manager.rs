@@ -5,7 +5,7 @@ use pin_project_lite::pin_project;
 use tokio::time::{interval, Interval};
 
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct Heartbeat {}
 
 #[derive(Debug)]
@@ -71,16 +71,16 @@ where
         }
 
         match this.interval.poll_tick(cx) {
-            Poll::Pending => return Poll::Pending,
+            Poll::Pending => Poll::Pending,
             Poll::Ready(_instant) => {
                 if !*this.ack_received {
-                    return Poll::Ready(Some(Err(HeartbeatError {})))
+                    Poll::Ready(Some(Err(HeartbeatError {})))
                 } else {
                     *this.ack_received = false;
-                    return Poll::Ready(Some(Ok(Heartbeat {})))
+                    Poll::Ready(Some(Ok(Heartbeat {})))
                 }
             }
-        };
+        }
     }
 }
 
@@ -88,7 +88,7 @@ where
 mod tests {
     use super::*;
     use futures::StreamExt;
-    use tokio::time::{advance, sleep, Duration};
+    use tokio::time::{advance, Duration};
     use tokio_stream::wrappers::ReceiverStream;
 
     #[tokio::test(start_paused = true)] // <-- IMPORTANT: we control time
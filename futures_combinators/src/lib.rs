@@ -1,24 +1,43 @@
-use std::{cmp::Reverse, collections::BinaryHeap, };
+use std::{collections::HashSet, pin::Pin, task::{Context, Poll}};
 
-use futures::{future::pending, Stream, StreamExt};
+use futures::{future::ready, Sink, SinkExt, Stream, StreamExt};
 
-use tokio::time::{Duration, Instant};
-use tokio_stream::wrappers::ReceiverStream;
+use tokio::{sync::mpsc::{self, UnboundedSender}, time::{Duration, Instant}};
+use tokio_stream::wrappers::{ReceiverStream, UnboundedReceiverStream};
+use tokio_util::sync::{PollSendError, PollSender};
 
+use crate::{
+    buffer::{bounded, BoundedReceiver, Overflow},
+    combinators::{Event, ScheduleCommand, Scheduler},
+};
 
+
+pub mod buffer;
 pub mod combinators;
+pub mod compose;
+pub mod durable;
 pub mod heartbeat_manager;
 pub mod reconnect_manager;
+pub mod recurring;
+pub mod simulation;
+pub mod windows;
 
 
+pub type TimerId = combinators::Id;
 
 pub enum OutEvent<Out> {
     Output(
         Instant,
         Out,
     ),
+    /// Arms the timer, replacing a pending one with the same id.
     Timer(
-        Duration
+        TimerId,
+        Duration,
+    ),
+    /// Disarms the timer. Cancelling a timer that is not pending does nothing.
+    CancelTimer(
+        TimerId
     ),
 }
 
@@ -27,8 +46,10 @@ pub enum InEvent<In> {
         Instant,
         In,
     ),
+    /// A timer fired. The instant is its deadline.
     Timeout(
-        Instant
+        Instant,
+        TimerId,
     )
 }
 
@@ -49,92 +70,397 @@ pub enum Sided<A, B> {
     B(B),
 }
 
+/// A protocol sitting between an application on side A and a transport on side B.
 pub trait BidirectionalTimedAutomata<InA, InB, OutA, OutB>: TimedAutomata<Sided<InA, InB>, Sided<OutA, OutB>> {}
 
+impl<P, InA, InB, OutA, OutB> BidirectionalTimedAutomata<InA, InB, OutA, OutB> for P
+where
+    P: TimedAutomata<Sided<InA, InB>, Sided<OutA, OutB>>,
+{}
+
+
+/// One end of a bounded duplex channel, a `Sink` of what it sends and a `Stream` of
+/// what it receives.
+pub struct Endpoint<In, Out> {
+    sink: PollSender<In>,
+    stream: ReceiverStream<Out>,
+}
+
+/// Two connected endpoints, each direction buffering `buffer` items.
+pub fn duplex<A, B>(buffer: usize) -> (Endpoint<A, B>, Endpoint<B, A>)
+where
+    A: Send + 'static,
+    B: Send + 'static,
+{
+    let (a_tx, a_rx) = mpsc::channel(buffer);
+    let (b_tx, b_rx) = mpsc::channel(buffer);
+    (
+        Endpoint { sink: PollSender::new(a_tx), stream: ReceiverStream::new(b_rx) },
+        Endpoint { sink: PollSender::new(b_tx), stream: ReceiverStream::new(a_rx) },
+    )
+}
+
+impl<In: Send, Out> Sink<In> for Endpoint<In, Out> {
+    type Error = PollSendError<In>;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.sink.poll_ready_unpin(cx)
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: In) -> Result<(), Self::Error> {
+        self.sink.start_send_unpin(item)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.sink.poll_flush_unpin(cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.sink.poll_close_unpin(cx)
+    }
+}
+
+impl<In, Out> Stream for Endpoint<In, Out> {
+    type Item = Out;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.stream.poll_next_unpin(cx)
+    }
+}
+
+
+/// A protocol's timers, kept in a `Scheduler`.
+struct Timers {
+    commands: UnboundedSender<ScheduleCommand<TimerId>>,
+    scheduler: Scheduler<UnboundedReceiverStream<ScheduleCommand<TimerId>>, TimerId>,
+    armed: HashSet<TimerId>,
+}
+
+impl Timers {
+    fn new() -> Self {
+        let (commands, rx) = mpsc::unbounded_channel();
+        Timers { commands, scheduler: Scheduler::new(UnboundedReceiverStream::new(rx)), armed: HashSet::new() }
+    }
+
+    fn arm(&mut self, id: TimerId, dur: Duration) {
+        self.armed.insert(id);
+        let _ = self.commands.send(ScheduleCommand::Cancel(id));
+        let _ = self.commands.send(ScheduleCommand::Schedule(Event::event_in(id, dur, id)));
+    }
+
+    fn cancel(&mut self, id: TimerId) {
+        self.armed.remove(&id);
+        let _ = self.commands.send(ScheduleCommand::Cancel(id));
+    }
+
+    fn is_empty(&self) -> bool {
+        self.armed.is_empty()
+    }
+
+    /// Deadline and id of the next timer to fire.
+    async fn next(&mut self) -> (Instant, TimerId) {
+        let fired = self.scheduler.next().await.expect("Scheduler never ends");
+        self.armed.remove(&fired.id);
+        (fired.value.timestamp, fired.id)
+    }
+}
+
+
+/// How `Runtime::run_buffered` holds outputs the consumer has not taken yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutputBuffer {
+    pub capacity: usize,
+    pub overflow: Overflow,
+}
+
+impl Default for OutputBuffer {
+    fn default() -> Self {
+        OutputBuffer { capacity: 16, overflow: Overflow::Block }
+    }
+}
+
 
 pub struct Runtime {}
 
 
 
 impl Runtime {
-    pub fn run<I, O, P>(stream: impl Stream<Item = I> + Send + 'static, mut protocol: P) -> impl Stream<Item = O>
-    where 
+    /// Drives the protocol on a spawned task, with its timers kept in a `Scheduler`.
+    /// Outputs are buffered with the default `OutputBuffer`, which holds back the
+    /// input while the consumer is behind.
+    pub fn run<I, O, P>(stream: impl Stream<Item = I> + Send + 'static, protocol: P) -> impl Stream<Item = O>
+    where
         P: TimedAutomata<I, O> + Send + 'static,
         I: Send + 'static,
         O: Send + 'static,
     {
-        
-
-        let (out_tx, out_rx) = tokio::sync::mpsc::channel(0);
+        Self::run_buffered(stream, protocol, OutputBuffer::default()).filter_map(|out| ready(out.ok()))
+    }
 
+    /// Like `run`, with the output buffering chosen by the caller. Under
+    /// `Overflow::Error` the stream ends with `Overflowed` once the buffer fills.
+    ///
+    /// The task ends when the output is dropped or overflows, or once the input has
+    /// ended and no timers are pending.
+    pub fn run_buffered<I, O, P>(stream: impl Stream<Item = I> + Send + 'static, mut protocol: P, buffer: OutputBuffer) -> BoundedReceiver<O>
+    where
+        P: TimedAutomata<I, O> + Send + 'static,
+        I: Send + 'static,
+        O: Send + 'static,
+    {
+        let (out_tx, out_rx) = bounded(buffer.capacity, buffer.overflow);
 
         tokio::spawn(async move {
             tokio::pin!(stream);
+            let mut timers = Timers::new();
+            let mut input_open = true;
+
+            while input_open || !timers.is_empty() {
+                let event = tokio::select! {
+                    _ = out_tx.closed() => break,
+                    opt_input = stream.next(), if input_open => match opt_input {
+                        Some(input) => InEvent::Input(Instant::now(), input),
+                        None => {
+                            input_open = false;
+                            continue
+                        },
+                    },
+                    (deadline, id) = timers.next() => InEvent::Timeout(deadline, id),
+                };
+
+                for out_event in protocol.on_event(event).await {
+                    match out_event {
+                        OutEvent::Output(_, out) => {
+                            if out_tx.send(out).await.is_err() {
+                                return
+                            }
+                        },
+                        OutEvent::Timer(id, dur) => timers.arm(id, dur),
+                        OutEvent::CancelTimer(id) => timers.cancel(id),
+                    }
+                }
+            }
+        });
 
-            let mut timers = BinaryHeap::<Reverse<Instant>>::new();
-            let mut next_timeout: Option<Instant> = None;
+        out_rx
+    }
 
-            loop {
+    /// Drives a protocol between the application and a transport. The transport is
+    /// side B, and side A is returned to the application as an `Endpoint`. Outputs
+    /// are routed by side, and the task waits for the side it is writing to, so a
+    /// slow transport or a slow application holds back reading from the other one.
+    ///
+    /// The task ends when the transport stream ends or its sink fails, or when the
+    /// application drops its endpoint.
+    pub fn run_bidirectional<InA, InB, OutA, OutB, T, P>(transport: T, mut protocol: P, buffer: usize) -> Endpoint<InA, OutA>
+    where
+        T: Sink<OutB> + Stream<Item = InB> + Send + 'static,
+        P: BidirectionalTimedAutomata<InA, InB, OutA, OutB> + Send + 'static,
+        InA: Send + 'static,
+        InB: Send + 'static,
+        OutA: Send + 'static,
+        OutB: Send + 'static,
+    {
+        let (app, mut runtime) = duplex(buffer);
 
-                if let Some(Reverse(when)) = timers.peek() {
-                    if next_timeout.is_none() {
-                        next_timeout = Some(*when)
-                    }
-                }
+        tokio::spawn(async move {
+            tokio::pin!(transport);
+            let mut timers = Timers::new();
 
-                tokio::select! {
-                    opt_input = stream.next() => {
-                        if let Some(input) = opt_input {
-                            let event = InEvent::Input(Instant::now(), input);
-                            let out_events = protocol.on_event(event).await;
-                            for out_event in out_events {
-                                match out_event {
-                                    OutEvent::Output(_, out) => {
-                                        if let Err(_) = out_tx.send(out).await {
-                                            break
-                                        };
-                                    },
-                                    OutEvent::Timer(dur) => {
-                                        timers.push(Reverse(Instant::now() + dur));
-                                    },
-                                }
+            loop {
+                let event = tokio::select! {
+                    opt_input = runtime.stream.next() => match opt_input {
+                        Some(input) => InEvent::Input(Instant::now(), Sided::A(input)),
+                        None => break,
+                    },
+                    opt_input = transport.next() => match opt_input {
+                        Some(input) => InEvent::Input(Instant::now(), Sided::B(input)),
+                        None => break,
+                    },
+                    (deadline, id) = timers.next() => InEvent::Timeout(deadline, id),
+                };
+
+                for out_event in protocol.on_event(event).await {
+                    match out_event {
+                        OutEvent::Output(_, Sided::A(out)) => {
+                            if runtime.send(out).await.is_err() {
+                                return
                             }
-                        }
+                        },
+                        OutEvent::Output(_, Sided::B(out)) => {
+                            if transport.send(out).await.is_err() {
+                                return
+                            }
+                        },
+                        OutEvent::Timer(id, dur) => timers.arm(id, dur),
+                        OutEvent::CancelTimer(id) => timers.cancel(id),
                     }
+                }
+            }
+        });
 
-                    _ = async {
-                        if let Some(to) = &mut next_timeout {
-                            tokio::time::sleep_until(*to).await;
-                        } else {
-                            pending::<()>().await
-                        }
-                    } => {
-                        if let Some(Reverse(expired)) = timers.pop() {
-                            let event = InEvent::Timeout(expired.into());
-                            let out_events = protocol.on_event(event).await;
-
-                            for out_event in out_events {
-                                match out_event {
-                                    OutEvent::Output(_, out) => {
-                                        if let Err(_) = out_tx.send(out).await {
-                                            break
-                                        };
-                                    },
-                                    OutEvent::Timer(dur) => {
-                                        timers.push(Reverse(Instant::now() + dur));
-                                    },
-                                }
-                            }
-                        }
+        app
+    }
+}
 
-                        next_timeout = None;
-                    }
-                }   
+
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{atomic::{AtomicUsize, Ordering}, Arc};
+
+    use futures::{future::{ready, Ready}, SinkExt, StreamExt};
+    use tokio::time::{sleep, timeout, Duration, Instant};
+    use tokio_stream::wrappers::ReceiverStream;
+
+    use crate::{duplex, InEvent, OutEvent, Runtime, Sided, TimedAutomata, TimerId};
+
+    const ACK: TimerId = 1;
+    const RESUME: TimerId = 2;
+
+    enum Gateway {
+        HeartbeatSent,
+        HeartbeatAck,
+        Disconnected,
+    }
+
+    /// Expects an ACK within 5 seconds of each heartbeat, and gives up on resuming
+    /// 30 seconds after a disconnect.
+    struct Timeouts;
+
+    impl TimedAutomata<Gateway, (Instant, TimerId)> for Timeouts {
+        type Fut = Ready<Vec<OutEvent<(Instant, TimerId)>>>;
+
+        fn on_event(&mut self, event: InEvent<Gateway>) -> Self::Fut {
+            ready(match event {
+                InEvent::Input(_, Gateway::HeartbeatSent) => vec![OutEvent::Timer(ACK, Duration::from_secs(5))],
+                InEvent::Input(_, Gateway::HeartbeatAck) => vec![OutEvent::CancelTimer(ACK)],
+                InEvent::Input(_, Gateway::Disconnected) => vec![OutEvent::Timer(RESUME, Duration::from_secs(30))],
+                InEvent::Timeout(deadline, id) => vec![OutEvent::Output(deadline, (deadline, id))],
+            })
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cancelled_timer_never_fires() {
+        let (tx, rx) = tokio::sync::mpsc::channel(4);
+        let mut out = Runtime::run(ReceiverStream::new(rx), Timeouts);
+
+        tx.send(Gateway::HeartbeatSent).await.unwrap();
+        sleep(Duration::from_secs(2)).await;
+        tx.send(Gateway::HeartbeatAck).await.unwrap();
+
+        assert!(timeout(Duration::from_secs(60), out.next()).await.is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn timeouts_carry_their_id_and_rearming_replaces() {
+        let start = Instant::now();
+        let (tx, rx) = tokio::sync::mpsc::channel(4);
+        let mut out = Runtime::run(ReceiverStream::new(rx), Timeouts);
+
+        tx.send(Gateway::Disconnected).await.unwrap();
+        tx.send(Gateway::HeartbeatSent).await.unwrap();
+        sleep(Duration::from_secs(3)).await;
+        tx.send(Gateway::HeartbeatSent).await.unwrap();
+
+        assert_eq!(out.next().await, Some((start + Duration::from_secs(8), ACK)));
+        assert_eq!(out.next().await, Some((start + Duration::from_secs(30), RESUME)));
+        assert!(timeout(Duration::from_secs(60), out.next()).await.is_err());
+    }
+
+    const HEARTBEAT: TimerId = 3;
+
+    /// Passes messages through both ways and heartbeats to the transport every 10
+    /// seconds once the application says hello.
+    struct Relay;
+
+    impl TimedAutomata<Sided<String, String>, Sided<String, String>> for Relay {
+        type Fut = Ready<Vec<OutEvent<Sided<String, String>>>>;
+
+        fn on_event(&mut self, event: InEvent<Sided<String, String>>) -> Self::Fut {
+            ready(match event {
+                InEvent::Input(at, Sided::A(message)) if message == "hello" => vec![
+                    OutEvent::Output(at, Sided::B(message)),
+                    OutEvent::Timer(HEARTBEAT, Duration::from_secs(10)),
+                ],
+                InEvent::Input(at, Sided::A(message)) => vec![OutEvent::Output(at, Sided::B(message))],
+                InEvent::Input(at, Sided::B(message)) => vec![OutEvent::Output(at, Sided::A(message))],
+                InEvent::Timeout(at, _) => vec![
+                    OutEvent::Output(at, Sided::B("heartbeat".to_string())),
+                    OutEvent::Timer(HEARTBEAT, Duration::from_secs(10)),
+                ],
+            })
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn bidirectional_routes_by_side() {
+        let (transport, mut server) = duplex(4);
+        let mut app = Runtime::run_bidirectional(transport, Relay, 4);
+
+        app.send("hello".to_string()).await.unwrap();
+        assert_eq!(server.next().await.as_deref(), Some("hello"));
+        server.send("welcome".to_string()).await.unwrap();
+        assert_eq!(app.next().await.as_deref(), Some("welcome"));
+
+        let start = Instant::now();
+        assert_eq!(server.next().await.as_deref(), Some("heartbeat"));
+        assert_eq!(server.next().await.as_deref(), Some("heartbeat"));
+        assert!(start.elapsed() >= Duration::from_secs(19));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn slow_application_holds_back_the_transport() {
+        let (transport, mut server) = duplex(1);
+        let mut app = Runtime::run_bidirectional(transport, Relay, 1);
+
+        let sent = Arc::new(AtomicUsize::new(0));
+        let counter = sent.clone();
+        tokio::spawn(async move {
+            for i in 0..10 {
+                server.send(i.to_string()).await.unwrap();
+                counter.fetch_add(1, Ordering::SeqCst);
             }
         });
 
+        sleep(Duration::from_secs(1)).await;
+        assert!(sent.load(Ordering::SeqCst) < 10);
+
+        let received: Vec<String> = app.by_ref().take(10).collect().await;
+        assert_eq!(received, (0..10).map(|i| i.to_string()).collect::<Vec<_>>());
+        sleep(Duration::from_secs(1)).await;
+        assert_eq!(sent.load(Ordering::SeqCst), 10);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn pending_timers_outlive_the_input() {
+        let start = Instant::now();
+        let input = futures::stream::iter([Gateway::Disconnected]);
+        let out: Vec<_> = Runtime::run(input, Timeouts).collect().await;
+        assert_eq!(out, vec![(start + Duration::from_secs(30), RESUME)]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn runtime_stops_when_output_is_dropped() {
+        let (tx, rx) = tokio::sync::mpsc::channel(4);
+        let out = Runtime::run(ReceiverStream::new(rx), Timeouts);
+        tx.send(Gateway::HeartbeatSent).await.unwrap();
+
+        drop(out);
+        assert!(timeout(Duration::from_secs(1), tx.closed()).await.is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn bidirectional_stops_when_endpoint_is_dropped() {
+        let (transport, mut server) = duplex(4);
+        let mut app = Runtime::run_bidirectional(transport, Relay, 4);
 
-        ReceiverStream::new(out_rx)
+        app.send("hello".to_string()).await.unwrap();
+        assert_eq!(server.next().await.as_deref(), Some("hello"));
 
+        // The pending heartbeat does not keep the transport open.
+        drop(app);
+        assert_eq!(timeout(Duration::from_secs(1), server.next()).await, Ok(None));
     }
 }
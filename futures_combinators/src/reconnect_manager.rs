@@ -1,7 +1,14 @@
-use std::{collections::VecDeque, pin::Pin, task::Poll};
-
-use futures::{future::Either, stream::{unfold, Peekable}, Sink, SinkExt, Stream, StreamExt};
+use std::{
+    collections::{HashSet, VecDeque},
+    convert::Infallible,
+    hash::Hash,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+use futures::{stream::Peekable, Sink, Stream, StreamExt};
 use pin_project_lite::pin_project;
+use tokio::time::{sleep_until, Duration, Instant, Sleep};
 
 
 
@@ -311,17 +318,273 @@ where
 }
 
 
+
+/// Counters for one endpoint of a `PriorityFailoverSink`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SinkStats {
+    /// Items flushed through the endpoint.
+    pub delivered: u64,
+    /// Times the connected sink failed.
+    pub errors: u64,
+    /// Failed connection attempts, including probes.
+    pub connect_failures: u64,
+    pub last_failure: Option<Instant>,
+}
+
+struct Endpoint<Fut, Si> {
+    sink: Option<Si>,
+    connecting: Option<Pin<Box<Fut>>>,
+    stats: SinkStats,
+}
+
+/// Sends through the highest priority endpoint that is up, index 0 being the
+/// highest. Endpoints are (re)connected with `connect(index)`.
+///
+/// While a lower priority endpoint is in use, the higher ones are probed every
+/// `probe_every` by connecting to them, and the sink fails back to the first that
+/// comes up. A failed endpoint waits for the next probe.
+///
+/// Delivery is at least once. Items sent but not yet flushed when an endpoint fails
+/// or is replaced are replayed on the next one. Keys of the last `remember` flushed
+/// items are kept, and items with those keys are skipped. Probes only run while
+/// the sink is driven, and flushing waits until some endpoint is up.
+pub struct PriorityFailoverSink<C, Fut, Si, I, K> {
+    connect: C,
+    endpoints: Vec<Endpoint<Fut, Si>>,
+    current: Option<usize>,
+    key: fn(&I) -> K,
+    queue: VecDeque<I>,
+    /// Sent to the current endpoint, not yet flushed.
+    pending: VecDeque<I>,
+    delivered: VecDeque<K>,
+    delivered_keys: HashSet<K>,
+    remember: usize,
+    probe_every: Duration,
+    probe: Pin<Box<Sleep>>,
+}
+
+impl<C, Fut, Si, I, K> Unpin for PriorityFailoverSink<C, Fut, Si, I, K> {}
+
+impl<C, Fut, Si, I, K, E> PriorityFailoverSink<C, Fut, Si, I, K>
+where
+    C: FnMut(usize) -> Fut,
+    Fut: Future<Output = Result<Si, E>>,
+    K: Eq + Hash + Clone,
+{
+    pub fn new(endpoints: usize, connect: C, key: fn(&I) -> K) -> Self {
+        Self {
+            connect,
+            endpoints: (0..endpoints).map(|_| Endpoint { sink: None, connecting: None, stats: SinkStats::default() }).collect(),
+            current: None,
+            key,
+            queue: VecDeque::new(),
+            pending: VecDeque::new(),
+            delivered: VecDeque::new(),
+            delivered_keys: HashSet::new(),
+            remember: 1024,
+            probe_every: Duration::from_secs(30),
+            probe: Box::pin(sleep_until(Instant::now())),
+        }
+    }
+
+    pub fn probe_every(mut self, probe_every: Duration) -> Self {
+        self.probe_every = probe_every;
+        self
+    }
+
+    /// How many delivered keys to keep for skipping duplicates.
+    pub fn remember(mut self, remember: usize) -> Self {
+        self.remember = remember;
+        self
+    }
+
+    /// The endpoint in use.
+    pub fn current(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// Stats of each endpoint, by priority.
+    pub fn stats(&self) -> Vec<SinkStats> {
+        self.endpoints.iter().map(|endpoint| endpoint.stats.clone()).collect()
+    }
+
+    fn start_connecting(&mut self, index: usize) {
+        if self.endpoints[index].connecting.is_none() {
+            self.endpoints[index].connecting = Some(Box::pin((self.connect)(index)));
+        }
+    }
+
+    fn replay(&mut self) {
+        while let Some(item) = self.pending.pop_back() {
+            self.queue.push_front(item);
+        }
+    }
+
+    fn fail(&mut self, index: usize) {
+        let stats = &mut self.endpoints[index].stats;
+        stats.errors += 1;
+        stats.last_failure = Some(Instant::now());
+        self.endpoints[index].sink = None;
+        self.current = None;
+        self.replay();
+        for other in (0..self.endpoints.len()).filter(|&other| other != index) {
+            self.start_connecting(other);
+        }
+    }
+
+    fn connected(&mut self, index: usize, sink: Si) {
+        match self.current {
+            Some(current) if current <= index => return,
+            Some(current) => {
+                self.endpoints[current].sink = None;
+                self.replay();
+            }
+            None => {}
+        }
+        self.endpoints[index].sink = Some(sink);
+        self.current = Some(index);
+    }
+
+    /// Runs due probes and polls connection attempts.
+    fn poll_endpoints(&mut self, cx: &mut Context<'_>) {
+        if self.current != Some(0) && self.probe.as_mut().poll(cx).is_ready() {
+            for index in 0..self.current.unwrap_or(self.endpoints.len()) {
+                self.start_connecting(index);
+            }
+            let next = Instant::now() + self.probe_every;
+            self.probe.as_mut().reset(next);
+            let _ = self.probe.as_mut().poll(cx);
+        }
+
+        for index in 0..self.endpoints.len() {
+            let Some(connecting) = self.endpoints[index].connecting.as_mut() else { continue };
+            let Poll::Ready(result) = connecting.as_mut().poll(cx) else { continue };
+            self.endpoints[index].connecting = None;
+            match result {
+                Ok(sink) => self.connected(index, sink),
+                Err(_) => {
+                    let stats = &mut self.endpoints[index].stats;
+                    stats.connect_failures += 1;
+                    stats.last_failure = Some(Instant::now());
+                }
+            }
+        }
+    }
+
+    fn remember_delivered(&mut self, index: usize) {
+        self.endpoints[index].stats.delivered += self.pending.len() as u64;
+        for item in self.pending.drain(..) {
+            let key = (self.key)(&item);
+            if self.delivered_keys.insert(key.clone()) {
+                self.delivered.push_back(key);
+            }
+        }
+        while self.delivered.len() > self.remember {
+            if let Some(key) = self.delivered.pop_front() {
+                self.delivered_keys.remove(&key);
+            }
+        }
+    }
+}
+
+impl<C, Fut, Si, I, K, E> PriorityFailoverSink<C, Fut, Si, I, K>
+where
+    C: FnMut(usize) -> Fut,
+    Fut: Future<Output = Result<Si, E>>,
+    Si: Sink<I> + Unpin,
+    I: Clone,
+    K: Eq + Hash + Clone,
+{
+    /// Sends the queue through endpoint `index` and flushes it. `Err` means the
+    /// endpoint failed.
+    fn poll_send(&mut self, index: usize, cx: &mut Context<'_>) -> Poll<Result<(), ()>> {
+        let sink = self.endpoints[index].sink.as_mut().expect("Current endpoint has a sink");
+        while let Some(item) = self.queue.front() {
+            if self.delivered_keys.contains(&(self.key)(item)) {
+                self.queue.pop_front();
+                continue
+            }
+            match Pin::new(&mut *sink).poll_ready(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Err(_)) => return Poll::Ready(Err(())),
+                Poll::Ready(Ok(())) => {}
+            }
+            let item = self.queue.pop_front().expect("Queue has a front");
+            self.pending.push_back(item.clone());
+            if Pin::new(&mut *sink).start_send(item).is_err() {
+                return Poll::Ready(Err(()))
+            }
+        }
+        match Pin::new(sink).poll_flush(cx) {
+            Poll::Ready(Ok(())) => Poll::Ready(Ok(())),
+            Poll::Ready(Err(_)) => Poll::Ready(Err(())),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<C, Fut, Si, I, K, E> Sink<I> for PriorityFailoverSink<C, Fut, Si, I, K>
+where
+    C: FnMut(usize) -> Fut,
+    Fut: Future<Output = Result<Si, E>>,
+    Si: Sink<I> + Unpin,
+    I: Clone,
+    K: Eq + Hash + Clone,
+{
+    type Error = Infallible;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.get_mut().poll_endpoints(cx);
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: I) -> Result<(), Self::Error> {
+        self.get_mut().queue.push_back(item);
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.get_mut();
+        loop {
+            this.poll_endpoints(cx);
+            let Some(index) = this.current else { return Poll::Pending };
+            match this.poll_send(index, cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Err(())) => this.fail(index),
+                Poll::Ready(Ok(())) => {
+                    this.remember_delivered(index);
+                    return Poll::Ready(Ok(()))
+                }
+            }
+        }
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_flush(cx))?;
+        let this = self.get_mut();
+        if let Some(index) = this.current
+            && let Some(sink) = this.endpoints[index].sink.as_mut()
+            && ready!(Pin::new(sink).poll_close(cx)).is_err()
+        {
+            this.fail(index);
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+
 #[cfg(test)]
 mod tests{
 
-    use std::{collections::VecDeque, pin::Pin, task::{Context, Poll}};
-    use futures::Sink;
+    use std::{collections::VecDeque, pin::Pin, sync::{atomic::{AtomicBool, Ordering}, Arc}, task::{Context, Poll}};
+    use futures::{future::ready, sink, Sink};
 
     use futures::{stream, SinkExt, Stream, StreamExt};
     use tokio;
+    use tokio::time::Duration;
     use tokio_util::sync::PollSender;
 
-    use crate::reconnect_manager::{FailoverSink, ReconnectManager};
+    use crate::reconnect_manager::{FailoverSink, PriorityFailoverSink, ReconnectManager};
     #[tokio::test]
     async fn test_failover_sink() {
         let (tx1, mut rx1) = tokio::sync::mpsc::channel::<i32>(10);
@@ -433,4 +696,45 @@ mod tests{
         let inner = mgr.inner.clone().unwrap();
         assert_eq!(inner.sent, vec![1, 2]);
     }
+
+    type Frame = (u32, &'static str);
+
+    #[tokio::test(start_paused = true)]
+    async fn priority_failover_replays_and_fails_back() {
+        let (primary_tx, mut primary_rx) = tokio::sync::mpsc::channel::<Frame>(10);
+        let (secondary_tx, mut secondary_rx) = tokio::sync::mpsc::channel::<Frame>(10);
+        let repaired = Arc::new(AtomicBool::new(false));
+
+        // The primary takes items and then fails until repaired.
+        let connect = {
+            let repaired = repaired.clone();
+            move |index: usize| {
+                let sink: Pin<Box<dyn Sink<Frame, Error = ()> + Send>> = match index {
+                    0 if !repaired.load(Ordering::SeqCst) => Box::pin(sink::unfold((), |(), _: Frame| async { Err(()) })),
+                    0 => Box::pin(PollSender::new(primary_tx.clone()).sink_map_err(|_| ())),
+                    _ => Box::pin(PollSender::new(secondary_tx.clone()).sink_map_err(|_| ())),
+                };
+                ready(Ok::<_, ()>(sink))
+            }
+        };
+        let mut failover = PriorityFailoverSink::new(2, connect, |frame: &Frame| frame.0).probe_every(Duration::from_secs(30));
+
+        failover.send((1, "hello")).await.unwrap();
+        assert_eq!(secondary_rx.recv().await, Some((1, "hello")));
+        assert_eq!(failover.current(), Some(1));
+
+        repaired.store(true, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_secs(30)).await;
+        failover.send((2, "world")).await.unwrap();
+        assert_eq!(primary_rx.recv().await, Some((2, "world")));
+        assert_eq!(failover.current(), Some(0));
+
+        // Already delivered, so skipped.
+        failover.send((1, "hello")).await.unwrap();
+        assert!(primary_rx.try_recv().is_err());
+
+        let stats = failover.stats();
+        assert_eq!((stats[0].errors, stats[0].delivered), (1, 1));
+        assert_eq!((stats[1].errors, stats[1].delivered), (0, 1));
+    }
 }
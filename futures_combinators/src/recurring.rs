@@ -0,0 +1,276 @@
+//! Rules for events that repeat, scheduled with `ScheduleCommand::Recurring`.
+
+use chrono::{DateTime, Datelike, NaiveDate, TimeDelta, TimeZone, Utc};
+use tokio::time::{Duration, Instant};
+
+use crate::combinators::{Event, Id};
+
+
+/// When a series fires next.
+pub trait Recurrence {
+    /// The first occurrence strictly after `after`, or `None` once the series is over.
+    fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>>;
+}
+
+/// Fires every `.0`, starting one interval after scheduling. A zero interval never
+/// fires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Every(pub Duration);
+
+impl Recurrence for Every {
+    fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        if self.0.is_zero() {
+            return None
+        }
+        after.checked_add_signed(TimeDelta::from_std(self.0).ok()?)
+    }
+}
+
+
+/// A cron field that failed to parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronError(pub String);
+
+/// A five field cron expression, `minute hour day-of-month month day-of-week`,
+/// read in the time zone `tz`. Fields take numbers, `*`, lists, ranges and steps,
+/// with Sunday as 0 or 7. As in cron, a day matches either day field when both are
+/// restricted.
+///
+/// Pass a zone with daylight saving rules, such as a `chrono_tz::Tz`, for local
+/// times to follow them; a `FixedOffset` never changes. Local times skipped by a
+/// daylight saving change do not fire, and repeated ones fire at the earlier instant.
+#[derive(Debug, Clone)]
+pub struct Cron<Tz: TimeZone> {
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    any_day: bool,
+    any_weekday: bool,
+    tz: Tz,
+}
+
+fn parse_field(field: &str, min: u32, max: u32) -> Result<u64, CronError> {
+    let error = || CronError(field.to_string());
+    let mut bits = 0;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, step.parse().map_err(|_| error())?),
+            None => (part, 1),
+        };
+        let (first, last) = match range {
+            "*" => (min, max),
+            _ => match range.split_once('-') {
+                Some((first, last)) => (first.parse().map_err(|_| error())?, last.parse().map_err(|_| error())?),
+                None => {
+                    let first = range.parse().map_err(|_| error())?;
+                    (first, if step > 1 { max } else { first })
+                }
+            },
+        };
+        if step == 0 || first < min || last > max || first > last {
+            return Err(error())
+        }
+        for value in (first..=last).step_by(step) {
+            bits |= 1 << value;
+        }
+    }
+    Ok(bits)
+}
+
+fn set(bits: u64) -> impl Iterator<Item = u32> {
+    (0..64).filter(move |bit| bits & (1 << bit) != 0)
+}
+
+impl<Tz: TimeZone> Cron<Tz> {
+    pub fn parse(expression: &str, tz: Tz) -> Result<Self, CronError> {
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            return Err(CronError(expression.to_string()))
+        };
+        let mut weekdays = parse_field(weekday, 0, 7)?;
+        if weekdays & (1 << 7) != 0 {
+            weekdays = (weekdays | 1) & !(1 << 7);
+        }
+
+        Ok(Cron {
+            minutes: parse_field(minute, 0, 59)?,
+            hours: parse_field(hour, 0, 23)?,
+            days: parse_field(day, 1, 31)?,
+            months: parse_field(month, 1, 12)?,
+            weekdays,
+            any_day: day == "*",
+            any_weekday: weekday == "*",
+            tz,
+        })
+    }
+
+    /// Every day at `hour:minute`.
+    ///
+    /// # Panics
+    /// If `hour` or `minute` is out of range.
+    pub fn daily_at(hour: u32, minute: u32, tz: Tz) -> Self {
+        Self::parse(&format!("{minute} {hour} * * *"), tz).expect("Hour and minute must be in range")
+    }
+
+    /// Monday to Friday at `hour:minute`.
+    ///
+    /// # Panics
+    /// If `hour` or `minute` is out of range.
+    pub fn weekdays_at(hour: u32, minute: u32, tz: Tz) -> Self {
+        Self::parse(&format!("{minute} {hour} * * 1-5"), tz).expect("Hour and minute must be in range")
+    }
+
+    fn matches(&self, date: NaiveDate) -> bool {
+        let day = self.days & (1 << date.day()) != 0;
+        let weekday = self.weekdays & (1 << date.weekday().num_days_from_sunday()) != 0;
+        let day = match (self.any_day, self.any_weekday) {
+            (false, false) => day || weekday,
+            _ => day && weekday,
+        };
+        day && self.months & (1 << date.month()) != 0
+    }
+}
+
+impl<Tz: TimeZone> Recurrence for Cron<Tz> {
+    fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let mut date = after.with_timezone(&self.tz).date_naive();
+        // Long enough for rules like February 29th on a Monday.
+        for _ in 0..366 * 28 {
+            if self.matches(date) {
+                for hour in set(self.hours) {
+                    for minute in set(self.minutes) {
+                        let local = date.and_hms_opt(hour, minute, 0)?;
+                        if let Some(at) = self.tz.from_local_datetime(&local).earliest()
+                            && at.with_timezone(&Utc) > after
+                        {
+                            return Some(at.with_timezone(&Utc))
+                        }
+                    }
+                }
+            }
+            date = date.succ_opt()?;
+        }
+        None
+    }
+}
+
+
+/// Events fired on every occurrence of a rule, until cancelled by id.
+///
+/// Occurrences are placed on the monotonic clock relative to when the series was
+/// created, so later wall clock adjustments are not followed.
+pub struct Series<T> {
+    pub id: Id,
+    rule: Box<dyn Recurrence + Send>,
+    make: Box<dyn FnMut() -> T + Send>,
+    anchor: (DateTime<Utc>, Instant),
+    due: DateTime<Utc>,
+    pub(crate) next: Instant,
+}
+
+impl<T> Series<T> {
+    /// Fires a clone of `value` on each occurrence.
+    pub fn new(id: Id, rule: impl Recurrence + Send + 'static, value: T) -> Self
+    where
+        T: Clone + Send + 'static,
+    {
+        Self::with(id, rule, move || value.clone())
+    }
+
+    /// Fires what `make` returns on each occurrence.
+    pub fn with(id: Id, rule: impl Recurrence + Send + 'static, make: impl FnMut() -> T + Send + 'static) -> Self {
+        let anchor = (Utc::now(), Instant::now());
+        Series {
+            id,
+            rule: Box::new(rule),
+            make: Box::new(make),
+            anchor,
+            due: anchor.0,
+            next: anchor.1,
+        }
+    }
+
+    /// The event for the next occurrence.
+    pub(crate) fn advance(&mut self) -> Option<Event<T>> {
+        self.due = self.rule.next_after(self.due)?;
+        let (wall, instant) = self.anchor;
+        self.next = instant + (self.due - wall).to_std().unwrap_or_default();
+        Some(Event::new((self.make)(), self.next, self.id))
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use chrono_tz::Europe::Berlin;
+    use futures::StreamExt;
+    use tokio::{
+        sync::mpsc::unbounded_channel,
+        time::{timeout, Duration, Instant},
+    };
+    use tokio_stream::wrappers::UnboundedReceiverStream;
+
+    use crate::{
+        combinators::{Event, ScheduleCommand, Scheduler},
+        recurring::{Cron, CronError, Every, Recurrence, Series},
+    };
+
+    #[test]
+    fn cron_finds_next_local_occurrence() {
+        // Friday 20:00 in Berlin.
+        let friday = Utc.with_ymd_and_hms(2025, 6, 6, 18, 0, 0).unwrap();
+
+        let stream = Cron::weekdays_at(19, 0, Berlin);
+        assert_eq!(stream.next_after(friday), Some(Utc.with_ymd_and_hms(2025, 6, 9, 17, 0, 0).unwrap()));
+
+        let quarterly = Cron::parse("*/15 9-17 * * *", Utc).unwrap();
+        assert_eq!(quarterly.next_after(friday), Some(Utc.with_ymd_and_hms(2025, 6, 7, 9, 0, 0).unwrap()));
+
+        // Either day field matches when both are restricted.
+        let monthly = Cron::parse("30 3 1 * 0", Utc).unwrap();
+        assert_eq!(monthly.next_after(friday), Some(Utc.with_ymd_and_hms(2025, 6, 8, 3, 30, 0).unwrap()));
+
+        assert_eq!(Cron::parse("0 24 * * *", Utc).unwrap_err(), CronError("24".to_string()));
+        assert!(Cron::parse("0 0 * *", Utc).is_err());
+
+        assert_eq!(Every(Duration::ZERO).next_after(friday), None);
+    }
+
+    #[test]
+    fn cron_follows_daylight_saving_changes() {
+        let nightly = Cron::daily_at(2, 30, Berlin);
+
+        // 02:30 does not exist on March 30th 2025, when clocks jump from 02:00 to 03:00.
+        let before_spring = Utc.with_ymd_and_hms(2025, 3, 29, 12, 0, 0).unwrap();
+        assert_eq!(nightly.next_after(before_spring), Some(Utc.with_ymd_and_hms(2025, 3, 31, 0, 30, 0).unwrap()));
+
+        // 02:30 happens twice on October 26th 2025 and fires only at the first, in summer time.
+        let before_autumn = Utc.with_ymd_and_hms(2025, 10, 25, 12, 0, 0).unwrap();
+        let repeated = nightly.next_after(before_autumn);
+        assert_eq!(repeated, Some(Utc.with_ymd_and_hms(2025, 10, 26, 0, 30, 0).unwrap()));
+        assert_eq!(nightly.next_after(repeated.unwrap()), Some(Utc.with_ymd_and_hms(2025, 10, 27, 1, 30, 0).unwrap()));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn series_repeats_until_cancelled() {
+        let (tx, rx) = unbounded_channel();
+        let mut scheduler = Scheduler::new(UnboundedReceiverStream::new(rx));
+        let start = Instant::now();
+        tx.send(ScheduleCommand::Recurring(Series::new(1, Every(Duration::from_secs(10)), "tick"))).unwrap();
+        tx.send(ScheduleCommand::Schedule(Event::event_in("once", Duration::from_secs(15), 2))).unwrap();
+
+        let mut fired = Vec::new();
+        for _ in 0..4 {
+            let event = scheduler.next().await.unwrap();
+            fired.push((event.value.value, (event.value.timestamp - start).as_secs()));
+        }
+        assert_eq!(fired, vec![("tick", 10), ("once", 15), ("tick", 20), ("tick", 30)]);
+
+        tx.send(ScheduleCommand::Cancel(1)).unwrap();
+        assert!(timeout(Duration::from_secs(60), scheduler.next()).await.is_err());
+    }
+}
@@ -0,0 +1,435 @@
+use std::{collections::VecDeque, mem};
+
+use futures::{stream, Stream, StreamExt};
+use tokio::time::{sleep_until, Duration, Instant};
+
+use crate::combinators::Timed;
+
+
+/// Items of a `Timed` stream that fell in `[start, end)`.
+#[derive(Debug)]
+pub struct Window<T> {
+    pub start: Instant,
+    pub end: Instant,
+    pub items: Vec<Timed<T>>,
+}
+
+impl<T> Window<T> {
+    fn new(start: Instant, end: Instant) -> Self {
+        Window { start, end, items: Vec::new() }
+    }
+}
+
+/// Start of the `size` long slot holding `timestamp`, counting slots from `origin`.
+fn align(origin: Instant, timestamp: Instant, size: Duration) -> Instant {
+    let slots = timestamp.saturating_duration_since(origin).as_nanos() / size.as_nanos().max(1);
+    origin + Duration::from_nanos((slots * size.as_nanos()) as u64)
+}
+
+
+/// A time based operator, woken for each item and once its deadline passes.
+trait Operator<In> {
+    type Out;
+
+    fn on_item(&mut self, item: In, ready: &mut VecDeque<Self::Out>);
+
+    fn deadline(&self) -> Option<Instant>;
+
+    fn on_deadline(&mut self, deadline: Instant, ready: &mut VecDeque<Self::Out>);
+
+    /// The input ended.
+    fn flush(&mut self, ready: &mut VecDeque<Self::Out>);
+}
+
+fn drive<S, O>(source: S, operator: O) -> impl Stream<Item = O::Out>
+where
+    S: Stream,
+    O: Operator<S::Item>,
+{
+    let state = (Box::pin(source), operator, VecDeque::new(), false);
+    stream::unfold(state, |(mut source, mut operator, mut ready, mut ended)| async move {
+        loop {
+            if let Some(out) = ready.pop_front() {
+                return Some((out, (source, operator, ready, ended)))
+            }
+            if ended {
+                return None
+            }
+
+            let deadline = operator.deadline();
+            tokio::select! {
+                biased;
+                item = source.next() => match item {
+                    Some(item) => operator.on_item(item, &mut ready),
+                    None => {
+                        operator.flush(&mut ready);
+                        ended = true;
+                    }
+                },
+                _ = sleep_until(deadline.unwrap_or_else(Instant::now)), if deadline.is_some() => {
+                    operator.on_deadline(deadline.expect("Branch needs a deadline"), &mut ready);
+                }
+            }
+        }
+    })
+}
+
+
+struct Tumbling<T> {
+    size: Duration,
+    origin: Option<Instant>,
+    current: Option<Window<T>>,
+}
+
+impl<T> Operator<Timed<T>> for Tumbling<T> {
+    type Out = Window<T>;
+
+    fn on_item(&mut self, item: Timed<T>, ready: &mut VecDeque<Window<T>>) {
+        let origin = *self.origin.get_or_insert(item.timestamp);
+        let start = align(origin, item.timestamp, self.size);
+        if self.current.as_ref().is_some_and(|current| start > current.start) {
+            ready.extend(self.current.take());
+        }
+        self.current
+            .get_or_insert_with(|| Window::new(start, start + self.size))
+            .items
+            .push(item);
+    }
+
+    fn deadline(&self) -> Option<Instant> {
+        self.current.as_ref().map(|current| current.end)
+    }
+
+    fn on_deadline(&mut self, _: Instant, ready: &mut VecDeque<Window<T>>) {
+        ready.extend(self.current.take());
+    }
+
+    fn flush(&mut self, ready: &mut VecDeque<Window<T>>) {
+        ready.extend(self.current.take());
+    }
+}
+
+
+struct Sliding<T> {
+    size: Duration,
+    step: Duration,
+    origin: Option<Instant>,
+    /// By start, which also orders them by end.
+    open: VecDeque<Window<T>>,
+}
+
+impl<T: Clone> Operator<Timed<T>> for Sliding<T> {
+    type Out = Window<T>;
+
+    fn on_item(&mut self, item: Timed<T>, ready: &mut VecDeque<Window<T>>) {
+        while self.open.front().is_some_and(|window| window.end <= item.timestamp) {
+            ready.extend(self.open.pop_front());
+        }
+
+        let origin = *self.origin.get_or_insert(item.timestamp);
+        let mut start = align(origin, item.timestamp, self.step);
+        let mut starts = Vec::new();
+        while start + self.size > item.timestamp {
+            starts.push(start);
+            match start.checked_sub(self.step) {
+                Some(earlier) if earlier >= origin => start = earlier,
+                _ => break,
+            }
+        }
+
+        for start in starts.into_iter().rev() {
+            let index = match self.open.iter().position(|window| window.start >= start) {
+                Some(index) if self.open[index].start == start => index,
+                Some(index) => {
+                    self.open.insert(index, Window::new(start, start + self.size));
+                    index
+                }
+                None => {
+                    self.open.push_back(Window::new(start, start + self.size));
+                    self.open.len() - 1
+                }
+            };
+            self.open[index].items.push(Timed::new(item.value.clone(), item.timestamp));
+        }
+    }
+
+    fn deadline(&self) -> Option<Instant> {
+        self.open.front().map(|window| window.end)
+    }
+
+    fn on_deadline(&mut self, _: Instant, ready: &mut VecDeque<Window<T>>) {
+        ready.extend(self.open.pop_front());
+    }
+
+    fn flush(&mut self, ready: &mut VecDeque<Window<T>>) {
+        ready.extend(self.open.drain(..));
+    }
+}
+
+
+struct Session<T> {
+    gap: Duration,
+    current: Option<Window<T>>,
+}
+
+impl<T> Operator<Timed<T>> for Session<T> {
+    type Out = Window<T>;
+
+    fn on_item(&mut self, item: Timed<T>, ready: &mut VecDeque<Window<T>>) {
+        if self.current.as_ref().is_some_and(|current| item.timestamp >= current.end) {
+            ready.extend(self.current.take());
+        }
+        let current = self.current.get_or_insert_with(|| Window::new(item.timestamp, item.timestamp));
+        current.end = current.end.max(item.timestamp + self.gap);
+        current.items.push(item);
+    }
+
+    fn deadline(&self) -> Option<Instant> {
+        self.current.as_ref().map(|current| current.end)
+    }
+
+    fn on_deadline(&mut self, _: Instant, ready: &mut VecDeque<Window<T>>) {
+        ready.extend(self.current.take());
+    }
+
+    fn flush(&mut self, ready: &mut VecDeque<Window<T>>) {
+        ready.extend(self.current.take());
+    }
+}
+
+
+struct Debounce<T> {
+    quiet: Duration,
+    pending: Option<(T, Instant)>,
+}
+
+impl<T> Operator<T> for Debounce<T> {
+    type Out = T;
+
+    fn on_item(&mut self, item: T, _: &mut VecDeque<T>) {
+        self.pending = Some((item, Instant::now() + self.quiet));
+    }
+
+    fn deadline(&self) -> Option<Instant> {
+        self.pending.as_ref().map(|(_, due)| *due)
+    }
+
+    fn on_deadline(&mut self, _: Instant, ready: &mut VecDeque<T>) {
+        ready.extend(self.pending.take().map(|(item, _)| item));
+    }
+
+    fn flush(&mut self, ready: &mut VecDeque<T>) {
+        ready.extend(self.pending.take().map(|(item, _)| item));
+    }
+}
+
+
+struct Throttle {
+    period: Duration,
+    until: Option<Instant>,
+}
+
+impl<T> Operator<T> for Throttle {
+    type Out = T;
+
+    fn on_item(&mut self, item: T, ready: &mut VecDeque<T>) {
+        let now = Instant::now();
+        if self.until.is_none_or(|until| now >= until) {
+            self.until = Some(now + self.period);
+            ready.push_back(item);
+        }
+    }
+
+    fn deadline(&self) -> Option<Instant> {
+        None
+    }
+
+    fn on_deadline(&mut self, _: Instant, _: &mut VecDeque<T>) {}
+
+    fn flush(&mut self, _: &mut VecDeque<T>) {}
+}
+
+
+struct Sample<T> {
+    period: Duration,
+    latest: Option<T>,
+    next: Option<Instant>,
+}
+
+impl<T> Operator<T> for Sample<T> {
+    type Out = T;
+
+    fn on_item(&mut self, item: T, _: &mut VecDeque<T>) {
+        self.latest = Some(item);
+        self.next.get_or_insert_with(|| Instant::now() + self.period);
+    }
+
+    fn deadline(&self) -> Option<Instant> {
+        self.next
+    }
+
+    fn on_deadline(&mut self, deadline: Instant, ready: &mut VecDeque<T>) {
+        // Ticking stops while no items come in and restarts with the next one.
+        self.next = self.latest.is_some().then_some(deadline + self.period);
+        ready.extend(self.latest.take());
+    }
+
+    fn flush(&mut self, ready: &mut VecDeque<T>) {
+        ready.extend(self.latest.take());
+    }
+}
+
+
+struct BufferUntilIdle<T> {
+    idle: Duration,
+    items: Vec<T>,
+    due: Option<Instant>,
+}
+
+impl<T> Operator<T> for BufferUntilIdle<T> {
+    type Out = Vec<T>;
+
+    fn on_item(&mut self, item: T, _: &mut VecDeque<Vec<T>>) {
+        self.items.push(item);
+        self.due = Some(Instant::now() + self.idle);
+    }
+
+    fn deadline(&self) -> Option<Instant> {
+        self.due
+    }
+
+    fn on_deadline(&mut self, _: Instant, ready: &mut VecDeque<Vec<T>>) {
+        self.due = None;
+        ready.push_back(mem::take(&mut self.items));
+    }
+
+    fn flush(&mut self, ready: &mut VecDeque<Vec<T>>) {
+        if !self.items.is_empty() {
+            ready.push_back(mem::take(&mut self.items));
+        }
+    }
+}
+
+
+/// Time based operators. Windows group `Timed` items by their timestamps and close
+/// once tokio's clock passes their end. The rate operators work on any stream and
+/// go by when items arrive.
+pub trait StreamExtTime: Stream + Sized {
+    /// Back to back windows of `size`, the first starting at the first item. Items
+    /// timestamped before the open window join it.
+    fn tumbling<T>(self, size: Duration) -> impl Stream<Item = Window<T>>
+    where
+        Self: Stream<Item = Timed<T>>,
+    {
+        drive(self, Tumbling { size, origin: None, current: None })
+    }
+
+    /// Windows of `size` starting every `step`, so an item lands in each window
+    /// covering its timestamp.
+    fn sliding<T: Clone>(self, size: Duration, step: Duration) -> impl Stream<Item = Window<T>>
+    where
+        Self: Stream<Item = Timed<T>>,
+    {
+        drive(self, Sliding { size, step, origin: None, open: VecDeque::new() })
+    }
+
+    /// Windows of items less than `gap` apart. A window ends `gap` after its last item.
+    fn session<T>(self, gap: Duration) -> impl Stream<Item = Window<T>>
+    where
+        Self: Stream<Item = Timed<T>>,
+    {
+        drive(self, Session { gap, current: None })
+    }
+
+    /// The last item of each burst, once `quiet` has passed without another.
+    fn debounce(self, quiet: Duration) -> impl Stream<Item = Self::Item> {
+        drive(self, Debounce { quiet, pending: None })
+    }
+
+    /// The first item, then nothing until `period` has passed.
+    fn throttle(self, period: Duration) -> impl Stream<Item = Self::Item> {
+        drive(self, Throttle { period, until: None })
+    }
+
+    /// The latest item every `period`, skipping periods without one.
+    fn sample(self, period: Duration) -> impl Stream<Item = Self::Item> {
+        drive(self, Sample { period, latest: None, next: None })
+    }
+
+    /// Everything received until the stream has been idle for `idle`.
+    fn buffer_until_idle(self, idle: Duration) -> impl Stream<Item = Vec<Self::Item>> {
+        drive(self, BufferUntilIdle { idle, items: Vec::new(), due: None })
+    }
+}
+
+impl<S> StreamExtTime for S where S: Stream {}
+
+
+
+#[cfg(test)]
+mod tests {
+    use futures::{Stream, StreamExt};
+    use tokio::{
+        sync::mpsc,
+        time::{sleep, Duration, Instant},
+    };
+    use tokio_stream::wrappers::ReceiverStream;
+
+    use crate::{
+        combinators::Timed,
+        windows::{StreamExtTime, Window},
+    };
+
+    /// Sends each value after sleeping its offset in milliseconds from the previous one.
+    fn script<T: Send + 'static>(items: Vec<(u64, T)>) -> impl Stream<Item = T> {
+        let (tx, rx) = mpsc::channel(16);
+        tokio::spawn(async move {
+            for (delay, item) in items {
+                sleep(Duration::from_millis(delay)).await;
+                tx.send(item).await.unwrap();
+            }
+        });
+        ReceiverStream::new(rx)
+    }
+
+    fn timed(items: Vec<(u64, &'static str)>) -> impl Stream<Item = Timed<&'static str>> {
+        script(items).map(Timed::tag_now)
+    }
+
+    fn values(windows: Vec<Window<&'static str>>) -> Vec<Vec<&'static str>> {
+        windows.into_iter().map(|window| window.items.into_iter().map(|item| item.value).collect()).collect()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn windows_group_by_timestamp() {
+        let items = || vec![(0, "a"), (400, "b"), (700, "c"), (1500, "d")];
+
+        let start = Instant::now();
+        let tumbling: Vec<_> = timed(items()).tumbling(Duration::from_secs(1)).collect().await;
+        assert_eq!(tumbling[0].end - start, Duration::from_secs(1));
+        assert_eq!(values(tumbling), vec![vec!["a", "b"], vec!["c"], vec!["d"]]);
+
+        let sliding = timed(items()).sliding(Duration::from_secs(1), Duration::from_millis(500)).collect().await;
+        assert_eq!(values(sliding), vec![vec!["a", "b"], vec!["c"], vec!["c"], vec!["d"], vec!["d"]]);
+
+        let session = timed(items()).session(Duration::from_millis(800)).collect().await;
+        assert_eq!(values(session), vec![vec!["a", "b", "c"], vec!["d"]]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn rate_operators_follow_the_clock() {
+        let items = || vec![(0, 1), (100, 2), (100, 3), (1000, 4), (100, 5), (1000, 6)];
+
+        let debounced: Vec<_> = script(items()).debounce(Duration::from_millis(500)).collect().await;
+        assert_eq!(debounced, vec![3, 5, 6]);
+
+        let throttled: Vec<_> = script(items()).throttle(Duration::from_millis(500)).collect().await;
+        assert_eq!(throttled, vec![1, 4, 6]);
+
+        let sampled: Vec<_> = script(items()).sample(Duration::from_millis(150)).collect().await;
+        assert_eq!(sampled, vec![2, 3, 5, 6]);
+
+        let batches: Vec<_> = script(items()).buffer_until_idle(Duration::from_millis(500)).collect().await;
+        assert_eq!(batches, vec![vec![1, 2, 3], vec![4, 5], vec![6]]);
+    }
+}
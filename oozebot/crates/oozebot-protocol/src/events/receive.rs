@@ -1,6 +1,6 @@
 use serde::{Deserialize, Deserializer};
 use serde_json::Value;
-use tokio_tungstenite::tungstenite::{self, Utf8Bytes};
+use tokio_tungstenite::tungstenite::Utf8Bytes;
 
 use crate::{opcodes::GatewayOpCode, GatewayError, RawGatewayPayload};
 
@@ -22,8 +22,9 @@ impl TryFrom<Utf8Bytes> for GatewayRecvEvent {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum GatewayRecvEvent {
+    Dispatch(Dispatch),
     Hello(Hello),
     HeartbeatAck(HeartbeatAck),
     Heartbeat(Heartbeat),
@@ -44,6 +45,20 @@ impl<'de> Deserialize<'de> for GatewayRecvEvent {
             .map_err(serde::de::Error::custom)?;
 
         match opcode {
+            GatewayOpCode::Dispatch => match raw.t.as_deref() {
+                Some("READY") => {
+                    serde_json::from_value(raw.d)
+                        .map(GatewayRecvEvent::Ready)
+                        .map_err(serde::de::Error::custom)
+                }
+                Some("RESUMED") => Ok(GatewayRecvEvent::Resumed(Resumed)),
+                _ => Ok(GatewayRecvEvent::Dispatch(Dispatch {
+                    op: raw.op,
+                    d: raw.d,
+                    s: raw.s,
+                    t: raw.t,
+                })),
+            },
             GatewayOpCode::Hello => {
                 serde_json::from_value(raw.d)
                     .map(GatewayRecvEvent::Hello)
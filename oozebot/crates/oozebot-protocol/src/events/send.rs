@@ -1,5 +1,7 @@
-use serde::Serialize;
-use tokio_tungstenite::tungstenite::{self, Utf8Bytes};
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+use tokio_tungstenite::tungstenite;
+
+use crate::opcodes::GatewayOpCode;
 
 impl From<Heartbeat> for GatewaySendEvent {
     fn from(value: Heartbeat) -> Self {
@@ -14,7 +16,7 @@ impl From<GatewaySendEvent> for tungstenite::Message {
     }
 }
 
-#[derive(Debug, Serialize, Clone, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub enum GatewaySendEvent {
     Identify(Identify),
     Resume(Resume),
@@ -25,6 +27,38 @@ pub enum GatewaySendEvent {
     UpdatePresence(UpdatePresence),
 }
 
+impl GatewaySendEvent {
+    pub fn op(&self) -> GatewayOpCode {
+        match self {
+            Self::Identify(_) => GatewayOpCode::Identify,
+            Self::Resume(_) => GatewayOpCode::Resume,
+            Self::Heartbeat(_) => GatewayOpCode::Heartbeat,
+            Self::RequestGuildMembers(_) => GatewayOpCode::RequestGuildMembers,
+            Self::RequestSoundboardSounds(_) => GatewayOpCode::RequestSoundboardSounds,
+            Self::UpdateVoiceState(_) => GatewayOpCode::VoiceStateUpdate,
+            Self::UpdatePresence(_) => GatewayOpCode::PresenceUpdate,
+        }
+    }
+}
+
+/// Sent as `{"op": <opcode>, "d": <payload>}`.
+impl Serialize for GatewaySendEvent {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut payload = serializer.serialize_struct("GatewaySendEvent", 2)?;
+        payload.serialize_field("op", &(self.op() as u8))?;
+        match self {
+            Self::Identify(d) => payload.serialize_field("d", d)?,
+            Self::Resume(d) => payload.serialize_field("d", d)?,
+            Self::Heartbeat(heartbeat) => payload.serialize_field("d", &heartbeat.d)?,
+            Self::RequestGuildMembers(d) => payload.serialize_field("d", d)?,
+            Self::RequestSoundboardSounds(d) => payload.serialize_field("d", d)?,
+            Self::UpdateVoiceState(d) => payload.serialize_field("d", d)?,
+            Self::UpdatePresence(d) => payload.serialize_field("d", d)?,
+        }
+        payload.end()
+    }
+}
+
 #[derive(Debug, Serialize, Clone, PartialEq, PartialOrd)]
 pub struct Identify {
     pub token: String,
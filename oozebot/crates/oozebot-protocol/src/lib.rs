@@ -26,7 +26,10 @@ struct RawGatewayPayload {
 
 #[cfg(test)]
 mod tests {
-    use crate::events::receive::GatewayRecvEvent;
+    use crate::events::{
+        receive::GatewayRecvEvent,
+        send::{GatewaySendEvent, Heartbeat, RequestSoundboardSounds},
+    };
 
     #[test]
     fn deserialize_hello_event() {
@@ -49,6 +52,45 @@ mod tests {
             _ => {panic!("Incorrect event variant {:?}", event)}
         }
     }
-}
 
+    #[test]
+    fn deserialize_dispatch_event() {
+        let json_data = r#"
+        {
+            "op": 0,
+            "s": 42,
+            "t": "GUILD_AUDIT_LOG_ENTRY_CREATE",
+            "d": {
+                "guild_id": "1",
+                "id": "2"
+            }
+        }
+        "#;
+
+        let event: GatewayRecvEvent =
+            serde_json::from_str(json_data).expect("Failed to deserialize");
 
+        match event {
+            GatewayRecvEvent::Dispatch(dispatch) => {
+                assert_eq!(dispatch.s, Some(42));
+                assert_eq!(dispatch.t.as_deref(), Some("GUILD_AUDIT_LOG_ENTRY_CREATE"));
+                assert_eq!(dispatch.d["guild_id"], "1");
+            }
+            _ => {panic!("Incorrect event variant {:?}", event)}
+        }
+    }
+
+    #[test]
+    fn serialize_send_events_with_opcode() {
+        let request = GatewaySendEvent::RequestSoundboardSounds(RequestSoundboardSounds {
+            guild_ids: vec!["1".to_string()],
+        });
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({ "op": 31, "d": { "guild_ids": ["1"] } }),
+        );
+
+        let heartbeat = GatewaySendEvent::Heartbeat(Heartbeat { d: Some(42) });
+        assert_eq!(serde_json::to_string(&heartbeat).unwrap(), r#"{"op":1,"d":42}"#);
+    }
+}
@@ -2014,7 +2014,7 @@ pub enum UploadApplicationAttachmentError {
 }
 
 
-pub async fn add_group_dm_user(configuration: &configuration::Configuration, channel_id: &str, user_id: &str, add_group_dm_user_request: models::AddGroupDmUserRequest) -> Result<models::AddGroupDmUser201Response, Error<AddGroupDmUserError>> {
+pub async fn add_group_dm_user(configuration: &configuration::Configuration, channel_id: &str, user_id: &str, add_group_dm_user_request: models::AddGroupDmUserRequest) -> Result<models::DmChannelResponse, Error<AddGroupDmUserError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_channel_id = channel_id;
     let p_path_user_id = user_id;
@@ -2051,8 +2051,8 @@ pub async fn add_group_dm_user(configuration: &configuration::Configuration, cha
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::AddGroupDmUser201Response`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::AddGroupDmUser201Response`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::DmChannelResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::DmChannelResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -2882,7 +2882,7 @@ pub async fn create_application_emoji(configuration: &configuration::Configurati
     }
 }
 
-pub async fn create_auto_moderation_rule(configuration: &configuration::Configuration, guild_id: &str, create_auto_moderation_rule_request: models::CreateAutoModerationRuleRequest) -> Result<models::CreateAutoModerationRule200Response, Error<CreateAutoModerationRuleError>> {
+pub async fn create_auto_moderation_rule(configuration: &configuration::Configuration, guild_id: &str, create_auto_moderation_rule_request: models::CreateAutoModerationRuleRequest) -> Result<models::AutoModerationRuleResponse, Error<CreateAutoModerationRuleError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_guild_id = guild_id;
     let p_body_create_auto_moderation_rule_request = create_auto_moderation_rule_request;
@@ -2918,8 +2918,8 @@ pub async fn create_auto_moderation_rule(configuration: &configuration::Configur
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::CreateAutoModerationRule200Response`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::CreateAutoModerationRule200Response`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::AutoModerationRuleResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::AutoModerationRuleResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -2928,7 +2928,7 @@ pub async fn create_auto_moderation_rule(configuration: &configuration::Configur
     }
 }
 
-pub async fn create_channel_invite(configuration: &configuration::Configuration, channel_id: &str, create_channel_invite_request: models::CreateChannelInviteRequest) -> Result<models::CreateChannelInvite200Response, Error<CreateChannelInviteError>> {
+pub async fn create_channel_invite(configuration: &configuration::Configuration, channel_id: &str, create_channel_invite_request: models::CreateChannelInviteRequest) -> Result<models::InviteResponse, Error<CreateChannelInviteError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_channel_id = channel_id;
     let p_body_create_channel_invite_request = create_channel_invite_request;
@@ -2964,8 +2964,8 @@ pub async fn create_channel_invite(configuration: &configuration::Configuration,
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::CreateChannelInvite200Response`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::CreateChannelInvite200Response`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::InviteResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::InviteResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -2974,7 +2974,7 @@ pub async fn create_channel_invite(configuration: &configuration::Configuration,
     }
 }
 
-pub async fn create_dm(configuration: &configuration::Configuration, create_private_channel_request: models::CreatePrivateChannelRequest) -> Result<models::AddGroupDmUser201Response, Error<CreateDmError>> {
+pub async fn create_dm(configuration: &configuration::Configuration, create_private_channel_request: models::CreatePrivateChannelRequest) -> Result<models::DmChannelResponse, Error<CreateDmError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_body_create_private_channel_request = create_private_channel_request;
 
@@ -3009,8 +3009,8 @@ pub async fn create_dm(configuration: &configuration::Configuration, create_priv
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::AddGroupDmUser201Response`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::AddGroupDmUser201Response`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::DmChannelResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::DmChannelResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -3259,7 +3259,7 @@ pub async fn create_guild_role(configuration: &configuration::Configuration, gui
     }
 }
 
-pub async fn create_guild_scheduled_event(configuration: &configuration::Configuration, guild_id: &str, create_guild_scheduled_event_request: models::CreateGuildScheduledEventRequest) -> Result<models::ListGuildScheduledEvents200ResponseInner, Error<CreateGuildScheduledEventError>> {
+pub async fn create_guild_scheduled_event(configuration: &configuration::Configuration, guild_id: &str, create_guild_scheduled_event_request: models::CreateGuildScheduledEventRequest) -> Result<models::GuildScheduledEventResponse, Error<CreateGuildScheduledEventError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_guild_id = guild_id;
     let p_body_create_guild_scheduled_event_request = create_guild_scheduled_event_request;
@@ -3295,8 +3295,8 @@ pub async fn create_guild_scheduled_event(configuration: &configuration::Configu
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ListGuildScheduledEvents200ResponseInner`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ListGuildScheduledEvents200ResponseInner`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::GuildScheduledEventResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GuildScheduledEventResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -4279,7 +4279,7 @@ pub async fn delete_auto_moderation_rule(configuration: &configuration::Configur
     }
 }
 
-pub async fn delete_channel(configuration: &configuration::Configuration, channel_id: &str) -> Result<models::GetChannel200Response, Error<DeleteChannelError>> {
+pub async fn delete_channel(configuration: &configuration::Configuration, channel_id: &str) -> Result<models::ChannelResponse, Error<DeleteChannelError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_channel_id = channel_id;
 
@@ -4313,8 +4313,8 @@ pub async fn delete_channel(configuration: &configuration::Configuration, channe
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::GetChannel200Response`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetChannel200Response`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ChannelResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ChannelResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -5838,7 +5838,7 @@ pub async fn get_application_emoji(configuration: &configuration::Configuration,
     }
 }
 
-pub async fn get_application_role_connections_metadata(configuration: &configuration::Configuration, application_id: &str) -> Result<Vec<models::ApplicationRoleConnectionsMetadataItemResponse>, Error<GetApplicationRoleConnectionsMetadataError>> {
+pub async fn get_application_role_connections_metadata(configuration: &configuration::Configuration, application_id: &str) -> Result<Vec<models::ApplicationRoleConnectionsMetadataItemRequest>, Error<GetApplicationRoleConnectionsMetadataError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_application_id = application_id;
 
@@ -5872,8 +5872,8 @@ pub async fn get_application_role_connections_metadata(configuration: &configura
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `Vec&lt;models::ApplicationRoleConnectionsMetadataItemResponse&gt;`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `Vec&lt;models::ApplicationRoleConnectionsMetadataItemResponse&gt;`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `Vec&lt;models::ApplicationRoleConnectionsMetadataItemRequest&gt;`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `Vec&lt;models::ApplicationRoleConnectionsMetadataItemRequest&gt;`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -5927,7 +5927,7 @@ pub async fn get_application_user_role_connection(configuration: &configuration:
     }
 }
 
-pub async fn get_auto_moderation_rule(configuration: &configuration::Configuration, guild_id: &str, rule_id: &str) -> Result<models::CreateAutoModerationRule200Response, Error<GetAutoModerationRuleError>> {
+pub async fn get_auto_moderation_rule(configuration: &configuration::Configuration, guild_id: &str, rule_id: &str) -> Result<models::AutoModerationRuleResponse, Error<GetAutoModerationRuleError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_guild_id = guild_id;
     let p_path_rule_id = rule_id;
@@ -5962,8 +5962,8 @@ pub async fn get_auto_moderation_rule(configuration: &configuration::Configurati
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::CreateAutoModerationRule200Response`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::CreateAutoModerationRule200Response`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::AutoModerationRuleResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::AutoModerationRuleResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -6014,7 +6014,7 @@ pub async fn get_bot_gateway(configuration: &configuration::Configuration, ) ->
     }
 }
 
-pub async fn get_channel(configuration: &configuration::Configuration, channel_id: &str) -> Result<models::GetChannel200Response, Error<GetChannelError>> {
+pub async fn get_channel(configuration: &configuration::Configuration, channel_id: &str) -> Result<models::ChannelResponse, Error<GetChannelError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_channel_id = channel_id;
 
@@ -6048,8 +6048,8 @@ pub async fn get_channel(configuration: &configuration::Configuration, channel_i
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::GetChannel200Response`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetChannel200Response`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ChannelResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ChannelResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -6669,7 +6669,7 @@ pub async fn get_guild_role(configuration: &configuration::Configuration, guild_
     }
 }
 
-pub async fn get_guild_scheduled_event(configuration: &configuration::Configuration, guild_id: &str, guild_scheduled_event_id: &str, with_user_count: Option<bool>) -> Result<models::ListGuildScheduledEvents200ResponseInner, Error<GetGuildScheduledEventError>> {
+pub async fn get_guild_scheduled_event(configuration: &configuration::Configuration, guild_id: &str, guild_scheduled_event_id: &str, with_user_count: Option<bool>) -> Result<models::GuildScheduledEventResponse, Error<GetGuildScheduledEventError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_guild_id = guild_id;
     let p_path_guild_scheduled_event_id = guild_scheduled_event_id;
@@ -6708,8 +6708,8 @@ pub async fn get_guild_scheduled_event(configuration: &configuration::Configurat
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ListGuildScheduledEvents200ResponseInner`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ListGuildScheduledEvents200ResponseInner`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::GuildScheduledEventResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GuildScheduledEventResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -6896,7 +6896,7 @@ pub async fn get_guild_vanity_url(configuration: &configuration::Configuration,
     }
 }
 
-pub async fn get_guild_webhooks(configuration: &configuration::Configuration, guild_id: &str) -> Result<Vec<models::ListChannelWebhooks200ResponseInner>, Error<GetGuildWebhooksError>> {
+pub async fn get_guild_webhooks(configuration: &configuration::Configuration, guild_id: &str) -> Result<Vec<models::WebhookResponse>, Error<GetGuildWebhooksError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_guild_id = guild_id;
 
@@ -6930,8 +6930,8 @@ pub async fn get_guild_webhooks(configuration: &configuration::Configuration, gu
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `Vec&lt;models::ListChannelWebhooks200ResponseInner&gt;`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `Vec&lt;models::ListChannelWebhooks200ResponseInner&gt;`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `Vec&lt;models::WebhookResponse&gt;`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `Vec&lt;models::WebhookResponse&gt;`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -7813,7 +7813,7 @@ pub async fn get_stage_instance(configuration: &configuration::Configuration, ch
     }
 }
 
-pub async fn get_sticker(configuration: &configuration::Configuration, sticker_id: &str) -> Result<models::GetSticker200Response, Error<GetStickerError>> {
+pub async fn get_sticker(configuration: &configuration::Configuration, sticker_id: &str) -> Result<models::StickerResponse, Error<GetStickerError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_sticker_id = sticker_id;
 
@@ -7847,8 +7847,8 @@ pub async fn get_sticker(configuration: &configuration::Configuration, sticker_i
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::GetSticker200Response`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetSticker200Response`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::StickerResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::StickerResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -8039,7 +8039,7 @@ pub async fn get_voice_state(configuration: &configuration::Configuration, guild
     }
 }
 
-pub async fn get_webhook(configuration: &configuration::Configuration, webhook_id: &str) -> Result<models::ListChannelWebhooks200ResponseInner, Error<GetWebhookError>> {
+pub async fn get_webhook(configuration: &configuration::Configuration, webhook_id: &str) -> Result<models::WebhookResponse, Error<GetWebhookError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_webhook_id = webhook_id;
 
@@ -8073,8 +8073,8 @@ pub async fn get_webhook(configuration: &configuration::Configuration, webhook_i
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ListChannelWebhooks200ResponseInner`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ListChannelWebhooks200ResponseInner`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::WebhookResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::WebhookResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -8083,7 +8083,7 @@ pub async fn get_webhook(configuration: &configuration::Configuration, webhook_i
     }
 }
 
-pub async fn get_webhook_by_token(configuration: &configuration::Configuration, webhook_id: &str, webhook_token: &str) -> Result<models::ListChannelWebhooks200ResponseInner, Error<GetWebhookByTokenError>> {
+pub async fn get_webhook_by_token(configuration: &configuration::Configuration, webhook_id: &str, webhook_token: &str) -> Result<models::WebhookResponse, Error<GetWebhookByTokenError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_webhook_id = webhook_id;
     let p_path_webhook_token = webhook_token;
@@ -8118,8 +8118,8 @@ pub async fn get_webhook_by_token(configuration: &configuration::Configuration,
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ListChannelWebhooks200ResponseInner`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ListChannelWebhooks200ResponseInner`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::WebhookResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::WebhookResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -8178,7 +8178,7 @@ pub async fn get_webhook_message(configuration: &configuration::Configuration, w
     }
 }
 
-pub async fn invite_resolve(configuration: &configuration::Configuration, code: &str, with_counts: Option<bool>, guild_scheduled_event_id: Option<&str>) -> Result<models::CreateChannelInvite200Response, Error<InviteResolveError>> {
+pub async fn invite_resolve(configuration: &configuration::Configuration, code: &str, with_counts: Option<bool>, guild_scheduled_event_id: Option<&str>) -> Result<models::InviteResponse, Error<InviteResolveError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_code = code;
     let p_query_with_counts = with_counts;
@@ -8220,8 +8220,8 @@ pub async fn invite_resolve(configuration: &configuration::Configuration, code:
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::CreateChannelInvite200Response`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::CreateChannelInvite200Response`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::InviteResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::InviteResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -8230,7 +8230,7 @@ pub async fn invite_resolve(configuration: &configuration::Configuration, code:
     }
 }
 
-pub async fn invite_revoke(configuration: &configuration::Configuration, code: &str) -> Result<models::CreateChannelInvite200Response, Error<InviteRevokeError>> {
+pub async fn invite_revoke(configuration: &configuration::Configuration, code: &str) -> Result<models::InviteResponse, Error<InviteRevokeError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_code = code;
 
@@ -8264,8 +8264,8 @@ pub async fn invite_revoke(configuration: &configuration::Configuration, code: &
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::CreateChannelInvite200Response`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::CreateChannelInvite200Response`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::InviteResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::InviteResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -8516,7 +8516,7 @@ pub async fn list_application_emojis(configuration: &configuration::Configuratio
     }
 }
 
-pub async fn list_auto_moderation_rules(configuration: &configuration::Configuration, guild_id: &str) -> Result<Vec<models::ListAutoModerationRules200ResponseInner>, Error<ListAutoModerationRulesError>> {
+pub async fn list_auto_moderation_rules(configuration: &configuration::Configuration, guild_id: &str) -> Result<Vec<models::AutoModerationRuleResponse>, Error<ListAutoModerationRulesError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_guild_id = guild_id;
 
@@ -8550,8 +8550,8 @@ pub async fn list_auto_moderation_rules(configuration: &configuration::Configura
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `Vec&lt;models::ListAutoModerationRules200ResponseInner&gt;`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `Vec&lt;models::ListAutoModerationRules200ResponseInner&gt;`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `Vec&lt;models::AutoModerationRuleResponse&gt;`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `Vec&lt;models::AutoModerationRuleResponse&gt;`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -8560,7 +8560,7 @@ pub async fn list_auto_moderation_rules(configuration: &configuration::Configura
     }
 }
 
-pub async fn list_channel_invites(configuration: &configuration::Configuration, channel_id: &str) -> Result<Vec<models::ListChannelInvites200ResponseInner>, Error<ListChannelInvitesError>> {
+pub async fn list_channel_invites(configuration: &configuration::Configuration, channel_id: &str) -> Result<Vec<models::InviteResponse>, Error<ListChannelInvitesError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_channel_id = channel_id;
 
@@ -8594,8 +8594,8 @@ pub async fn list_channel_invites(configuration: &configuration::Configuration,
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `Vec&lt;models::ListChannelInvites200ResponseInner&gt;`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `Vec&lt;models::ListChannelInvites200ResponseInner&gt;`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `Vec&lt;models::InviteResponse&gt;`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `Vec&lt;models::InviteResponse&gt;`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -8604,7 +8604,7 @@ pub async fn list_channel_invites(configuration: &configuration::Configuration,
     }
 }
 
-pub async fn list_channel_webhooks(configuration: &configuration::Configuration, channel_id: &str) -> Result<Vec<models::ListChannelWebhooks200ResponseInner>, Error<ListChannelWebhooksError>> {
+pub async fn list_channel_webhooks(configuration: &configuration::Configuration, channel_id: &str) -> Result<Vec<models::WebhookResponse>, Error<ListChannelWebhooksError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_channel_id = channel_id;
 
@@ -8638,8 +8638,8 @@ pub async fn list_channel_webhooks(configuration: &configuration::Configuration,
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `Vec&lt;models::ListChannelWebhooks200ResponseInner&gt;`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `Vec&lt;models::ListChannelWebhooks200ResponseInner&gt;`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `Vec&lt;models::WebhookResponse&gt;`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `Vec&lt;models::WebhookResponse&gt;`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -8884,7 +8884,7 @@ pub async fn list_guild_bans(configuration: &configuration::Configuration, guild
     }
 }
 
-pub async fn list_guild_channels(configuration: &configuration::Configuration, guild_id: &str) -> Result<Vec<models::GetChannel200Response>, Error<ListGuildChannelsError>> {
+pub async fn list_guild_channels(configuration: &configuration::Configuration, guild_id: &str) -> Result<Vec<models::ChannelResponse>, Error<ListGuildChannelsError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_guild_id = guild_id;
 
@@ -8927,8 +8927,8 @@ pub async fn list_guild_channels(configuration: &configuration::Configuration, g
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `Vec&lt;models::GetChannel200Response&gt;`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `Vec&lt;models::GetChannel200Response&gt;`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `Vec&lt;models::ChannelResponse&gt;`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `Vec&lt;models::ChannelResponse&gt;`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -8981,7 +8981,7 @@ pub async fn list_guild_emojis(configuration: &configuration::Configuration, gui
     }
 }
 
-pub async fn list_guild_integrations(configuration: &configuration::Configuration, guild_id: &str) -> Result<Vec<models::ListGuildIntegrations200ResponseInner>, Error<ListGuildIntegrationsError>> {
+pub async fn list_guild_integrations(configuration: &configuration::Configuration, guild_id: &str) -> Result<Vec<models::IntegrationResponse>, Error<ListGuildIntegrationsError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_guild_id = guild_id;
 
@@ -9015,8 +9015,8 @@ pub async fn list_guild_integrations(configuration: &configuration::Configuratio
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `Vec&lt;models::ListGuildIntegrations200ResponseInner&gt;`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `Vec&lt;models::ListGuildIntegrations200ResponseInner&gt;`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `Vec&lt;models::IntegrationResponse&gt;`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `Vec&lt;models::IntegrationResponse&gt;`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -9025,7 +9025,7 @@ pub async fn list_guild_integrations(configuration: &configuration::Configuratio
     }
 }
 
-pub async fn list_guild_invites(configuration: &configuration::Configuration, guild_id: &str) -> Result<Vec<models::ListChannelInvites200ResponseInner>, Error<ListGuildInvitesError>> {
+pub async fn list_guild_invites(configuration: &configuration::Configuration, guild_id: &str) -> Result<Vec<models::InviteResponse>, Error<ListGuildInvitesError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_guild_id = guild_id;
 
@@ -9059,8 +9059,8 @@ pub async fn list_guild_invites(configuration: &configuration::Configuration, gu
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `Vec&lt;models::ListChannelInvites200ResponseInner&gt;`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `Vec&lt;models::ListChannelInvites200ResponseInner&gt;`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `Vec&lt;models::InviteResponse&gt;`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `Vec&lt;models::InviteResponse&gt;`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -9226,7 +9226,7 @@ pub async fn list_guild_scheduled_event_users(configuration: &configuration::Con
     }
 }
 
-pub async fn list_guild_scheduled_events(configuration: &configuration::Configuration, guild_id: &str, with_user_count: Option<bool>) -> Result<Vec<models::ListGuildScheduledEvents200ResponseInner>, Error<ListGuildScheduledEventsError>> {
+pub async fn list_guild_scheduled_events(configuration: &configuration::Configuration, guild_id: &str, with_user_count: Option<bool>) -> Result<Vec<models::GuildScheduledEventResponse>, Error<ListGuildScheduledEventsError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_guild_id = guild_id;
     let p_query_with_user_count = with_user_count;
@@ -9264,8 +9264,8 @@ pub async fn list_guild_scheduled_events(configuration: &configuration::Configur
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `Vec&lt;models::ListGuildScheduledEvents200ResponseInner&gt;`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `Vec&lt;models::ListGuildScheduledEvents200ResponseInner&gt;`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `Vec&lt;models::GuildScheduledEventResponse&gt;`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `Vec&lt;models::GuildScheduledEventResponse&gt;`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -10843,7 +10843,7 @@ pub async fn update_application_emoji(configuration: &configuration::Configurati
     }
 }
 
-pub async fn update_application_role_connections_metadata(configuration: &configuration::Configuration, application_id: &str, application_role_connections_metadata_item_request: Option<Vec<models::ApplicationRoleConnectionsMetadataItemRequest>>) -> Result<Vec<models::ApplicationRoleConnectionsMetadataItemResponse>, Error<UpdateApplicationRoleConnectionsMetadataError>> {
+pub async fn update_application_role_connections_metadata(configuration: &configuration::Configuration, application_id: &str, application_role_connections_metadata_item_request: Option<Vec<models::ApplicationRoleConnectionsMetadataItemRequest>>) -> Result<Vec<models::ApplicationRoleConnectionsMetadataItemRequest>, Error<UpdateApplicationRoleConnectionsMetadataError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_application_id = application_id;
     let p_body_application_role_connections_metadata_item_request = application_role_connections_metadata_item_request;
@@ -10879,8 +10879,8 @@ pub async fn update_application_role_connections_metadata(configuration: &config
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `Vec&lt;models::ApplicationRoleConnectionsMetadataItemResponse&gt;`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `Vec&lt;models::ApplicationRoleConnectionsMetadataItemResponse&gt;`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `Vec&lt;models::ApplicationRoleConnectionsMetadataItemRequest&gt;`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `Vec&lt;models::ApplicationRoleConnectionsMetadataItemRequest&gt;`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -10936,7 +10936,7 @@ pub async fn update_application_user_role_connection(configuration: &configurati
     }
 }
 
-pub async fn update_auto_moderation_rule(configuration: &configuration::Configuration, guild_id: &str, rule_id: &str, update_auto_moderation_rule_request: models::UpdateAutoModerationRuleRequest) -> Result<models::CreateAutoModerationRule200Response, Error<UpdateAutoModerationRuleError>> {
+pub async fn update_auto_moderation_rule(configuration: &configuration::Configuration, guild_id: &str, rule_id: &str, update_auto_moderation_rule_request: models::UpdateAutoModerationRuleRequest) -> Result<models::AutoModerationRuleResponse, Error<UpdateAutoModerationRuleError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_guild_id = guild_id;
     let p_path_rule_id = rule_id;
@@ -10973,8 +10973,8 @@ pub async fn update_auto_moderation_rule(configuration: &configuration::Configur
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::CreateAutoModerationRule200Response`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::CreateAutoModerationRule200Response`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::AutoModerationRuleResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::AutoModerationRuleResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -10983,7 +10983,7 @@ pub async fn update_auto_moderation_rule(configuration: &configuration::Configur
     }
 }
 
-pub async fn update_channel(configuration: &configuration::Configuration, channel_id: &str, update_channel_request: models::UpdateChannelRequest) -> Result<models::GetChannel200Response, Error<UpdateChannelError>> {
+pub async fn update_channel(configuration: &configuration::Configuration, channel_id: &str, update_channel_request: models::UpdateChannelRequest) -> Result<models::ChannelResponse, Error<UpdateChannelError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_channel_id = channel_id;
     let p_body_update_channel_request = update_channel_request;
@@ -11019,8 +11019,8 @@ pub async fn update_channel(configuration: &configuration::Configuration, channe
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::GetChannel200Response`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetChannel200Response`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ChannelResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ChannelResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -11273,7 +11273,7 @@ pub async fn update_guild_role(configuration: &configuration::Configuration, gui
     }
 }
 
-pub async fn update_guild_scheduled_event(configuration: &configuration::Configuration, guild_id: &str, guild_scheduled_event_id: &str, update_guild_scheduled_event_request: models::UpdateGuildScheduledEventRequest) -> Result<models::ListGuildScheduledEvents200ResponseInner, Error<UpdateGuildScheduledEventError>> {
+pub async fn update_guild_scheduled_event(configuration: &configuration::Configuration, guild_id: &str, guild_scheduled_event_id: &str, update_guild_scheduled_event_request: models::UpdateGuildScheduledEventRequest) -> Result<models::GuildScheduledEventResponse, Error<UpdateGuildScheduledEventError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_guild_id = guild_id;
     let p_path_guild_scheduled_event_id = guild_scheduled_event_id;
@@ -11310,8 +11310,8 @@ pub async fn update_guild_scheduled_event(configuration: &configuration::Configu
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ListGuildScheduledEvents200ResponseInner`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ListGuildScheduledEvents200ResponseInner`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::GuildScheduledEventResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GuildScheduledEventResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -11908,7 +11908,7 @@ pub async fn update_voice_state(configuration: &configuration::Configuration, gu
     }
 }
 
-pub async fn update_webhook(configuration: &configuration::Configuration, webhook_id: &str, update_webhook_request: models::UpdateWebhookRequest) -> Result<models::ListChannelWebhooks200ResponseInner, Error<UpdateWebhookError>> {
+pub async fn update_webhook(configuration: &configuration::Configuration, webhook_id: &str, update_webhook_request: models::UpdateWebhookRequest) -> Result<models::WebhookResponse, Error<UpdateWebhookError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_webhook_id = webhook_id;
     let p_body_update_webhook_request = update_webhook_request;
@@ -11944,8 +11944,8 @@ pub async fn update_webhook(configuration: &configuration::Configuration, webhoo
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ListChannelWebhooks200ResponseInner`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ListChannelWebhooks200ResponseInner`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::WebhookResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::WebhookResponse`")))),
         }
     } else {
         let content = resp.text().await?;
@@ -11954,7 +11954,7 @@ pub async fn update_webhook(configuration: &configuration::Configuration, webhoo
     }
 }
 
-pub async fn update_webhook_by_token(configuration: &configuration::Configuration, webhook_id: &str, webhook_token: &str, update_webhook_by_token_request: models::UpdateWebhookByTokenRequest) -> Result<models::ListChannelWebhooks200ResponseInner, Error<UpdateWebhookByTokenError>> {
+pub async fn update_webhook_by_token(configuration: &configuration::Configuration, webhook_id: &str, webhook_token: &str, update_webhook_by_token_request: models::UpdateWebhookByTokenRequest) -> Result<models::WebhookResponse, Error<UpdateWebhookByTokenError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_webhook_id = webhook_id;
     let p_path_webhook_token = webhook_token;
@@ -11991,8 +11991,8 @@ pub async fn update_webhook_by_token(configuration: &configuration::Configuratio
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ListChannelWebhooks200ResponseInner`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ListChannelWebhooks200ResponseInner`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::WebhookResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::WebhookResponse`")))),
         }
     } else {
         let content = resp.text().await?;
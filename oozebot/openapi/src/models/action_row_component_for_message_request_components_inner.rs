@@ -15,11 +15,11 @@ use serde::{Deserialize, Serialize};
 #[serde(untagged)]
 pub enum ActionRowComponentForMessageRequestComponentsInner {
     ButtonComponentForMessageRequest(Box<models::ButtonComponentForMessageRequest>),
-    ChannelSelectComponentForMessageRequest(Box<models::ChannelSelectComponentForMessageRequest>),
-    MentionableSelectComponentForMessageRequest(Box<models::MentionableSelectComponentForMessageRequest>),
-    RoleSelectComponentForMessageRequest(Box<models::RoleSelectComponentForMessageRequest>),
-    StringSelectComponentForMessageRequest(Box<models::StringSelectComponentForMessageRequest>),
-    UserSelectComponentForMessageRequest(Box<models::UserSelectComponentForMessageRequest>),
+    ChannelSelectComponentForModalRequest(Box<models::ChannelSelectComponentForModalRequest>),
+    MentionableSelectComponentForModalRequest(Box<models::MentionableSelectComponentForModalRequest>),
+    RoleSelectComponentForModalRequest(Box<models::RoleSelectComponentForModalRequest>),
+    StringSelectComponentForModalRequest(Box<models::StringSelectComponentForModalRequest>),
+    UserSelectComponentForModalRequest(Box<models::UserSelectComponentForModalRequest>),
 }
 
 impl Default for ActionRowComponentForMessageRequestComponentsInner {
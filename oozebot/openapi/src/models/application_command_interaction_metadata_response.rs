@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ApplicationCommandInteractionMetadataResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "type")]
     pub r#type: i32,
     #[serde(rename = "user", skip_serializing_if = "Option::is_none")]
@@ -22,15 +22,15 @@ pub struct ApplicationCommandInteractionMetadataResponse {
     #[serde(rename = "authorizing_integration_owners")]
     pub authorizing_integration_owners: std::collections::HashMap<String, String>,
     #[serde(rename = "original_response_message_id", skip_serializing_if = "Option::is_none")]
-    pub original_response_message_id: Option<String>,
+    pub original_response_message_id: Option<models::Snowflake>,
     #[serde(rename = "target_user", skip_serializing_if = "Option::is_none")]
     pub target_user: Option<Box<models::UserResponse>>,
     #[serde(rename = "target_message_id", skip_serializing_if = "Option::is_none")]
-    pub target_message_id: Option<String>,
+    pub target_message_id: Option<models::Snowflake>,
 }
 
 impl ApplicationCommandInteractionMetadataResponse {
-    pub fn new(id: String, r#type: i32, authorizing_integration_owners: std::collections::HashMap<String, String>) -> ApplicationCommandInteractionMetadataResponse {
+    pub fn new(id: models::Snowflake, r#type: i32, authorizing_integration_owners: std::collections::HashMap<String, String>) -> ApplicationCommandInteractionMetadataResponse {
         ApplicationCommandInteractionMetadataResponse {
             id,
             r#type,
@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ApplicationCommandPermission {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "type")]
     pub r#type: i32,
     #[serde(rename = "permission")]
@@ -22,7 +22,7 @@ pub struct ApplicationCommandPermission {
 }
 
 impl ApplicationCommandPermission {
-    pub fn new(id: String, r#type: i32, permission: bool) -> ApplicationCommandPermission {
+    pub fn new(id: models::Snowflake, r#type: i32, permission: bool) -> ApplicationCommandPermission {
         ApplicationCommandPermission {
             id,
             r#type,
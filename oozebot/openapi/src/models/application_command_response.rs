@@ -14,11 +14,11 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ApplicationCommandResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "application_id")]
-    pub application_id: String,
+    pub application_id: models::Snowflake,
     #[serde(rename = "version")]
-    pub version: String,
+    pub version: models::Snowflake,
     #[serde(rename = "default_member_permissions", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub default_member_permissions: Option<Option<String>>,
     #[serde(rename = "type")]
@@ -36,7 +36,7 @@ pub struct ApplicationCommandResponse {
     #[serde(rename = "description_localizations", skip_serializing_if = "Option::is_none")]
     pub description_localizations: Option<std::collections::HashMap<String, String>>,
     #[serde(rename = "guild_id", skip_serializing_if = "Option::is_none")]
-    pub guild_id: Option<String>,
+    pub guild_id: Option<models::Snowflake>,
     #[serde(rename = "dm_permission", skip_serializing_if = "Option::is_none")]
     pub dm_permission: Option<bool>,
     #[serde(rename = "contexts", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -50,7 +50,7 @@ pub struct ApplicationCommandResponse {
 }
 
 impl ApplicationCommandResponse {
-    pub fn new(id: String, application_id: String, version: String, r#type: i32, name: String, description: String) -> ApplicationCommandResponse {
+    pub fn new(id: models::Snowflake, application_id: models::Snowflake, version: models::Snowflake, r#type: i32, name: String, description: String) -> ApplicationCommandResponse {
         ApplicationCommandResponse {
             id,
             application_id,
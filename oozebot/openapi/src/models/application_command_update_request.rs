@@ -36,7 +36,7 @@ pub struct ApplicationCommandUpdateRequest {
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub r#type: Option<i32>,
     #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<models::Snowflake>,
 }
 
 impl ApplicationCommandUpdateRequest {
@@ -20,7 +20,7 @@ pub struct ApplicationFormPartial {
     #[serde(rename = "cover_image", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub cover_image: Option<Option<String>>,
     #[serde(rename = "team_id", skip_serializing_if = "Option::is_none")]
-    pub team_id: Option<String>,
+    pub team_id: Option<models::Snowflake>,
     #[serde(rename = "flags", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub flags: Option<Option<i32>>,
     #[serde(rename = "interactions_endpoint_url", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
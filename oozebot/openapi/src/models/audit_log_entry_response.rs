@@ -14,13 +14,13 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AuditLogEntryResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "action_type", deserialize_with = "Option::deserialize")]
     pub action_type: Option<i32>,
     #[serde(rename = "user_id", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<String>,
+    pub user_id: Option<models::Snowflake>,
     #[serde(rename = "target_id", skip_serializing_if = "Option::is_none")]
-    pub target_id: Option<String>,
+    pub target_id: Option<models::Snowflake>,
     #[serde(rename = "changes", skip_serializing_if = "Option::is_none")]
     pub changes: Option<Vec<models::AuditLogObjectChangeResponse>>,
     #[serde(rename = "options", skip_serializing_if = "Option::is_none")]
@@ -30,7 +30,7 @@ pub struct AuditLogEntryResponse {
 }
 
 impl AuditLogEntryResponse {
-    pub fn new(id: String, action_type: Option<i32>) -> AuditLogEntryResponse {
+    pub fn new(id: models::Snowflake, action_type: Option<i32>) -> AuditLogEntryResponse {
         AuditLogEntryResponse {
             id,
             action_type,
@@ -13,7 +13,7 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
-pub enum CreateAutoModerationRule200Response {
+pub enum AutoModerationRuleResponse {
     DefaultKeywordRuleResponse(Box<models::DefaultKeywordRuleResponse>),
     KeywordRuleResponse(Box<models::KeywordRuleResponse>),
     MlSpamRuleResponse(Box<models::MlSpamRuleResponse>),
@@ -21,7 +21,7 @@ pub enum CreateAutoModerationRule200Response {
     SpamLinkRuleResponse(Box<models::SpamLinkRuleResponse>),
 }
 
-impl Default for CreateAutoModerationRule200Response {
+impl Default for AutoModerationRuleResponse {
     fn default() -> Self {
         Self::DefaultKeywordRuleResponse(Default::default())
     }
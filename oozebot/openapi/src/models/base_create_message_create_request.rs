@@ -20,7 +20,7 @@ pub struct BaseCreateMessageCreateRequest {
     #[serde(rename = "allowed_mentions", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub allowed_mentions: Option<Option<Box<models::MessageAllowedMentionsRequest>>>,
     #[serde(rename = "sticker_ids", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
-    pub sticker_ids: Option<Option<Vec<String>>>,
+    pub sticker_ids: Option<Option<Vec<models::Snowflake>>>,
     #[serde(rename = "components", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub components: Option<Option<Vec<models::BaseCreateMessageCreateRequestComponentsInner>>>,
     #[serde(rename = "flags", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -20,7 +20,7 @@ pub enum BaseCreateMessageCreateRequestComponentsInner {
     MediaGalleryComponentForMessageRequest(Box<models::MediaGalleryComponentForMessageRequest>),
     SectionComponentForMessageRequest(Box<models::SectionComponentForMessageRequest>),
     SeparatorComponentForMessageRequest(Box<models::SeparatorComponentForMessageRequest>),
-    TextDisplayComponentForMessageRequest(Box<models::TextDisplayComponentForMessageRequest>),
+    TextDisplayComponentForModalRequest(Box<models::TextDisplayComponentForModalRequest>),
 }
 
 impl Default for BaseCreateMessageCreateRequestComponentsInner {
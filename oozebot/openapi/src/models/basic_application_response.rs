@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BasicApplicationResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "icon", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -26,13 +26,13 @@ pub struct BasicApplicationResponse {
     #[serde(rename = "cover_image", skip_serializing_if = "Option::is_none")]
     pub cover_image: Option<String>,
     #[serde(rename = "primary_sku_id", skip_serializing_if = "Option::is_none")]
-    pub primary_sku_id: Option<String>,
+    pub primary_sku_id: Option<models::Snowflake>,
     #[serde(rename = "bot", skip_serializing_if = "Option::is_none")]
     pub bot: Option<Box<models::UserResponse>>,
 }
 
 impl BasicApplicationResponse {
-    pub fn new(id: String, name: String, description: String) -> BasicApplicationResponse {
+    pub fn new(id: models::Snowflake, name: String, description: String) -> BasicApplicationResponse {
         BasicApplicationResponse {
             id,
             name,
@@ -32,13 +32,13 @@ pub struct BasicGuildMemberResponse {
     #[serde(rename = "premium_since", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub premium_since: Option<Option<String>>,
     #[serde(rename = "roles")]
-    pub roles: Vec<String>,
+    pub roles: Vec<models::Snowflake>,
     #[serde(rename = "collectibles", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub collectibles: Option<Option<Box<models::UserCollectiblesResponse>>>,
 }
 
 impl BasicGuildMemberResponse {
-    pub fn new(flags: i32, joined_at: String, pending: bool, roles: Vec<String>) -> BasicGuildMemberResponse {
+    pub fn new(flags: i32, joined_at: String, pending: bool, roles: Vec<models::Snowflake>) -> BasicGuildMemberResponse {
         BasicGuildMemberResponse {
             avatar: None,
             avatar_decoration_data: None,
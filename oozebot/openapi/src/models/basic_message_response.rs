@@ -20,7 +20,7 @@ pub struct BasicMessageResponse {
     #[serde(rename = "mentions")]
     pub mentions: Vec<models::UserResponse>,
     #[serde(rename = "mention_roles")]
-    pub mention_roles: Vec<String>,
+    pub mention_roles: Vec<models::Snowflake>,
     #[serde(rename = "attachments")]
     pub attachments: Vec<models::MessageAttachmentResponse>,
     #[serde(rename = "embeds")]
@@ -34,13 +34,13 @@ pub struct BasicMessageResponse {
     #[serde(rename = "components")]
     pub components: Vec<models::BasicMessageResponseComponentsInner>,
     #[serde(rename = "stickers", skip_serializing_if = "Option::is_none")]
-    pub stickers: Option<Vec<models::GetSticker200Response>>,
+    pub stickers: Option<Vec<models::StickerResponse>>,
     #[serde(rename = "sticker_items", skip_serializing_if = "Option::is_none")]
     pub sticker_items: Option<Vec<models::MessageStickerItemResponse>>,
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "channel_id")]
-    pub channel_id: String,
+    pub channel_id: models::Snowflake,
     #[serde(rename = "author")]
     pub author: Box<models::UserResponse>,
     #[serde(rename = "pinned")]
@@ -56,13 +56,13 @@ pub struct BasicMessageResponse {
     #[serde(rename = "application", skip_serializing_if = "Option::is_none")]
     pub application: Option<Box<models::BasicApplicationResponse>>,
     #[serde(rename = "application_id", skip_serializing_if = "Option::is_none")]
-    pub application_id: Option<String>,
+    pub application_id: Option<models::Snowflake>,
     #[serde(rename = "interaction", skip_serializing_if = "Option::is_none")]
     pub interaction: Option<Box<models::MessageInteractionResponse>>,
     #[serde(rename = "nonce", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub nonce: Option<Option<Box<models::BasicMessageResponseNonce>>>,
     #[serde(rename = "webhook_id", skip_serializing_if = "Option::is_none")]
-    pub webhook_id: Option<String>,
+    pub webhook_id: Option<models::Snowflake>,
     #[serde(rename = "message_reference", skip_serializing_if = "Option::is_none")]
     pub message_reference: Option<Box<models::MessageReferenceResponse>>,
     #[serde(rename = "thread", skip_serializing_if = "Option::is_none")]
@@ -88,7 +88,7 @@ pub struct BasicMessageResponse {
 }
 
 impl BasicMessageResponse {
-    pub fn new(r#type: i32, content: String, mentions: Vec<models::UserResponse>, mention_roles: Vec<String>, attachments: Vec<models::MessageAttachmentResponse>, embeds: Vec<models::MessageEmbedResponse>, timestamp: String, flags: i32, components: Vec<models::BasicMessageResponseComponentsInner>, id: String, channel_id: String, author: models::UserResponse, pinned: bool, mention_everyone: bool, tts: bool) -> BasicMessageResponse {
+    pub fn new(r#type: i32, content: String, mentions: Vec<models::UserResponse>, mention_roles: Vec<models::Snowflake>, attachments: Vec<models::MessageAttachmentResponse>, embeds: Vec<models::MessageEmbedResponse>, timestamp: String, flags: i32, components: Vec<models::BasicMessageResponseComponentsInner>, id: models::Snowflake, channel_id: models::Snowflake, author: models::UserResponse, pinned: bool, mention_everyone: bool, tts: bool) -> BasicMessageResponse {
         BasicMessageResponse {
             r#type,
             content,
@@ -16,7 +16,7 @@ pub struct BotAddGuildMemberRequest {
     #[serde(rename = "nick", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub nick: Option<Option<String>>,
     #[serde(rename = "roles", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
-    pub roles: Option<Option<Vec<String>>>,
+    pub roles: Option<Option<Vec<models::Snowflake>>>,
     #[serde(rename = "mute", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub mute: Option<Option<bool>>,
     #[serde(rename = "deaf", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -14,13 +14,13 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BulkBanUsersRequest {
     #[serde(rename = "user_ids")]
-    pub user_ids: Vec<String>,
+    pub user_ids: Vec<models::Snowflake>,
     #[serde(rename = "delete_message_seconds", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub delete_message_seconds: Option<Option<i32>>,
 }
 
 impl BulkBanUsersRequest {
-    pub fn new(user_ids: Vec<String>) -> BulkBanUsersRequest {
+    pub fn new(user_ids: Vec<models::Snowflake>) -> BulkBanUsersRequest {
         BulkBanUsersRequest {
             user_ids,
             delete_message_seconds: None,
@@ -14,13 +14,13 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BulkBanUsersResponse {
     #[serde(rename = "banned_users")]
-    pub banned_users: Vec<String>,
+    pub banned_users: Vec<models::Snowflake>,
     #[serde(rename = "failed_users")]
-    pub failed_users: Vec<String>,
+    pub failed_users: Vec<models::Snowflake>,
 }
 
 impl BulkBanUsersResponse {
-    pub fn new(banned_users: Vec<String>, failed_users: Vec<String>) -> BulkBanUsersResponse {
+    pub fn new(banned_users: Vec<models::Snowflake>, failed_users: Vec<models::Snowflake>) -> BulkBanUsersResponse {
         BulkBanUsersResponse {
             banned_users,
             failed_users,
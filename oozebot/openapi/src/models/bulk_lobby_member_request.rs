@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BulkLobbyMemberRequest {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "metadata", skip_serializing_if = "Option::is_none")]
     pub metadata: Option<std::collections::HashMap<String, String>>,
     #[serde(rename = "flags", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -24,7 +24,7 @@ pub struct BulkLobbyMemberRequest {
 }
 
 impl BulkLobbyMemberRequest {
-    pub fn new(id: String) -> BulkLobbyMemberRequest {
+    pub fn new(id: models::Snowflake) -> BulkLobbyMemberRequest {
         BulkLobbyMemberRequest {
             id,
             metadata: None,
@@ -28,7 +28,7 @@ pub struct ButtonComponentForMessageRequest {
     #[serde(rename = "url", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub url: Option<Option<String>>,
     #[serde(rename = "sku_id", skip_serializing_if = "Option::is_none")]
-    pub sku_id: Option<String>,
+    pub sku_id: Option<models::Snowflake>,
     #[serde(rename = "emoji", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub emoji: Option<Option<Box<models::ComponentEmojiForRequest>>>,
 }
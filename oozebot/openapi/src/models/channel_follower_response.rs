@@ -14,13 +14,13 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ChannelFollowerResponse {
     #[serde(rename = "channel_id")]
-    pub channel_id: String,
+    pub channel_id: models::Snowflake,
     #[serde(rename = "webhook_id")]
-    pub webhook_id: String,
+    pub webhook_id: models::Snowflake,
 }
 
 impl ChannelFollowerResponse {
-    pub fn new(channel_id: String, webhook_id: String) -> ChannelFollowerResponse {
+    pub fn new(channel_id: models::Snowflake, webhook_id: models::Snowflake) -> ChannelFollowerResponse {
         ChannelFollowerResponse {
             channel_id,
             webhook_id,
@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ChannelPermissionOverwriteRequest {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "type", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub r#type: Option<Option<i32>>,
     #[serde(rename = "allow", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -24,7 +24,7 @@ pub struct ChannelPermissionOverwriteRequest {
 }
 
 impl ChannelPermissionOverwriteRequest {
-    pub fn new(id: String) -> ChannelPermissionOverwriteRequest {
+    pub fn new(id: models::Snowflake) -> ChannelPermissionOverwriteRequest {
         ChannelPermissionOverwriteRequest {
             id,
             r#type: None,
@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ChannelPermissionOverwriteResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "type", deserialize_with = "Option::deserialize")]
     pub r#type: Option<i32>,
     #[serde(rename = "allow")]
@@ -24,7 +24,7 @@ pub struct ChannelPermissionOverwriteResponse {
 }
 
 impl ChannelPermissionOverwriteResponse {
-    pub fn new(id: String, r#type: Option<i32>, allow: String, deny: String) -> ChannelPermissionOverwriteResponse {
+    pub fn new(id: models::Snowflake, r#type: Option<i32>, allow: String, deny: String) -> ChannelPermissionOverwriteResponse {
         ChannelPermissionOverwriteResponse {
             id,
             r#type,
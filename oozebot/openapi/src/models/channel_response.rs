@@ -13,14 +13,14 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
-pub enum GetChannel200Response {
+pub enum ChannelResponse {
     GuildChannelResponse(Box<models::GuildChannelResponse>),
     PrivateChannelResponse(Box<models::PrivateChannelResponse>),
     PrivateGroupChannelResponse(Box<models::PrivateGroupChannelResponse>),
     ThreadResponse(Box<models::ThreadResponse>),
 }
 
-impl Default for GetChannel200Response {
+impl Default for ChannelResponse {
     fn default() -> Self {
         Self::GuildChannelResponse(Default::default())
     }
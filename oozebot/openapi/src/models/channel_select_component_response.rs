@@ -30,7 +30,7 @@ pub struct ChannelSelectComponentResponse {
     #[serde(rename = "channel_types", skip_serializing_if = "Option::is_none")]
     pub channel_types: Option<Vec<i32>>,
     #[serde(rename = "default_values", skip_serializing_if = "Option::is_none")]
-    pub default_values: Option<Vec<models::ChannelSelectDefaultValueResponse>>,
+    pub default_values: Option<Vec<models::ChannelSelectDefaultValue>>,
 }
 
 impl ChannelSelectComponentResponse {
@@ -16,11 +16,11 @@ pub struct ChannelSelectDefaultValue {
     #[serde(rename = "type", deserialize_with = "Option::deserialize")]
     pub r#type: Option<String>,
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
 }
 
 impl ChannelSelectDefaultValue {
-    pub fn new(r#type: Option<String>, id: String) -> ChannelSelectDefaultValue {
+    pub fn new(r#type: Option<String>, id: models::Snowflake) -> ChannelSelectDefaultValue {
         ChannelSelectDefaultValue {
             r#type,
             id,
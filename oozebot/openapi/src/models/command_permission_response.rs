@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CommandPermissionResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "type")]
     pub r#type: i32,
     #[serde(rename = "permission")]
@@ -22,7 +22,7 @@ pub struct CommandPermissionResponse {
 }
 
 impl CommandPermissionResponse {
-    pub fn new(id: String, r#type: i32, permission: bool) -> CommandPermissionResponse {
+    pub fn new(id: models::Snowflake, r#type: i32, permission: bool) -> CommandPermissionResponse {
         CommandPermissionResponse {
             id,
             r#type,
@@ -14,17 +14,17 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CommandPermissionsResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "application_id")]
-    pub application_id: String,
+    pub application_id: models::Snowflake,
     #[serde(rename = "guild_id")]
-    pub guild_id: String,
+    pub guild_id: models::Snowflake,
     #[serde(rename = "permissions")]
     pub permissions: Vec<models::CommandPermissionResponse>,
 }
 
 impl CommandPermissionsResponse {
-    pub fn new(id: String, application_id: String, guild_id: String, permissions: Vec<models::CommandPermissionResponse>) -> CommandPermissionsResponse {
+    pub fn new(id: models::Snowflake, application_id: models::Snowflake, guild_id: models::Snowflake, permissions: Vec<models::CommandPermissionResponse>) -> CommandPermissionsResponse {
         CommandPermissionsResponse {
             id,
             application_id,
@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ComponentEmojiResponse {
     #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<models::Snowflake>,
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "animated", skip_serializing_if = "Option::is_none")]
@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConnectedAccountGuildResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "icon", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub icon: Option<Option<String>>,
     #[serde(rename = "name")]
@@ -22,7 +22,7 @@ pub struct ConnectedAccountGuildResponse {
 }
 
 impl ConnectedAccountGuildResponse {
-    pub fn new(id: String, name: String) -> ConnectedAccountGuildResponse {
+    pub fn new(id: models::Snowflake, name: String) -> ConnectedAccountGuildResponse {
         ConnectedAccountGuildResponse {
             id,
             icon: None,
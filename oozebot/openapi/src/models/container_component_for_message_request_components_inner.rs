@@ -19,7 +19,7 @@ pub enum ContainerComponentForMessageRequestComponentsInner {
     MediaGalleryComponentForMessageRequest(Box<models::MediaGalleryComponentForMessageRequest>),
     SectionComponentForMessageRequest(Box<models::SectionComponentForMessageRequest>),
     SeparatorComponentForMessageRequest(Box<models::SeparatorComponentForMessageRequest>),
-    TextDisplayComponentForMessageRequest(Box<models::TextDisplayComponentForMessageRequest>),
+    TextDisplayComponentForModalRequest(Box<models::TextDisplayComponentForModalRequest>),
 }
 
 impl Default for ContainerComponentForMessageRequestComponentsInner {
@@ -14,15 +14,15 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CreateEntitlementRequestData {
     #[serde(rename = "sku_id")]
-    pub sku_id: String,
+    pub sku_id: models::Snowflake,
     #[serde(rename = "owner_id")]
-    pub owner_id: String,
+    pub owner_id: models::Snowflake,
     #[serde(rename = "owner_type")]
     pub owner_type: i32,
 }
 
 impl CreateEntitlementRequestData {
-    pub fn new(sku_id: String, owner_id: String, owner_type: i32) -> CreateEntitlementRequestData {
+    pub fn new(sku_id: models::Snowflake, owner_id: models::Snowflake, owner_type: i32) -> CreateEntitlementRequestData {
         CreateEntitlementRequestData {
             sku_id,
             owner_id,
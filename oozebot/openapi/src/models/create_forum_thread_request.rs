@@ -20,7 +20,7 @@ pub struct CreateForumThreadRequest {
     #[serde(rename = "rate_limit_per_user", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub rate_limit_per_user: Option<Option<i32>>,
     #[serde(rename = "applied_tags", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
-    pub applied_tags: Option<Option<Vec<String>>>,
+    pub applied_tags: Option<Option<Vec<models::Snowflake>>>,
     #[serde(rename = "message")]
     pub message: Box<models::BaseCreateMessageCreateRequest>,
 }
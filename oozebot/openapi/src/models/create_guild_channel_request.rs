@@ -30,7 +30,7 @@ pub struct CreateGuildChannelRequest {
     #[serde(rename = "rate_limit_per_user", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub rate_limit_per_user: Option<Option<i32>>,
     #[serde(rename = "parent_id", skip_serializing_if = "Option::is_none")]
-    pub parent_id: Option<String>,
+    pub parent_id: Option<models::Snowflake>,
     #[serde(rename = "permission_overwrites", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub permission_overwrites: Option<Option<Vec<models::ChannelPermissionOverwriteRequest>>>,
     #[serde(rename = "rtc_region", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -22,9 +22,9 @@ pub struct CreateGuildInviteRequest {
     #[serde(rename = "unique", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub unique: Option<Option<bool>>,
     #[serde(rename = "target_user_id", skip_serializing_if = "Option::is_none")]
-    pub target_user_id: Option<String>,
+    pub target_user_id: Option<models::Snowflake>,
     #[serde(rename = "target_application_id", skip_serializing_if = "Option::is_none")]
-    pub target_application_id: Option<String>,
+    pub target_application_id: Option<models::Snowflake>,
     #[serde(rename = "target_type", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub target_type: Option<Option<i32>>,
 }
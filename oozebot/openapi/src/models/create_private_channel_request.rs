@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CreatePrivateChannelRequest {
     #[serde(rename = "recipient_id", skip_serializing_if = "Option::is_none")]
-    pub recipient_id: Option<String>,
+    pub recipient_id: Option<models::Snowflake>,
     #[serde(rename = "access_tokens", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub access_tokens: Option<Option<Vec<String>>>,
     #[serde(rename = "nicks", skip_serializing_if = "Option::is_none")]
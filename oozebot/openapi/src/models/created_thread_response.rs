@@ -14,21 +14,21 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CreatedThreadResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "type")]
     pub r#type: i32,
     #[serde(rename = "last_message_id", skip_serializing_if = "Option::is_none")]
-    pub last_message_id: Option<String>,
+    pub last_message_id: Option<models::Snowflake>,
     #[serde(rename = "flags")]
     pub flags: i32,
     #[serde(rename = "last_pin_timestamp", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub last_pin_timestamp: Option<Option<String>>,
     #[serde(rename = "guild_id")]
-    pub guild_id: String,
+    pub guild_id: models::Snowflake,
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "parent_id", skip_serializing_if = "Option::is_none")]
-    pub parent_id: Option<String>,
+    pub parent_id: Option<models::Snowflake>,
     #[serde(rename = "rate_limit_per_user", skip_serializing_if = "Option::is_none")]
     pub rate_limit_per_user: Option<i32>,
     #[serde(rename = "bitrate", skip_serializing_if = "Option::is_none")]
@@ -42,7 +42,7 @@ pub struct CreatedThreadResponse {
     #[serde(rename = "permissions", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub permissions: Option<Option<String>>,
     #[serde(rename = "owner_id")]
-    pub owner_id: String,
+    pub owner_id: models::Snowflake,
     #[serde(rename = "thread_metadata")]
     pub thread_metadata: Box<models::ThreadMetadataResponse>,
     #[serde(rename = "message_count")]
@@ -52,13 +52,13 @@ pub struct CreatedThreadResponse {
     #[serde(rename = "total_message_sent")]
     pub total_message_sent: i32,
     #[serde(rename = "applied_tags", skip_serializing_if = "Option::is_none")]
-    pub applied_tags: Option<Vec<String>>,
+    pub applied_tags: Option<Vec<models::Snowflake>>,
     #[serde(rename = "member", skip_serializing_if = "Option::is_none")]
     pub member: Option<Box<models::ThreadMemberResponse>>,
 }
 
 impl CreatedThreadResponse {
-    pub fn new(id: String, r#type: i32, flags: i32, guild_id: String, name: String, owner_id: String, thread_metadata: models::ThreadMetadataResponse, message_count: i32, member_count: i32, total_message_sent: i32) -> CreatedThreadResponse {
+    pub fn new(id: models::Snowflake, r#type: i32, flags: i32, guild_id: models::Snowflake, name: String, owner_id: models::Snowflake, thread_metadata: models::ThreadMetadataResponse, message_count: i32, member_count: i32, total_message_sent: i32) -> CreatedThreadResponse {
         CreatedThreadResponse {
             id,
             r#type,
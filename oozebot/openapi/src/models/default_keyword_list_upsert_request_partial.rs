@@ -22,9 +22,9 @@ pub struct DefaultKeywordListUpsertRequestPartial {
     #[serde(rename = "enabled", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub enabled: Option<Option<bool>>,
     #[serde(rename = "exempt_roles", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
-    pub exempt_roles: Option<Option<Vec<String>>>,
+    pub exempt_roles: Option<Option<Vec<models::Snowflake>>>,
     #[serde(rename = "exempt_channels", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
-    pub exempt_channels: Option<Option<Vec<String>>>,
+    pub exempt_channels: Option<Option<Vec<models::Snowflake>>>,
     #[serde(rename = "trigger_type", skip_serializing_if = "Option::is_none")]
     pub trigger_type: Option<i32>,
     #[serde(rename = "trigger_metadata", skip_serializing_if = "Option::is_none")]
@@ -15,7 +15,7 @@ use serde::{Deserialize, Serialize};
 #[serde(untagged)]
 pub enum DefaultKeywordRuleResponseActionsInner {
     BlockMessageActionResponse(Box<models::BlockMessageActionResponse>),
-    FlagToChannelActionResponse(Box<models::FlagToChannelActionResponse>),
+    FlagToChannelAction(Box<models::FlagToChannelAction>),
     QuarantineUserActionResponse(Box<models::QuarantineUserActionResponse>),
     UserCommunicationDisabledActionResponse(Box<models::UserCommunicationDisabledActionResponse>),
 }
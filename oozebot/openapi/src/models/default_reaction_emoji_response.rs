@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DefaultReactionEmojiResponse {
     #[serde(rename = "emoji_id", skip_serializing_if = "Option::is_none")]
-    pub emoji_id: Option<String>,
+    pub emoji_id: Option<models::Snowflake>,
     #[serde(rename = "emoji_name", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub emoji_name: Option<Option<String>>,
 }
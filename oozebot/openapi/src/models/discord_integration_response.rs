@@ -22,7 +22,7 @@ pub struct DiscordIntegrationResponse {
     #[serde(rename = "enabled")]
     pub enabled: bool,
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "application")]
     pub application: Box<models::IntegrationApplicationResponse>,
     #[serde(rename = "scopes")]
@@ -32,7 +32,7 @@ pub struct DiscordIntegrationResponse {
 }
 
 impl DiscordIntegrationResponse {
-    pub fn new(r#type: Option<String>, account: models::AccountResponse, enabled: bool, id: String, application: models::IntegrationApplicationResponse, scopes: Vec<String>) -> DiscordIntegrationResponse {
+    pub fn new(r#type: Option<String>, account: models::AccountResponse, enabled: bool, id: models::Snowflake, application: models::IntegrationApplicationResponse, scopes: Vec<String>) -> DiscordIntegrationResponse {
         DiscordIntegrationResponse {
             r#type,
             name: None,
@@ -13,12 +13,12 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
-pub enum AddGroupDmUser201Response {
+pub enum DmChannelResponse {
     PrivateChannelResponse(Box<models::PrivateChannelResponse>),
     PrivateGroupChannelResponse(Box<models::PrivateGroupChannelResponse>),
 }
 
-impl Default for AddGroupDmUser201Response {
+impl Default for DmChannelResponse {
     fn default() -> Self {
         Self::PrivateChannelResponse(Default::default())
     }
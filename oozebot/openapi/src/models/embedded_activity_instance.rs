@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EmbeddedActivityInstance {
     #[serde(rename = "application_id")]
-    pub application_id: String,
+    pub application_id: models::Snowflake,
     #[serde(rename = "instance_id")]
     pub instance_id: String,
     #[serde(rename = "launch_id")]
@@ -22,11 +22,11 @@ pub struct EmbeddedActivityInstance {
     #[serde(rename = "location")]
     pub location: Box<models::EmbeddedActivityInstanceLocation>,
     #[serde(rename = "users")]
-    pub users: Vec<String>,
+    pub users: Vec<models::Snowflake>,
 }
 
 impl EmbeddedActivityInstance {
-    pub fn new(application_id: String, instance_id: String, launch_id: String, location: models::EmbeddedActivityInstanceLocation, users: Vec<String>) -> EmbeddedActivityInstance {
+    pub fn new(application_id: models::Snowflake, instance_id: String, launch_id: String, location: models::EmbeddedActivityInstanceLocation, users: Vec<models::Snowflake>) -> EmbeddedActivityInstance {
         EmbeddedActivityInstance {
             application_id,
             instance_id,
@@ -14,13 +14,13 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EmojiResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "user", skip_serializing_if = "Option::is_none")]
     pub user: Option<Box<models::UserResponse>>,
     #[serde(rename = "roles")]
-    pub roles: Vec<String>,
+    pub roles: Vec<models::Snowflake>,
     #[serde(rename = "require_colons")]
     pub require_colons: bool,
     #[serde(rename = "managed")]
@@ -32,7 +32,7 @@ pub struct EmojiResponse {
 }
 
 impl EmojiResponse {
-    pub fn new(id: String, name: String, roles: Vec<String>, require_colons: bool, managed: bool, animated: bool, available: bool) -> EmojiResponse {
+    pub fn new(id: models::Snowflake, name: String, roles: Vec<models::Snowflake>, require_colons: bool, managed: bool, animated: bool, available: bool) -> EmojiResponse {
         EmojiResponse {
             id,
             name,
@@ -14,15 +14,15 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EntitlementResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "sku_id")]
-    pub sku_id: String,
+    pub sku_id: models::Snowflake,
     #[serde(rename = "application_id")]
-    pub application_id: String,
+    pub application_id: models::Snowflake,
     #[serde(rename = "user_id")]
-    pub user_id: String,
+    pub user_id: models::Snowflake,
     #[serde(rename = "guild_id", skip_serializing_if = "Option::is_none")]
-    pub guild_id: Option<String>,
+    pub guild_id: Option<models::Snowflake>,
     #[serde(rename = "deleted")]
     pub deleted: bool,
     #[serde(rename = "starts_at", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -38,11 +38,11 @@ pub struct EntitlementResponse {
     #[serde(rename = "consumed", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub consumed: Option<Option<bool>>,
     #[serde(rename = "gifter_user_id", skip_serializing_if = "Option::is_none")]
-    pub gifter_user_id: Option<String>,
+    pub gifter_user_id: Option<models::Snowflake>,
 }
 
 impl EntitlementResponse {
-    pub fn new(id: String, sku_id: String, application_id: String, user_id: String, deleted: bool, r#type: Option<i32>) -> EntitlementResponse {
+    pub fn new(id: models::Snowflake, sku_id: models::Snowflake, application_id: models::Snowflake, user_id: models::Snowflake, deleted: bool, r#type: Option<i32>) -> EntitlementResponse {
         EntitlementResponse {
             id,
             sku_id,
@@ -36,7 +36,7 @@ pub struct ExternalConnectionIntegrationResponse {
     #[serde(rename = "synced_at", skip_serializing_if = "Option::is_none")]
     pub synced_at: Option<String>,
     #[serde(rename = "role_id", skip_serializing_if = "Option::is_none")]
-    pub role_id: Option<String>,
+    pub role_id: Option<models::Snowflake>,
     #[serde(rename = "syncing", skip_serializing_if = "Option::is_none")]
     pub syncing: Option<bool>,
     #[serde(rename = "enable_emoticons", skip_serializing_if = "Option::is_none")]
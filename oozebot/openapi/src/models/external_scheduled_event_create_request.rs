@@ -28,7 +28,7 @@ pub struct ExternalScheduledEventCreateRequest {
     #[serde(rename = "entity_type", deserialize_with = "Option::deserialize")]
     pub entity_type: Option<i32>,
     #[serde(rename = "channel_id", skip_serializing_if = "Option::is_none")]
-    pub channel_id: Option<String>,
+    pub channel_id: Option<models::Snowflake>,
     #[serde(rename = "entity_metadata")]
     pub entity_metadata: Box<models::EntityMetadataExternal>,
 }
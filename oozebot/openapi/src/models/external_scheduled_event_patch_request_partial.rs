@@ -30,7 +30,7 @@ pub struct ExternalScheduledEventPatchRequestPartial {
     #[serde(rename = "privacy_level", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub privacy_level: Option<Option<serde_json::Value>>,
     #[serde(rename = "channel_id", skip_serializing_if = "Option::is_none")]
-    pub channel_id: Option<String>,
+    pub channel_id: Option<models::Snowflake>,
     #[serde(rename = "entity_metadata", skip_serializing_if = "Option::is_none")]
     pub entity_metadata: Option<Box<models::EntityMetadataExternal>>,
 }
@@ -14,17 +14,17 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ExternalScheduledEventResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "guild_id")]
-    pub guild_id: String,
+    pub guild_id: models::Snowflake,
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "description", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub description: Option<Option<String>>,
     #[serde(rename = "channel_id", skip_serializing_if = "Option::is_none")]
-    pub channel_id: Option<String>,
+    pub channel_id: Option<models::Snowflake>,
     #[serde(rename = "creator_id", skip_serializing_if = "Option::is_none")]
-    pub creator_id: Option<String>,
+    pub creator_id: Option<models::Snowflake>,
     #[serde(rename = "creator", skip_serializing_if = "Option::is_none")]
     pub creator: Option<Box<models::UserResponse>>,
     #[serde(rename = "image", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -38,7 +38,7 @@ pub struct ExternalScheduledEventResponse {
     #[serde(rename = "entity_type", deserialize_with = "Option::deserialize")]
     pub entity_type: Option<i32>,
     #[serde(rename = "entity_id", skip_serializing_if = "Option::is_none")]
-    pub entity_id: Option<String>,
+    pub entity_id: Option<models::Snowflake>,
     #[serde(rename = "user_count", skip_serializing_if = "Option::is_none")]
     pub user_count: Option<i32>,
     #[serde(rename = "privacy_level", deserialize_with = "Option::deserialize")]
@@ -46,11 +46,11 @@ pub struct ExternalScheduledEventResponse {
     #[serde(rename = "user_rsvp", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub user_rsvp: Option<Option<Box<models::ScheduledEventUserResponse>>>,
     #[serde(rename = "entity_metadata")]
-    pub entity_metadata: Box<models::EntityMetadataExternalResponse>,
+    pub entity_metadata: Box<models::EntityMetadataExternal>,
 }
 
 impl ExternalScheduledEventResponse {
-    pub fn new(id: String, guild_id: String, name: String, scheduled_start_time: String, status: Option<i32>, entity_type: Option<i32>, privacy_level: Option<serde_json::Value>, entity_metadata: models::EntityMetadataExternalResponse) -> ExternalScheduledEventResponse {
+    pub fn new(id: models::Snowflake, guild_id: models::Snowflake, name: String, scheduled_start_time: String, status: Option<i32>, entity_type: Option<i32>, privacy_level: Option<serde_json::Value>, entity_metadata: models::EntityMetadataExternal) -> ExternalScheduledEventResponse {
         ExternalScheduledEventResponse {
             id,
             guild_id,
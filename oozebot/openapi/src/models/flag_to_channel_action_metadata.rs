@@ -14,11 +14,11 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FlagToChannelActionMetadata {
     #[serde(rename = "channel_id")]
-    pub channel_id: String,
+    pub channel_id: models::Snowflake,
 }
 
 impl FlagToChannelActionMetadata {
-    pub fn new(channel_id: String) -> FlagToChannelActionMetadata {
+    pub fn new(channel_id: models::Snowflake) -> FlagToChannelActionMetadata {
         FlagToChannelActionMetadata {
             channel_id,
         }
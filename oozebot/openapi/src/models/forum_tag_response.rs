@@ -14,19 +14,19 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ForumTagResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "moderated")]
     pub moderated: bool,
     #[serde(rename = "emoji_id", skip_serializing_if = "Option::is_none")]
-    pub emoji_id: Option<String>,
+    pub emoji_id: Option<models::Snowflake>,
     #[serde(rename = "emoji_name", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub emoji_name: Option<Option<String>>,
 }
 
 impl ForumTagResponse {
-    pub fn new(id: String, name: String, moderated: bool) -> ForumTagResponse {
+    pub fn new(id: models::Snowflake, name: String, moderated: bool) -> ForumTagResponse {
         ForumTagResponse {
             id,
             name,
@@ -20,19 +20,19 @@ pub struct GuildAuditLogResponse {
     #[serde(rename = "integrations")]
     pub integrations: Vec<models::GuildAuditLogResponseIntegrationsInner>,
     #[serde(rename = "webhooks")]
-    pub webhooks: Vec<models::ListChannelWebhooks200ResponseInner>,
+    pub webhooks: Vec<models::WebhookResponse>,
     #[serde(rename = "guild_scheduled_events")]
-    pub guild_scheduled_events: Vec<models::ListGuildScheduledEvents200ResponseInner>,
+    pub guild_scheduled_events: Vec<models::GuildScheduledEventResponse>,
     #[serde(rename = "threads")]
     pub threads: Vec<models::ThreadResponse>,
     #[serde(rename = "application_commands")]
     pub application_commands: Vec<models::ApplicationCommandResponse>,
     #[serde(rename = "auto_moderation_rules")]
-    pub auto_moderation_rules: Vec<models::ListAutoModerationRules200ResponseInner>,
+    pub auto_moderation_rules: Vec<models::AutoModerationRuleResponse>,
 }
 
 impl GuildAuditLogResponse {
-    pub fn new(audit_log_entries: Vec<models::AuditLogEntryResponse>, users: Vec<models::UserResponse>, integrations: Vec<models::GuildAuditLogResponseIntegrationsInner>, webhooks: Vec<models::ListChannelWebhooks200ResponseInner>, guild_scheduled_events: Vec<models::ListGuildScheduledEvents200ResponseInner>, threads: Vec<models::ThreadResponse>, application_commands: Vec<models::ApplicationCommandResponse>, auto_moderation_rules: Vec<models::ListAutoModerationRules200ResponseInner>) -> GuildAuditLogResponse {
+    pub fn new(audit_log_entries: Vec<models::AuditLogEntryResponse>, users: Vec<models::UserResponse>, integrations: Vec<models::GuildAuditLogResponseIntegrationsInner>, webhooks: Vec<models::WebhookResponse>, guild_scheduled_events: Vec<models::GuildScheduledEventResponse>, threads: Vec<models::ThreadResponse>, application_commands: Vec<models::ApplicationCommandResponse>, auto_moderation_rules: Vec<models::AutoModerationRuleResponse>) -> GuildAuditLogResponse {
         GuildAuditLogResponse {
             audit_log_entries,
             users,
@@ -18,13 +18,13 @@ pub struct GuildChannelLocation {
     #[serde(rename = "kind")]
     pub kind: String,
     #[serde(rename = "channel_id")]
-    pub channel_id: String,
+    pub channel_id: models::Snowflake,
     #[serde(rename = "guild_id")]
-    pub guild_id: String,
+    pub guild_id: models::Snowflake,
 }
 
 impl GuildChannelLocation {
-    pub fn new(id: String, kind: String, channel_id: String, guild_id: String) -> GuildChannelLocation {
+    pub fn new(id: String, kind: String, channel_id: models::Snowflake, guild_id: models::Snowflake) -> GuildChannelLocation {
         GuildChannelLocation {
             id,
             kind,
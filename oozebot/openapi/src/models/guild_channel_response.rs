@@ -14,21 +14,21 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct GuildChannelResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "type")]
     pub r#type: i32,
     #[serde(rename = "last_message_id", skip_serializing_if = "Option::is_none")]
-    pub last_message_id: Option<String>,
+    pub last_message_id: Option<models::Snowflake>,
     #[serde(rename = "flags")]
     pub flags: i32,
     #[serde(rename = "last_pin_timestamp", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub last_pin_timestamp: Option<Option<String>>,
     #[serde(rename = "guild_id")]
-    pub guild_id: String,
+    pub guild_id: models::Snowflake,
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "parent_id", skip_serializing_if = "Option::is_none")]
-    pub parent_id: Option<String>,
+    pub parent_id: Option<models::Snowflake>,
     #[serde(rename = "rate_limit_per_user", skip_serializing_if = "Option::is_none")]
     pub rate_limit_per_user: Option<i32>,
     #[serde(rename = "bitrate", skip_serializing_if = "Option::is_none")]
@@ -66,11 +66,11 @@ pub struct GuildChannelResponse {
     #[serde(rename = "hd_streaming_until", skip_serializing_if = "Option::is_none")]
     pub hd_streaming_until: Option<String>,
     #[serde(rename = "hd_streaming_buyer_id", skip_serializing_if = "Option::is_none")]
-    pub hd_streaming_buyer_id: Option<String>,
+    pub hd_streaming_buyer_id: Option<models::Snowflake>,
 }
 
 impl GuildChannelResponse {
-    pub fn new(id: String, r#type: i32, flags: i32, guild_id: String, name: String, position: i32) -> GuildChannelResponse {
+    pub fn new(id: models::Snowflake, r#type: i32, flags: i32, guild_id: models::Snowflake, name: String, position: i32) -> GuildChannelResponse {
         GuildChannelResponse {
             id,
             r#type,
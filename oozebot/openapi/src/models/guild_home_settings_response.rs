@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct GuildHomeSettingsResponse {
     #[serde(rename = "guild_id")]
-    pub guild_id: String,
+    pub guild_id: models::Snowflake,
     #[serde(rename = "enabled")]
     pub enabled: bool,
     #[serde(rename = "welcome_message", skip_serializing_if = "Option::is_none")]
@@ -26,7 +26,7 @@ pub struct GuildHomeSettingsResponse {
 }
 
 impl GuildHomeSettingsResponse {
-    pub fn new(guild_id: String, enabled: bool, new_member_actions: Vec<models::NewMemberActionResponse>, resource_channels: Vec<models::ResourceChannelResponse>) -> GuildHomeSettingsResponse {
+    pub fn new(guild_id: models::Snowflake, enabled: bool, new_member_actions: Vec<models::NewMemberActionResponse>, resource_channels: Vec<models::ResourceChannelResponse>) -> GuildHomeSettingsResponse {
         GuildHomeSettingsResponse {
             guild_id,
             enabled,
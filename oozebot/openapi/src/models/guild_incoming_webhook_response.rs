@@ -14,15 +14,15 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct GuildIncomingWebhookResponse {
     #[serde(rename = "application_id", skip_serializing_if = "Option::is_none")]
-    pub application_id: Option<String>,
+    pub application_id: Option<models::Snowflake>,
     #[serde(rename = "avatar", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub avatar: Option<Option<String>>,
     #[serde(rename = "channel_id", skip_serializing_if = "Option::is_none")]
-    pub channel_id: Option<String>,
+    pub channel_id: Option<models::Snowflake>,
     #[serde(rename = "guild_id", skip_serializing_if = "Option::is_none")]
-    pub guild_id: Option<String>,
+    pub guild_id: Option<models::Snowflake>,
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "type")]
@@ -36,7 +36,7 @@ pub struct GuildIncomingWebhookResponse {
 }
 
 impl GuildIncomingWebhookResponse {
-    pub fn new(id: String, name: String, r#type: i32) -> GuildIncomingWebhookResponse {
+    pub fn new(id: models::Snowflake, name: String, r#type: i32) -> GuildIncomingWebhookResponse {
         GuildIncomingWebhookResponse {
             application_id: None,
             avatar: None,
@@ -32,7 +32,7 @@ pub struct GuildInviteResponse {
     #[serde(rename = "guild")]
     pub guild: Box<models::InviteGuildResponse>,
     #[serde(rename = "guild_id")]
-    pub guild_id: String,
+    pub guild_id: models::Snowflake,
     #[serde(rename = "channel")]
     pub channel: Box<models::InviteChannelResponse>,
     #[serde(rename = "target_type", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -58,7 +58,7 @@ pub struct GuildInviteResponse {
 }
 
 impl GuildInviteResponse {
-    pub fn new(r#type: Option<i32>, code: String, guild: models::InviteGuildResponse, guild_id: String, channel: models::InviteChannelResponse) -> GuildInviteResponse {
+    pub fn new(r#type: Option<i32>, code: String, guild: models::InviteGuildResponse, guild_id: models::Snowflake, channel: models::InviteChannelResponse) -> GuildInviteResponse {
         GuildInviteResponse {
             r#type,
             code,
@@ -32,9 +32,9 @@ pub struct GuildPatchRequestPartial {
     #[serde(rename = "afk_timeout", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub afk_timeout: Option<Option<i32>>,
     #[serde(rename = "afk_channel_id", skip_serializing_if = "Option::is_none")]
-    pub afk_channel_id: Option<String>,
+    pub afk_channel_id: Option<models::Snowflake>,
     #[serde(rename = "system_channel_id", skip_serializing_if = "Option::is_none")]
-    pub system_channel_id: Option<String>,
+    pub system_channel_id: Option<models::Snowflake>,
     #[serde(rename = "splash", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub splash: Option<Option<String>>,
     #[serde(rename = "banner", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -48,11 +48,11 @@ pub struct GuildPatchRequestPartial {
     #[serde(rename = "home_header", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub home_header: Option<Option<String>>,
     #[serde(rename = "rules_channel_id", skip_serializing_if = "Option::is_none")]
-    pub rules_channel_id: Option<String>,
+    pub rules_channel_id: Option<models::Snowflake>,
     #[serde(rename = "safety_alerts_channel_id", skip_serializing_if = "Option::is_none")]
-    pub safety_alerts_channel_id: Option<String>,
+    pub safety_alerts_channel_id: Option<models::Snowflake>,
     #[serde(rename = "public_updates_channel_id", skip_serializing_if = "Option::is_none")]
-    pub public_updates_channel_id: Option<String>,
+    pub public_updates_channel_id: Option<models::Snowflake>,
     #[serde(rename = "premium_progress_bar_enabled", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub premium_progress_bar_enabled: Option<Option<bool>>,
 }
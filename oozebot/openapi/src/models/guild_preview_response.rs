@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct GuildPreviewResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "icon", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -40,7 +40,7 @@ pub struct GuildPreviewResponse {
 }
 
 impl GuildPreviewResponse {
-    pub fn new(id: String, name: String, features: Vec<String>, approximate_member_count: i32, approximate_presence_count: i32, emojis: Vec<models::EmojiResponse>, stickers: Vec<models::GuildStickerResponse>) -> GuildPreviewResponse {
+    pub fn new(id: models::Snowflake, name: String, features: Vec<String>, approximate_member_count: i32, approximate_presence_count: i32, emojis: Vec<models::EmojiResponse>, stickers: Vec<models::GuildStickerResponse>) -> GuildPreviewResponse {
         GuildPreviewResponse {
             id,
             name,
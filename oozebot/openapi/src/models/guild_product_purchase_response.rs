@@ -14,13 +14,13 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct GuildProductPurchaseResponse {
     #[serde(rename = "listing_id")]
-    pub listing_id: String,
+    pub listing_id: models::Snowflake,
     #[serde(rename = "product_name")]
     pub product_name: String,
 }
 
 impl GuildProductPurchaseResponse {
-    pub fn new(listing_id: String, product_name: String) -> GuildProductPurchaseResponse {
+    pub fn new(listing_id: models::Snowflake, product_name: String) -> GuildProductPurchaseResponse {
         GuildProductPurchaseResponse {
             listing_id,
             product_name,
@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct GuildResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "icon", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -32,23 +32,23 @@ pub struct GuildResponse {
     #[serde(rename = "banner", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub banner: Option<Option<String>>,
     #[serde(rename = "owner_id")]
-    pub owner_id: String,
+    pub owner_id: models::Snowflake,
     #[serde(rename = "application_id", skip_serializing_if = "Option::is_none")]
-    pub application_id: Option<String>,
+    pub application_id: Option<models::Snowflake>,
     #[serde(rename = "region")]
     pub region: String,
     #[serde(rename = "afk_channel_id", skip_serializing_if = "Option::is_none")]
-    pub afk_channel_id: Option<String>,
+    pub afk_channel_id: Option<models::Snowflake>,
     #[serde(rename = "afk_timeout", deserialize_with = "Option::deserialize")]
     pub afk_timeout: Option<i32>,
     #[serde(rename = "system_channel_id", skip_serializing_if = "Option::is_none")]
-    pub system_channel_id: Option<String>,
+    pub system_channel_id: Option<models::Snowflake>,
     #[serde(rename = "system_channel_flags")]
     pub system_channel_flags: i32,
     #[serde(rename = "widget_enabled")]
     pub widget_enabled: bool,
     #[serde(rename = "widget_channel_id", skip_serializing_if = "Option::is_none")]
-    pub widget_channel_id: Option<String>,
+    pub widget_channel_id: Option<models::Snowflake>,
     #[serde(rename = "verification_level")]
     pub verification_level: i32,
     #[serde(rename = "roles")]
@@ -76,11 +76,11 @@ pub struct GuildResponse {
     #[serde(rename = "preferred_locale")]
     pub preferred_locale: String,
     #[serde(rename = "rules_channel_id", skip_serializing_if = "Option::is_none")]
-    pub rules_channel_id: Option<String>,
+    pub rules_channel_id: Option<models::Snowflake>,
     #[serde(rename = "safety_alerts_channel_id", skip_serializing_if = "Option::is_none")]
-    pub safety_alerts_channel_id: Option<String>,
+    pub safety_alerts_channel_id: Option<models::Snowflake>,
     #[serde(rename = "public_updates_channel_id", skip_serializing_if = "Option::is_none")]
-    pub public_updates_channel_id: Option<String>,
+    pub public_updates_channel_id: Option<models::Snowflake>,
     #[serde(rename = "premium_progress_bar_enabled")]
     pub premium_progress_bar_enabled: bool,
     #[serde(rename = "nsfw")]
@@ -94,7 +94,7 @@ pub struct GuildResponse {
 }
 
 impl GuildResponse {
-    pub fn new(id: String, name: String, features: Vec<String>, owner_id: String, region: String, afk_timeout: Option<i32>, system_channel_flags: i32, widget_enabled: bool, verification_level: i32, roles: Vec<models::GuildRoleResponse>, default_message_notifications: i32, mfa_level: i32, explicit_content_filter: i32, max_members: i32, max_stage_video_channel_users: i32, max_video_channel_users: i32, premium_tier: i32, premium_subscription_count: i32, preferred_locale: String, premium_progress_bar_enabled: bool, nsfw: bool, nsfw_level: Option<i32>, emojis: Vec<models::EmojiResponse>, stickers: Vec<models::GuildStickerResponse>) -> GuildResponse {
+    pub fn new(id: models::Snowflake, name: String, features: Vec<String>, owner_id: models::Snowflake, region: String, afk_timeout: Option<i32>, system_channel_flags: i32, widget_enabled: bool, verification_level: i32, roles: Vec<models::GuildRoleResponse>, default_message_notifications: i32, mfa_level: i32, explicit_content_filter: i32, max_members: i32, max_stage_video_channel_users: i32, max_video_channel_users: i32, premium_tier: i32, premium_subscription_count: i32, preferred_locale: String, premium_progress_bar_enabled: bool, nsfw: bool, nsfw_level: Option<i32>, emojis: Vec<models::EmojiResponse>, stickers: Vec<models::GuildStickerResponse>) -> GuildResponse {
         GuildResponse {
             id,
             name,
@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct GuildRoleResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "description", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -44,7 +44,7 @@ pub struct GuildRoleResponse {
 }
 
 impl GuildRoleResponse {
-    pub fn new(id: String, name: String, permissions: String, position: i32, color: i32, colors: models::GuildRoleColorsResponse, hoist: bool, managed: bool, mentionable: bool, flags: i32) -> GuildRoleResponse {
+    pub fn new(id: models::Snowflake, name: String, permissions: String, position: i32, color: i32, colors: models::GuildRoleColorsResponse, hoist: bool, managed: bool, mentionable: bool, flags: i32) -> GuildRoleResponse {
         GuildRoleResponse {
             id,
             name,
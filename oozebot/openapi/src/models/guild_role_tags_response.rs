@@ -14,17 +14,17 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct GuildRoleTagsResponse {
     #[serde(rename = "premium_subscriber", skip_serializing_if = "Option::is_none")]
-    pub premium_subscriber: Option<models::Null>,
+    pub premium_subscriber: Option<serde_json::Value>,
     #[serde(rename = "bot_id", skip_serializing_if = "Option::is_none")]
-    pub bot_id: Option<String>,
+    pub bot_id: Option<models::Snowflake>,
     #[serde(rename = "integration_id", skip_serializing_if = "Option::is_none")]
-    pub integration_id: Option<String>,
+    pub integration_id: Option<models::Snowflake>,
     #[serde(rename = "subscription_listing_id", skip_serializing_if = "Option::is_none")]
-    pub subscription_listing_id: Option<String>,
+    pub subscription_listing_id: Option<models::Snowflake>,
     #[serde(rename = "available_for_purchase", skip_serializing_if = "Option::is_none")]
-    pub available_for_purchase: Option<models::Null>,
+    pub available_for_purchase: Option<serde_json::Value>,
     #[serde(rename = "guild_connections", skip_serializing_if = "Option::is_none")]
-    pub guild_connections: Option<models::Null>,
+    pub guild_connections: Option<serde_json::Value>,
 }
 
 impl GuildRoleTagsResponse {
@@ -13,13 +13,13 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
-pub enum ListGuildScheduledEvents200ResponseInner {
+pub enum GuildScheduledEventResponse {
     ExternalScheduledEventResponse(Box<models::ExternalScheduledEventResponse>),
     StageScheduledEventResponse(Box<models::StageScheduledEventResponse>),
     VoiceScheduledEventResponse(Box<models::VoiceScheduledEventResponse>),
 }
 
-impl Default for ListGuildScheduledEvents200ResponseInner {
+impl Default for GuildScheduledEventResponse {
     fn default() -> Self {
         Self::ExternalScheduledEventResponse(Default::default())
     }
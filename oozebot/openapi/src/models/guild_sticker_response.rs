@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct GuildStickerResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "tags")]
@@ -28,13 +28,13 @@ pub struct GuildStickerResponse {
     #[serde(rename = "available")]
     pub available: bool,
     #[serde(rename = "guild_id")]
-    pub guild_id: String,
+    pub guild_id: models::Snowflake,
     #[serde(rename = "user", skip_serializing_if = "Option::is_none")]
     pub user: Option<Box<models::UserResponse>>,
 }
 
 impl GuildStickerResponse {
-    pub fn new(id: String, name: String, tags: String, r#type: i32, available: bool, guild_id: String) -> GuildStickerResponse {
+    pub fn new(id: models::Snowflake, name: String, tags: String, r#type: i32, available: bool, guild_id: models::Snowflake) -> GuildStickerResponse {
         GuildStickerResponse {
             id,
             name,
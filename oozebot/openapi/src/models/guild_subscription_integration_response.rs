@@ -22,11 +22,11 @@ pub struct GuildSubscriptionIntegrationResponse {
     #[serde(rename = "enabled")]
     pub enabled: bool,
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
 }
 
 impl GuildSubscriptionIntegrationResponse {
-    pub fn new(r#type: Option<String>, account: models::AccountResponse, enabled: bool, id: String) -> GuildSubscriptionIntegrationResponse {
+    pub fn new(r#type: Option<String>, account: models::AccountResponse, enabled: bool, id: models::Snowflake) -> GuildSubscriptionIntegrationResponse {
         GuildSubscriptionIntegrationResponse {
             r#type,
             name: None,
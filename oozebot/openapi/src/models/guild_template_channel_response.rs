@@ -32,7 +32,7 @@ pub struct GuildTemplateChannelResponse {
     #[serde(rename = "rate_limit_per_user")]
     pub rate_limit_per_user: i32,
     #[serde(rename = "parent_id", skip_serializing_if = "Option::is_none")]
-    pub parent_id: Option<String>,
+    pub parent_id: Option<models::Snowflake>,
     #[serde(rename = "default_auto_archive_duration", skip_serializing_if = "Option::is_none")]
     pub default_auto_archive_duration: Option<i32>,
     #[serde(rename = "permission_overwrites")]
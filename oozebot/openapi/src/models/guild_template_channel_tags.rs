@@ -18,7 +18,7 @@ pub struct GuildTemplateChannelTags {
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "emoji_id", skip_serializing_if = "Option::is_none")]
-    pub emoji_id: Option<String>,
+    pub emoji_id: Option<models::Snowflake>,
     #[serde(rename = "emoji_name", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub emoji_name: Option<Option<String>>,
     #[serde(rename = "moderated", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -22,7 +22,7 @@ pub struct GuildTemplateResponse {
     #[serde(rename = "usage_count")]
     pub usage_count: i32,
     #[serde(rename = "creator_id")]
-    pub creator_id: String,
+    pub creator_id: models::Snowflake,
     #[serde(rename = "creator", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub creator: Option<Option<Box<models::UserResponse>>>,
     #[serde(rename = "created_at")]
@@ -30,7 +30,7 @@ pub struct GuildTemplateResponse {
     #[serde(rename = "updated_at")]
     pub updated_at: String,
     #[serde(rename = "source_guild_id")]
-    pub source_guild_id: String,
+    pub source_guild_id: models::Snowflake,
     #[serde(rename = "serialized_source_guild")]
     pub serialized_source_guild: Box<models::GuildTemplateSnapshotResponse>,
     #[serde(rename = "is_dirty", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -38,7 +38,7 @@ pub struct GuildTemplateResponse {
 }
 
 impl GuildTemplateResponse {
-    pub fn new(code: String, name: String, usage_count: i32, creator_id: String, created_at: String, updated_at: String, source_guild_id: String, serialized_source_guild: models::GuildTemplateSnapshotResponse) -> GuildTemplateResponse {
+    pub fn new(code: String, name: String, usage_count: i32, creator_id: models::Snowflake, created_at: String, updated_at: String, source_guild_id: models::Snowflake, serialized_source_guild: models::GuildTemplateSnapshotResponse) -> GuildTemplateResponse {
         GuildTemplateResponse {
             code,
             name,
@@ -28,11 +28,11 @@ pub struct GuildTemplateSnapshotResponse {
     #[serde(rename = "preferred_locale")]
     pub preferred_locale: String,
     #[serde(rename = "afk_channel_id", skip_serializing_if = "Option::is_none")]
-    pub afk_channel_id: Option<String>,
+    pub afk_channel_id: Option<models::Snowflake>,
     #[serde(rename = "afk_timeout", deserialize_with = "Option::deserialize")]
     pub afk_timeout: Option<i32>,
     #[serde(rename = "system_channel_id", skip_serializing_if = "Option::is_none")]
-    pub system_channel_id: Option<String>,
+    pub system_channel_id: Option<models::Snowflake>,
     #[serde(rename = "system_channel_flags")]
     pub system_channel_flags: i32,
     #[serde(rename = "roles")]
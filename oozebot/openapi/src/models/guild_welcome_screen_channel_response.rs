@@ -14,17 +14,17 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct GuildWelcomeScreenChannelResponse {
     #[serde(rename = "channel_id")]
-    pub channel_id: String,
+    pub channel_id: models::Snowflake,
     #[serde(rename = "description")]
     pub description: String,
     #[serde(rename = "emoji_id", skip_serializing_if = "Option::is_none")]
-    pub emoji_id: Option<String>,
+    pub emoji_id: Option<models::Snowflake>,
     #[serde(rename = "emoji_name", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub emoji_name: Option<Option<String>>,
 }
 
 impl GuildWelcomeScreenChannelResponse {
-    pub fn new(channel_id: String, description: String) -> GuildWelcomeScreenChannelResponse {
+    pub fn new(channel_id: models::Snowflake, description: String) -> GuildWelcomeScreenChannelResponse {
         GuildWelcomeScreenChannelResponse {
             channel_id,
             description,
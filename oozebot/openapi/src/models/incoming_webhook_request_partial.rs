@@ -36,7 +36,7 @@ pub struct IncomingWebhookRequestPartial {
     #[serde(rename = "thread_name", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub thread_name: Option<Option<String>>,
     #[serde(rename = "applied_tags", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
-    pub applied_tags: Option<Option<Vec<String>>>,
+    pub applied_tags: Option<Option<Vec<models::Snowflake>>>,
 }
 
 impl IncomingWebhookRequestPartial {
@@ -13,13 +13,13 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
-pub enum ListGuildIntegrations200ResponseInner {
+pub enum IntegrationResponse {
     DiscordIntegrationResponse(Box<models::DiscordIntegrationResponse>),
     ExternalConnectionIntegrationResponse(Box<models::ExternalConnectionIntegrationResponse>),
     GuildSubscriptionIntegrationResponse(Box<models::GuildSubscriptionIntegrationResponse>),
 }
 
-impl Default for ListGuildIntegrations200ResponseInner {
+impl Default for IntegrationResponse {
     fn default() -> Self {
         Self::DiscordIntegrationResponse(Default::default())
     }
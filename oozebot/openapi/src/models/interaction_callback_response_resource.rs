@@ -15,7 +15,7 @@ use serde::{Deserialize, Serialize};
 #[serde(untagged)]
 pub enum InteractionCallbackResponseResource {
     CreateMessageInteractionCallbackResponse(Box<models::CreateMessageInteractionCallbackResponse>),
-    LaunchActivityInteractionCallbackResponse(Box<models::LaunchActivityInteractionCallbackResponse>),
+    LaunchActivityInteractionCallbackRequest(Box<models::LaunchActivityInteractionCallbackRequest>),
     UpdateMessageInteractionCallbackResponse(Box<models::UpdateMessageInteractionCallbackResponse>),
 }
 
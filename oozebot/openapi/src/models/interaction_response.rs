@@ -14,23 +14,23 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct InteractionResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "type")]
     pub r#type: i32,
     #[serde(rename = "response_message_id", skip_serializing_if = "Option::is_none")]
-    pub response_message_id: Option<String>,
+    pub response_message_id: Option<models::Snowflake>,
     #[serde(rename = "response_message_loading", skip_serializing_if = "Option::is_none")]
     pub response_message_loading: Option<bool>,
     #[serde(rename = "response_message_ephemeral", skip_serializing_if = "Option::is_none")]
     pub response_message_ephemeral: Option<bool>,
     #[serde(rename = "channel_id", skip_serializing_if = "Option::is_none")]
-    pub channel_id: Option<String>,
+    pub channel_id: Option<models::Snowflake>,
     #[serde(rename = "guild_id", skip_serializing_if = "Option::is_none")]
-    pub guild_id: Option<String>,
+    pub guild_id: Option<models::Snowflake>,
 }
 
 impl InteractionResponse {
-    pub fn new(id: String, r#type: i32) -> InteractionResponse {
+    pub fn new(id: models::Snowflake, r#type: i32) -> InteractionResponse {
         InteractionResponse {
             id,
             r#type,
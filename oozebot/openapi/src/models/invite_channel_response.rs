@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct InviteChannelResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "type")]
     pub r#type: i32,
     #[serde(rename = "name", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -26,7 +26,7 @@ pub struct InviteChannelResponse {
 }
 
 impl InviteChannelResponse {
-    pub fn new(id: String, r#type: i32) -> InviteChannelResponse {
+    pub fn new(id: models::Snowflake, r#type: i32) -> InviteChannelResponse {
         InviteChannelResponse {
             id,
             r#type,
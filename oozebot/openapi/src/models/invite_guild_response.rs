@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct InviteGuildResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "splash", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -40,7 +40,7 @@ pub struct InviteGuildResponse {
 }
 
 impl InviteGuildResponse {
-    pub fn new(id: String, name: String, features: Vec<String>, premium_subscription_count: i32) -> InviteGuildResponse {
+    pub fn new(id: models::Snowflake, name: String, features: Vec<String>, premium_subscription_count: i32) -> InviteGuildResponse {
         InviteGuildResponse {
             id,
             name,
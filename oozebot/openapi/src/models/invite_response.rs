@@ -13,13 +13,13 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
-pub enum CreateChannelInvite200Response {
+pub enum InviteResponse {
     FriendInviteResponse(Box<models::FriendInviteResponse>),
     GroupDmInviteResponse(Box<models::GroupDmInviteResponse>),
     GuildInviteResponse(Box<models::GuildInviteResponse>),
 }
 
-impl Default for CreateChannelInvite200Response {
+impl Default for InviteResponse {
     fn default() -> Self {
         Self::FriendInviteResponse(Default::default())
     }
@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct LobbyMemberRequest {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "metadata", skip_serializing_if = "Option::is_none")]
     pub metadata: Option<std::collections::HashMap<String, String>>,
     #[serde(rename = "flags", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -22,7 +22,7 @@ pub struct LobbyMemberRequest {
 }
 
 impl LobbyMemberRequest {
-    pub fn new(id: String) -> LobbyMemberRequest {
+    pub fn new(id: models::Snowflake) -> LobbyMemberRequest {
         LobbyMemberRequest {
             id,
             metadata: None,
@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct LobbyMemberResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "metadata", skip_serializing_if = "Option::is_none")]
     pub metadata: Option<std::collections::HashMap<String, String>>,
     #[serde(rename = "flags")]
@@ -22,7 +22,7 @@ pub struct LobbyMemberResponse {
 }
 
 impl LobbyMemberResponse {
-    pub fn new(id: String, flags: i32) -> LobbyMemberResponse {
+    pub fn new(id: models::Snowflake, flags: i32) -> LobbyMemberResponse {
         LobbyMemberResponse {
             id,
             metadata: None,
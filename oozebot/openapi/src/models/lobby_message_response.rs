@@ -14,15 +14,15 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct LobbyMessageResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "type")]
     pub r#type: i32,
     #[serde(rename = "content")]
     pub content: String,
     #[serde(rename = "lobby_id")]
-    pub lobby_id: String,
+    pub lobby_id: models::Snowflake,
     #[serde(rename = "channel_id")]
-    pub channel_id: String,
+    pub channel_id: models::Snowflake,
     #[serde(rename = "author")]
     pub author: Box<models::UserResponse>,
     #[serde(rename = "metadata", skip_serializing_if = "Option::is_none")]
@@ -30,11 +30,11 @@ pub struct LobbyMessageResponse {
     #[serde(rename = "flags")]
     pub flags: i32,
     #[serde(rename = "application_id", skip_serializing_if = "Option::is_none")]
-    pub application_id: Option<String>,
+    pub application_id: Option<models::Snowflake>,
 }
 
 impl LobbyMessageResponse {
-    pub fn new(id: String, r#type: i32, content: String, lobby_id: String, channel_id: String, author: models::UserResponse, flags: i32) -> LobbyMessageResponse {
+    pub fn new(id: models::Snowflake, r#type: i32, content: String, lobby_id: models::Snowflake, channel_id: models::Snowflake, author: models::UserResponse, flags: i32) -> LobbyMessageResponse {
         LobbyMessageResponse {
             id,
             r#type,
@@ -14,9 +14,9 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct LobbyResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "application_id")]
-    pub application_id: String,
+    pub application_id: models::Snowflake,
     #[serde(rename = "metadata", skip_serializing_if = "Option::is_none")]
     pub metadata: Option<std::collections::HashMap<String, String>>,
     #[serde(rename = "members")]
@@ -30,7 +30,7 @@ pub struct LobbyResponse {
 }
 
 impl LobbyResponse {
-    pub fn new(id: String, application_id: String, members: Vec<models::LobbyMemberResponse>, flags: i64) -> LobbyResponse {
+    pub fn new(id: models::Snowflake, application_id: models::Snowflake, members: Vec<models::LobbyMemberResponse>, flags: i64) -> LobbyResponse {
         LobbyResponse {
             id,
             application_id,
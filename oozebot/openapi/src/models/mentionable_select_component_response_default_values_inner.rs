@@ -14,13 +14,13 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum MentionableSelectComponentResponseDefaultValuesInner {
-    RoleSelectDefaultValueResponse(Box<models::RoleSelectDefaultValueResponse>),
-    UserSelectDefaultValueResponse(Box<models::UserSelectDefaultValueResponse>),
+    RoleSelectDefaultValue(Box<models::RoleSelectDefaultValue>),
+    UserSelectDefaultValue(Box<models::UserSelectDefaultValue>),
 }
 
 impl Default for MentionableSelectComponentResponseDefaultValuesInner {
     fn default() -> Self {
-        Self::RoleSelectDefaultValueResponse(Default::default())
+        Self::RoleSelectDefaultValue(Default::default())
     }
 }
 
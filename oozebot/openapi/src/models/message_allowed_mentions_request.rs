@@ -16,9 +16,9 @@ pub struct MessageAllowedMentionsRequest {
     #[serde(rename = "parse", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub parse: Option<Option<Vec<String>>>,
     #[serde(rename = "users", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
-    pub users: Option<Option<Vec<String>>>,
+    pub users: Option<Option<Vec<models::Snowflake>>>,
     #[serde(rename = "roles", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
-    pub roles: Option<Option<Vec<String>>>,
+    pub roles: Option<Option<Vec<models::Snowflake>>>,
     #[serde(rename = "replied_user", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub replied_user: Option<Option<bool>>,
 }
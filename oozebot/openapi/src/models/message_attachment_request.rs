@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MessageAttachmentRequest {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "filename", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub filename: Option<Option<String>>,
     #[serde(rename = "description", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -30,7 +30,7 @@ pub struct MessageAttachmentRequest {
 }
 
 impl MessageAttachmentRequest {
-    pub fn new(id: String) -> MessageAttachmentRequest {
+    pub fn new(id: models::Snowflake) -> MessageAttachmentRequest {
         MessageAttachmentRequest {
             id,
             filename: None,
@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MessageAttachmentResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "filename")]
     pub filename: String,
     #[serde(rename = "size")]
@@ -48,7 +48,7 @@ pub struct MessageAttachmentResponse {
 }
 
 impl MessageAttachmentResponse {
-    pub fn new(id: String, filename: String, size: i32, url: String, proxy_url: String) -> MessageAttachmentResponse {
+    pub fn new(id: models::Snowflake, filename: String, size: i32, url: String, proxy_url: String) -> MessageAttachmentResponse {
         MessageAttachmentResponse {
             id,
             filename,
@@ -16,11 +16,11 @@ pub struct MessageCallResponse {
     #[serde(rename = "ended_timestamp", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub ended_timestamp: Option<Option<String>>,
     #[serde(rename = "participants")]
-    pub participants: Vec<String>,
+    pub participants: Vec<models::Snowflake>,
 }
 
 impl MessageCallResponse {
-    pub fn new(participants: Vec<String>) -> MessageCallResponse {
+    pub fn new(participants: Vec<models::Snowflake>) -> MessageCallResponse {
         MessageCallResponse {
             ended_timestamp: None,
             participants,
@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MessageInteractionResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "type")]
     pub r#type: i32,
     #[serde(rename = "name")]
@@ -26,7 +26,7 @@ pub struct MessageInteractionResponse {
 }
 
 impl MessageInteractionResponse {
-    pub fn new(id: String, r#type: i32, name: String) -> MessageInteractionResponse {
+    pub fn new(id: models::Snowflake, r#type: i32, name: String) -> MessageInteractionResponse {
         MessageInteractionResponse {
             id,
             r#type,
@@ -14,17 +14,17 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MessageMentionChannelResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "type")]
     pub r#type: i32,
     #[serde(rename = "guild_id")]
-    pub guild_id: String,
+    pub guild_id: models::Snowflake,
 }
 
 impl MessageMentionChannelResponse {
-    pub fn new(id: String, name: String, r#type: i32, guild_id: String) -> MessageMentionChannelResponse {
+    pub fn new(id: models::Snowflake, name: String, r#type: i32, guild_id: models::Snowflake) -> MessageMentionChannelResponse {
         MessageMentionChannelResponse {
             id,
             name,
@@ -14,11 +14,11 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MessageReferenceRequest {
     #[serde(rename = "guild_id", skip_serializing_if = "Option::is_none")]
-    pub guild_id: Option<String>,
+    pub guild_id: Option<models::Snowflake>,
     #[serde(rename = "channel_id", skip_serializing_if = "Option::is_none")]
-    pub channel_id: Option<String>,
+    pub channel_id: Option<models::Snowflake>,
     #[serde(rename = "message_id")]
-    pub message_id: String,
+    pub message_id: models::Snowflake,
     #[serde(rename = "fail_if_not_exists", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub fail_if_not_exists: Option<Option<bool>>,
     #[serde(rename = "type", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -26,7 +26,7 @@ pub struct MessageReferenceRequest {
 }
 
 impl MessageReferenceRequest {
-    pub fn new(message_id: String) -> MessageReferenceRequest {
+    pub fn new(message_id: models::Snowflake) -> MessageReferenceRequest {
         MessageReferenceRequest {
             guild_id: None,
             channel_id: None,
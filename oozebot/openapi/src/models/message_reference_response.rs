@@ -16,15 +16,15 @@ pub struct MessageReferenceResponse {
     #[serde(rename = "type", deserialize_with = "Option::deserialize")]
     pub r#type: Option<serde_json::Value>,
     #[serde(rename = "channel_id")]
-    pub channel_id: String,
+    pub channel_id: models::Snowflake,
     #[serde(rename = "message_id", skip_serializing_if = "Option::is_none")]
-    pub message_id: Option<String>,
+    pub message_id: Option<models::Snowflake>,
     #[serde(rename = "guild_id", skip_serializing_if = "Option::is_none")]
-    pub guild_id: Option<String>,
+    pub guild_id: Option<models::Snowflake>,
 }
 
 impl MessageReferenceResponse {
-    pub fn new(r#type: Option<serde_json::Value>, channel_id: String) -> MessageReferenceResponse {
+    pub fn new(r#type: Option<serde_json::Value>, channel_id: models::Snowflake) -> MessageReferenceResponse {
         MessageReferenceResponse {
             r#type,
             channel_id,
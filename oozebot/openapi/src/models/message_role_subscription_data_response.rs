@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MessageRoleSubscriptionDataResponse {
     #[serde(rename = "role_subscription_listing_id")]
-    pub role_subscription_listing_id: String,
+    pub role_subscription_listing_id: models::Snowflake,
     #[serde(rename = "tier_name")]
     pub tier_name: String,
     #[serde(rename = "total_months_subscribed")]
@@ -24,7 +24,7 @@ pub struct MessageRoleSubscriptionDataResponse {
 }
 
 impl MessageRoleSubscriptionDataResponse {
-    pub fn new(role_subscription_listing_id: String, tier_name: String, total_months_subscribed: i32, is_renewal: bool) -> MessageRoleSubscriptionDataResponse {
+    pub fn new(role_subscription_listing_id: models::Snowflake, tier_name: String, total_months_subscribed: i32, is_renewal: bool) -> MessageRoleSubscriptionDataResponse {
         MessageRoleSubscriptionDataResponse {
             role_subscription_listing_id,
             tier_name,
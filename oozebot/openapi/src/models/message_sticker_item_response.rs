@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MessageStickerItemResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "format_type", deserialize_with = "Option::deserialize")]
@@ -22,7 +22,7 @@ pub struct MessageStickerItemResponse {
 }
 
 impl MessageStickerItemResponse {
-    pub fn new(id: String, name: String, format_type: Option<i32>) -> MessageStickerItemResponse {
+    pub fn new(id: models::Snowflake, name: String, format_type: Option<i32>) -> MessageStickerItemResponse {
         MessageStickerItemResponse {
             id,
             name,
@@ -20,7 +20,7 @@ pub struct MinimalContentMessageResponse {
     #[serde(rename = "mentions")]
     pub mentions: Vec<models::UserResponse>,
     #[serde(rename = "mention_roles")]
-    pub mention_roles: Vec<String>,
+    pub mention_roles: Vec<models::Snowflake>,
     #[serde(rename = "attachments")]
     pub attachments: Vec<models::MessageAttachmentResponse>,
     #[serde(rename = "embeds")]
@@ -34,13 +34,13 @@ pub struct MinimalContentMessageResponse {
     #[serde(rename = "components")]
     pub components: Vec<models::BasicMessageResponseComponentsInner>,
     #[serde(rename = "stickers", skip_serializing_if = "Option::is_none")]
-    pub stickers: Option<Vec<models::GetSticker200Response>>,
+    pub stickers: Option<Vec<models::StickerResponse>>,
     #[serde(rename = "sticker_items", skip_serializing_if = "Option::is_none")]
     pub sticker_items: Option<Vec<models::MessageStickerItemResponse>>,
 }
 
 impl MinimalContentMessageResponse {
-    pub fn new(r#type: i32, content: String, mentions: Vec<models::UserResponse>, mention_roles: Vec<String>, attachments: Vec<models::MessageAttachmentResponse>, embeds: Vec<models::MessageEmbedResponse>, timestamp: String, flags: i32, components: Vec<models::BasicMessageResponseComponentsInner>) -> MinimalContentMessageResponse {
+    pub fn new(r#type: i32, content: String, mentions: Vec<models::UserResponse>, mention_roles: Vec<models::Snowflake>, attachments: Vec<models::MessageAttachmentResponse>, embeds: Vec<models::MessageEmbedResponse>, timestamp: String, flags: i32, components: Vec<models::BasicMessageResponseComponentsInner>) -> MinimalContentMessageResponse {
         MinimalContentMessageResponse {
             r#type,
             content,
@@ -14,11 +14,11 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MlSpamRuleResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "guild_id")]
-    pub guild_id: String,
+    pub guild_id: models::Snowflake,
     #[serde(rename = "creator_id")]
-    pub creator_id: String,
+    pub creator_id: models::Snowflake,
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "event_type")]
@@ -30,15 +30,15 @@ pub struct MlSpamRuleResponse {
     #[serde(rename = "enabled")]
     pub enabled: bool,
     #[serde(rename = "exempt_roles")]
-    pub exempt_roles: Vec<String>,
+    pub exempt_roles: Vec<models::Snowflake>,
     #[serde(rename = "exempt_channels")]
-    pub exempt_channels: Vec<String>,
+    pub exempt_channels: Vec<models::Snowflake>,
     #[serde(rename = "trigger_metadata")]
     pub trigger_metadata: serde_json::Value,
 }
 
 impl MlSpamRuleResponse {
-    pub fn new(id: String, guild_id: String, creator_id: String, name: String, event_type: i32, actions: Vec<models::DefaultKeywordRuleResponseActionsInner>, trigger_type: i32, enabled: bool, exempt_roles: Vec<String>, exempt_channels: Vec<String>, trigger_metadata: serde_json::Value) -> MlSpamRuleResponse {
+    pub fn new(id: models::Snowflake, guild_id: models::Snowflake, creator_id: models::Snowflake, name: String, event_type: i32, actions: Vec<models::DefaultKeywordRuleResponseActionsInner>, trigger_type: i32, enabled: bool, exempt_roles: Vec<models::Snowflake>, exempt_channels: Vec<models::Snowflake>, trigger_metadata: serde_json::Value) -> MlSpamRuleResponse {
         MlSpamRuleResponse {
             id,
             guild_id,
@@ -12,8 +12,6 @@ pub mod action_row_component_response_components_inner;
 pub use self::action_row_component_response_components_inner::ActionRowComponentResponseComponentsInner;
 pub mod activities_attachment_response;
 pub use self::activities_attachment_response::ActivitiesAttachmentResponse;
-pub mod add_group_dm_user_201_response;
-pub use self::add_group_dm_user_201_response::AddGroupDmUser201Response;
 pub mod add_group_dm_user_request;
 pub use self::add_group_dm_user_request::AddGroupDmUserRequest;
 pub mod add_lobby_member_request;
@@ -118,8 +116,6 @@ pub mod application_response;
 pub use self::application_response::ApplicationResponse;
 pub mod application_role_connections_metadata_item_request;
 pub use self::application_role_connections_metadata_item_request::ApplicationRoleConnectionsMetadataItemRequest;
-pub mod application_role_connections_metadata_item_response;
-pub use self::application_role_connections_metadata_item_response::ApplicationRoleConnectionsMetadataItemResponse;
 pub mod application_user_role_connection_response;
 pub use self::application_user_role_connection_response::ApplicationUserRoleConnectionResponse;
 pub mod attachment_response;
@@ -128,6 +124,8 @@ pub mod audit_log_entry_response;
 pub use self::audit_log_entry_response::AuditLogEntryResponse;
 pub mod audit_log_object_change_response;
 pub use self::audit_log_object_change_response::AuditLogObjectChangeResponse;
+pub mod auto_moderation_rule_response;
+pub use self::auto_moderation_rule_response::AutoModerationRuleResponse;
 pub mod ban_user_from_guild_request;
 pub use self::ban_user_from_guild_request::BanUserFromGuildRequest;
 pub mod base_create_message_create_request;
@@ -184,16 +182,14 @@ pub mod channel_permission_overwrite_request;
 pub use self::channel_permission_overwrite_request::ChannelPermissionOverwriteRequest;
 pub mod channel_permission_overwrite_response;
 pub use self::channel_permission_overwrite_response::ChannelPermissionOverwriteResponse;
-pub mod channel_select_component_for_message_request;
-pub use self::channel_select_component_for_message_request::ChannelSelectComponentForMessageRequest;
+pub mod channel_response;
+pub use self::channel_response::ChannelResponse;
 pub mod channel_select_component_for_modal_request;
 pub use self::channel_select_component_for_modal_request::ChannelSelectComponentForModalRequest;
 pub mod channel_select_component_response;
 pub use self::channel_select_component_response::ChannelSelectComponentResponse;
 pub mod channel_select_default_value;
 pub use self::channel_select_default_value::ChannelSelectDefaultValue;
-pub mod channel_select_default_value_response;
-pub use self::channel_select_default_value_response::ChannelSelectDefaultValueResponse;
 pub mod command_permission_response;
 pub use self::command_permission_response::CommandPermissionResponse;
 pub mod command_permissions_response;
@@ -218,12 +214,8 @@ pub mod container_component_response_components_inner;
 pub use self::container_component_response_components_inner::ContainerComponentResponseComponentsInner;
 pub mod create_application_emoji_request;
 pub use self::create_application_emoji_request::CreateApplicationEmojiRequest;
-pub mod create_auto_moderation_rule_200_response;
-pub use self::create_auto_moderation_rule_200_response::CreateAutoModerationRule200Response;
 pub mod create_auto_moderation_rule_request;
 pub use self::create_auto_moderation_rule_request::CreateAutoModerationRuleRequest;
-pub mod create_channel_invite_200_response;
-pub use self::create_channel_invite_200_response::CreateChannelInvite200Response;
 pub mod create_channel_invite_request;
 pub use self::create_channel_invite_request::CreateChannelInviteRequest;
 pub mod create_entitlement_request_data;
@@ -292,6 +284,8 @@ pub mod default_reaction_emoji_response;
 pub use self::default_reaction_emoji_response::DefaultReactionEmojiResponse;
 pub mod discord_integration_response;
 pub use self::discord_integration_response::DiscordIntegrationResponse;
+pub mod dm_channel_response;
+pub use self::dm_channel_response::DmChannelResponse;
 pub mod edit_lobby_channel_link_request;
 pub use self::edit_lobby_channel_link_request::EditLobbyChannelLinkRequest;
 pub mod embedded_activity_instance;
@@ -304,8 +298,6 @@ pub mod entitlement_response;
 pub use self::entitlement_response::EntitlementResponse;
 pub mod entity_metadata_external;
 pub use self::entity_metadata_external::EntityMetadataExternal;
-pub mod entity_metadata_external_response;
-pub use self::entity_metadata_external_response::EntityMetadataExternalResponse;
 pub mod error;
 pub use self::error::Error;
 pub mod error_details;
@@ -332,10 +324,6 @@ pub mod flag_to_channel_action;
 pub use self::flag_to_channel_action::FlagToChannelAction;
 pub mod flag_to_channel_action_metadata;
 pub use self::flag_to_channel_action_metadata::FlagToChannelActionMetadata;
-pub mod flag_to_channel_action_metadata_response;
-pub use self::flag_to_channel_action_metadata_response::FlagToChannelActionMetadataResponse;
-pub mod flag_to_channel_action_response;
-pub use self::flag_to_channel_action_response::FlagToChannelActionResponse;
 pub mod follow_channel_request;
 pub use self::follow_channel_request::FollowChannelRequest;
 pub mod forum_tag_response;
@@ -348,12 +336,8 @@ pub mod gateway_bot_session_start_limit_response;
 pub use self::gateway_bot_session_start_limit_response::GatewayBotSessionStartLimitResponse;
 pub mod gateway_response;
 pub use self::gateway_response::GatewayResponse;
-pub mod get_channel_200_response;
-pub use self::get_channel_200_response::GetChannel200Response;
 pub mod get_entitlements_sku_ids_parameter;
 pub use self::get_entitlements_sku_ids_parameter::GetEntitlementsSkuIdsParameter;
-pub mod get_sticker_200_response;
-pub use self::get_sticker_200_response::GetSticker200Response;
 pub mod github_author;
 pub use self::github_author::GithubAuthor;
 pub mod github_check_app;
@@ -422,6 +406,8 @@ pub mod guild_role_response;
 pub use self::guild_role_response::GuildRoleResponse;
 pub mod guild_role_tags_response;
 pub use self::guild_role_tags_response::GuildRoleTagsResponse;
+pub mod guild_scheduled_event_response;
+pub use self::guild_scheduled_event_response::GuildScheduledEventResponse;
 pub mod guild_sticker_response;
 pub use self::guild_sticker_response::GuildStickerResponse;
 pub mod guild_subscription_integration_response;
@@ -458,6 +444,8 @@ pub mod inner_errors;
 pub use self::inner_errors::InnerErrors;
 pub mod integration_application_response;
 pub use self::integration_application_response::IntegrationApplicationResponse;
+pub mod integration_response;
+pub use self::integration_response::IntegrationResponse;
 pub mod interaction_application_command_autocomplete_callback_integer_data;
 pub use self::interaction_application_command_autocomplete_callback_integer_data::InteractionApplicationCommandAutocompleteCallbackIntegerData;
 pub mod interaction_application_command_autocomplete_callback_number_data;
@@ -478,6 +466,8 @@ pub mod invite_channel_response;
 pub use self::invite_channel_response::InviteChannelResponse;
 pub mod invite_guild_response;
 pub use self::invite_guild_response::InviteGuildResponse;
+pub mod invite_response;
+pub use self::invite_response::InviteResponse;
 pub mod keyword_rule_response;
 pub use self::keyword_rule_response::KeywordRuleResponse;
 pub mod keyword_trigger_metadata;
@@ -494,20 +484,8 @@ pub mod label_component_for_modal_request_component;
 pub use self::label_component_for_modal_request_component::LabelComponentForModalRequestComponent;
 pub mod launch_activity_interaction_callback_request;
 pub use self::launch_activity_interaction_callback_request::LaunchActivityInteractionCallbackRequest;
-pub mod launch_activity_interaction_callback_response;
-pub use self::launch_activity_interaction_callback_response::LaunchActivityInteractionCallbackResponse;
 pub mod list_application_emojis_response;
 pub use self::list_application_emojis_response::ListApplicationEmojisResponse;
-pub mod list_auto_moderation_rules_200_response_inner;
-pub use self::list_auto_moderation_rules_200_response_inner::ListAutoModerationRules200ResponseInner;
-pub mod list_channel_invites_200_response_inner;
-pub use self::list_channel_invites_200_response_inner::ListChannelInvites200ResponseInner;
-pub mod list_channel_webhooks_200_response_inner;
-pub use self::list_channel_webhooks_200_response_inner::ListChannelWebhooks200ResponseInner;
-pub mod list_guild_integrations_200_response_inner;
-pub use self::list_guild_integrations_200_response_inner::ListGuildIntegrations200ResponseInner;
-pub mod list_guild_scheduled_events_200_response_inner;
-pub use self::list_guild_scheduled_events_200_response_inner::ListGuildScheduledEvents200ResponseInner;
 pub mod list_guild_soundboard_sounds_response;
 pub use self::list_guild_soundboard_sounds_response::ListGuildSoundboardSoundsResponse;
 pub mod lobby_guild_invite_response;
@@ -538,8 +516,6 @@ pub mod mention_spam_upsert_request;
 pub use self::mention_spam_upsert_request::MentionSpamUpsertRequest;
 pub mod mention_spam_upsert_request_partial;
 pub use self::mention_spam_upsert_request_partial::MentionSpamUpsertRequestPartial;
-pub mod mentionable_select_component_for_message_request;
-pub use self::mentionable_select_component_for_message_request::MentionableSelectComponentForMessageRequest;
 pub mod mentionable_select_component_for_message_request_default_values_inner;
 pub use self::mentionable_select_component_for_message_request_default_values_inner::MentionableSelectComponentForMessageRequestDefaultValuesInner;
 pub mod mentionable_select_component_for_modal_request;
@@ -656,12 +632,8 @@ pub mod poll_create_request;
 pub use self::poll_create_request::PollCreateRequest;
 pub mod poll_emoji;
 pub use self::poll_emoji::PollEmoji;
-pub mod poll_emoji_create_request;
-pub use self::poll_emoji_create_request::PollEmojiCreateRequest;
 pub mod poll_media;
 pub use self::poll_media::PollMedia;
-pub mod poll_media_create_request;
-pub use self::poll_media_create_request::PollMediaCreateRequest;
 pub mod poll_media_response;
 pub use self::poll_media_response::PollMediaResponse;
 pub mod poll_response;
@@ -716,16 +688,12 @@ pub mod rich_embed_thumbnail;
 pub use self::rich_embed_thumbnail::RichEmbedThumbnail;
 pub mod rich_embed_video;
 pub use self::rich_embed_video::RichEmbedVideo;
-pub mod role_select_component_for_message_request;
-pub use self::role_select_component_for_message_request::RoleSelectComponentForMessageRequest;
 pub mod role_select_component_for_modal_request;
 pub use self::role_select_component_for_modal_request::RoleSelectComponentForModalRequest;
 pub mod role_select_component_response;
 pub use self::role_select_component_response::RoleSelectComponentResponse;
 pub mod role_select_default_value;
 pub use self::role_select_default_value::RoleSelectDefaultValue;
-pub mod role_select_default_value_response;
-pub use self::role_select_default_value_response::RoleSelectDefaultValueResponse;
 pub mod scheduled_event_response;
 pub use self::scheduled_event_response::ScheduledEventResponse;
 pub mod scheduled_event_user_response;
@@ -752,6 +720,8 @@ pub mod settings_emoji_response;
 pub use self::settings_emoji_response::SettingsEmojiResponse;
 pub mod slack_webhook;
 pub use self::slack_webhook::SlackWebhook;
+pub mod snowflake;
+pub use self::snowflake::Snowflake;
 pub mod soundboard_create_request;
 pub use self::soundboard_create_request::SoundboardCreateRequest;
 pub mod soundboard_patch_request_partial;
@@ -776,8 +746,8 @@ pub mod sticker_pack_collection_response;
 pub use self::sticker_pack_collection_response::StickerPackCollectionResponse;
 pub mod sticker_pack_response;
 pub use self::sticker_pack_response::StickerPackResponse;
-pub mod string_select_component_for_message_request;
-pub use self::string_select_component_for_message_request::StringSelectComponentForMessageRequest;
+pub mod sticker_response;
+pub use self::sticker_response::StickerResponse;
 pub mod string_select_component_for_modal_request;
 pub use self::string_select_component_for_modal_request::StringSelectComponentForModalRequest;
 pub mod string_select_component_response;
@@ -790,8 +760,6 @@ pub mod team_member_response;
 pub use self::team_member_response::TeamMemberResponse;
 pub mod team_response;
 pub use self::team_response::TeamResponse;
-pub mod text_display_component_for_message_request;
-pub use self::text_display_component_for_message_request::TextDisplayComponentForMessageRequest;
 pub mod text_display_component_for_modal_request;
 pub use self::text_display_component_for_modal_request::TextDisplayComponentForModalRequest;
 pub mod text_display_component_response;
@@ -900,16 +868,12 @@ pub mod user_primary_guild_response;
 pub use self::user_primary_guild_response::UserPrimaryGuildResponse;
 pub mod user_response;
 pub use self::user_response::UserResponse;
-pub mod user_select_component_for_message_request;
-pub use self::user_select_component_for_message_request::UserSelectComponentForMessageRequest;
 pub mod user_select_component_for_modal_request;
 pub use self::user_select_component_for_modal_request::UserSelectComponentForModalRequest;
 pub mod user_select_component_response;
 pub use self::user_select_component_response::UserSelectComponentResponse;
 pub mod user_select_default_value;
 pub use self::user_select_default_value::UserSelectDefaultValue;
-pub mod user_select_default_value_response;
-pub use self::user_select_default_value_response::UserSelectDefaultValueResponse;
 pub mod vanity_url_error_response;
 pub use self::vanity_url_error_response::VanityUrlErrorResponse;
 pub mod vanity_url_response;
@@ -924,6 +888,8 @@ pub mod voice_scheduled_event_response;
 pub use self::voice_scheduled_event_response::VoiceScheduledEventResponse;
 pub mod voice_state_response;
 pub use self::voice_state_response::VoiceStateResponse;
+pub mod webhook_response;
+pub use self::webhook_response::WebhookResponse;
 pub mod webhook_slack_embed;
 pub use self::webhook_slack_embed::WebhookSlackEmbed;
 pub mod webhook_slack_embed_field;
@@ -946,3 +912,19 @@ pub mod widget_response;
 pub use self::widget_response::WidgetResponse;
 pub mod widget_settings_response;
 pub use self::widget_settings_response::WidgetSettingsResponse;
+pub type ApplicationRoleConnectionsMetadataItemResponse = self::ApplicationRoleConnectionsMetadataItemRequest;
+pub type ChannelSelectComponentForMessageRequest = self::ChannelSelectComponentForModalRequest;
+pub type ChannelSelectDefaultValueResponse = self::ChannelSelectDefaultValue;
+pub type EntityMetadataExternalResponse = self::EntityMetadataExternal;
+pub type FlagToChannelActionMetadataResponse = self::FlagToChannelActionMetadata;
+pub type FlagToChannelActionResponse = self::FlagToChannelAction;
+pub type LaunchActivityInteractionCallbackResponse = self::LaunchActivityInteractionCallbackRequest;
+pub type MentionableSelectComponentForMessageRequest = self::MentionableSelectComponentForModalRequest;
+pub type PollEmojiCreateRequest = self::PollEmoji;
+pub type PollMediaCreateRequest = self::PollMedia;
+pub type RoleSelectComponentForMessageRequest = self::RoleSelectComponentForModalRequest;
+pub type RoleSelectDefaultValueResponse = self::RoleSelectDefaultValue;
+pub type StringSelectComponentForMessageRequest = self::StringSelectComponentForModalRequest;
+pub type TextDisplayComponentForMessageRequest = self::TextDisplayComponentForModalRequest;
+pub type UserSelectComponentForMessageRequest = self::UserSelectComponentForModalRequest;
+pub type UserSelectDefaultValueResponse = self::UserSelectDefaultValue;
@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ModalSubmitInteractionMetadataResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "type")]
     pub r#type: i32,
     #[serde(rename = "user", skip_serializing_if = "Option::is_none")]
@@ -22,13 +22,13 @@ pub struct ModalSubmitInteractionMetadataResponse {
     #[serde(rename = "authorizing_integration_owners")]
     pub authorizing_integration_owners: std::collections::HashMap<String, String>,
     #[serde(rename = "original_response_message_id", skip_serializing_if = "Option::is_none")]
-    pub original_response_message_id: Option<String>,
+    pub original_response_message_id: Option<models::Snowflake>,
     #[serde(rename = "triggering_interaction_metadata")]
     pub triggering_interaction_metadata: Box<models::ModalSubmitInteractionMetadataResponseTriggeringInteractionMetadata>,
 }
 
 impl ModalSubmitInteractionMetadataResponse {
-    pub fn new(id: String, r#type: i32, authorizing_integration_owners: std::collections::HashMap<String, String>, triggering_interaction_metadata: models::ModalSubmitInteractionMetadataResponseTriggeringInteractionMetadata) -> ModalSubmitInteractionMetadataResponse {
+    pub fn new(id: models::Snowflake, r#type: i32, authorizing_integration_owners: std::collections::HashMap<String, String>, triggering_interaction_metadata: models::ModalSubmitInteractionMetadataResponseTriggeringInteractionMetadata) -> ModalSubmitInteractionMetadataResponse {
         ModalSubmitInteractionMetadataResponse {
             id,
             r#type,
@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MyGuildResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "icon", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -34,7 +34,7 @@ pub struct MyGuildResponse {
 }
 
 impl MyGuildResponse {
-    pub fn new(id: String, name: String, owner: bool, permissions: String, features: Vec<String>) -> MyGuildResponse {
+    pub fn new(id: models::Snowflake, name: String, owner: bool, permissions: String, features: Vec<String>) -> MyGuildResponse {
         MyGuildResponse {
             id,
             name,
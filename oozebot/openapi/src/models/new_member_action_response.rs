@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct NewMemberActionResponse {
     #[serde(rename = "channel_id")]
-    pub channel_id: String,
+    pub channel_id: models::Snowflake,
     #[serde(rename = "action_type", deserialize_with = "Option::deserialize")]
     pub action_type: Option<i32>,
     #[serde(rename = "title")]
@@ -28,7 +28,7 @@ pub struct NewMemberActionResponse {
 }
 
 impl NewMemberActionResponse {
-    pub fn new(channel_id: String, action_type: Option<i32>, title: String, description: String) -> NewMemberActionResponse {
+    pub fn new(channel_id: models::Snowflake, action_type: Option<i32>, title: String, description: String) -> NewMemberActionResponse {
         NewMemberActionResponse {
             channel_id,
             action_type,
@@ -14,21 +14,21 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OnboardingPromptOptionRequest {
     #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<models::Snowflake>,
     #[serde(rename = "title")]
     pub title: String,
     #[serde(rename = "description", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub description: Option<Option<String>>,
     #[serde(rename = "emoji_id", skip_serializing_if = "Option::is_none")]
-    pub emoji_id: Option<String>,
+    pub emoji_id: Option<models::Snowflake>,
     #[serde(rename = "emoji_name", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub emoji_name: Option<Option<String>>,
     #[serde(rename = "emoji_animated", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub emoji_animated: Option<Option<bool>>,
     #[serde(rename = "role_ids", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
-    pub role_ids: Option<Option<Vec<String>>>,
+    pub role_ids: Option<Option<Vec<models::Snowflake>>>,
     #[serde(rename = "channel_ids", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
-    pub channel_ids: Option<Option<Vec<String>>>,
+    pub channel_ids: Option<Option<Vec<models::Snowflake>>>,
 }
 
 impl OnboardingPromptOptionRequest {
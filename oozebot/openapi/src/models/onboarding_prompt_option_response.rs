@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OnboardingPromptOptionResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "title")]
     pub title: String,
     #[serde(rename = "description")]
@@ -22,13 +22,13 @@ pub struct OnboardingPromptOptionResponse {
     #[serde(rename = "emoji")]
     pub emoji: Box<models::SettingsEmojiResponse>,
     #[serde(rename = "role_ids")]
-    pub role_ids: Vec<String>,
+    pub role_ids: Vec<models::Snowflake>,
     #[serde(rename = "channel_ids")]
-    pub channel_ids: Vec<String>,
+    pub channel_ids: Vec<models::Snowflake>,
 }
 
 impl OnboardingPromptOptionResponse {
-    pub fn new(id: String, title: String, description: String, emoji: models::SettingsEmojiResponse, role_ids: Vec<String>, channel_ids: Vec<String>) -> OnboardingPromptOptionResponse {
+    pub fn new(id: models::Snowflake, title: String, description: String, emoji: models::SettingsEmojiResponse, role_ids: Vec<models::Snowflake>, channel_ids: Vec<models::Snowflake>) -> OnboardingPromptOptionResponse {
         OnboardingPromptOptionResponse {
             id,
             title,
@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OnboardingPromptResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "title")]
     pub title: String,
     #[serde(rename = "options")]
@@ -30,7 +30,7 @@ pub struct OnboardingPromptResponse {
 }
 
 impl OnboardingPromptResponse {
-    pub fn new(id: String, title: String, options: Vec<models::OnboardingPromptOptionResponse>, single_select: bool, required: bool, in_onboarding: bool, r#type: i32) -> OnboardingPromptResponse {
+    pub fn new(id: models::Snowflake, title: String, options: Vec<models::OnboardingPromptOptionResponse>, single_select: bool, required: bool, in_onboarding: bool, r#type: i32) -> OnboardingPromptResponse {
         OnboardingPromptResponse {
             id,
             title,
@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PartialDiscordIntegrationResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "type", deserialize_with = "Option::deserialize")]
     pub r#type: Option<String>,
     #[serde(rename = "name", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -22,11 +22,11 @@ pub struct PartialDiscordIntegrationResponse {
     #[serde(rename = "account")]
     pub account: Box<models::AccountResponse>,
     #[serde(rename = "application_id")]
-    pub application_id: String,
+    pub application_id: models::Snowflake,
 }
 
 impl PartialDiscordIntegrationResponse {
-    pub fn new(id: String, r#type: Option<String>, account: models::AccountResponse, application_id: String) -> PartialDiscordIntegrationResponse {
+    pub fn new(id: models::Snowflake, r#type: Option<String>, account: models::AccountResponse, application_id: models::Snowflake) -> PartialDiscordIntegrationResponse {
         PartialDiscordIntegrationResponse {
             id,
             r#type,
@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PartialExternalConnectionIntegrationResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "type", deserialize_with = "Option::deserialize")]
     pub r#type: Option<String>,
     #[serde(rename = "name", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -24,7 +24,7 @@ pub struct PartialExternalConnectionIntegrationResponse {
 }
 
 impl PartialExternalConnectionIntegrationResponse {
-    pub fn new(id: String, r#type: Option<String>, account: models::AccountResponse) -> PartialExternalConnectionIntegrationResponse {
+    pub fn new(id: models::Snowflake, r#type: Option<String>, account: models::AccountResponse) -> PartialExternalConnectionIntegrationResponse {
         PartialExternalConnectionIntegrationResponse {
             id,
             r#type,
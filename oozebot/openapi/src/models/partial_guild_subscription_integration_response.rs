@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PartialGuildSubscriptionIntegrationResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "type", deserialize_with = "Option::deserialize")]
     pub r#type: Option<String>,
     #[serde(rename = "name", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -24,7 +24,7 @@ pub struct PartialGuildSubscriptionIntegrationResponse {
 }
 
 impl PartialGuildSubscriptionIntegrationResponse {
-    pub fn new(id: String, r#type: Option<String>, account: models::AccountResponse) -> PartialGuildSubscriptionIntegrationResponse {
+    pub fn new(id: models::Snowflake, r#type: Option<String>, account: models::AccountResponse) -> PartialGuildSubscriptionIntegrationResponse {
         PartialGuildSubscriptionIntegrationResponse {
             id,
             r#type,
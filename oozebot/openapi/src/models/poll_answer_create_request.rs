@@ -14,11 +14,11 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PollAnswerCreateRequest {
     #[serde(rename = "poll_media")]
-    pub poll_media: Box<models::PollMediaCreateRequest>,
+    pub poll_media: Box<models::PollMedia>,
 }
 
 impl PollAnswerCreateRequest {
-    pub fn new(poll_media: models::PollMediaCreateRequest) -> PollAnswerCreateRequest {
+    pub fn new(poll_media: models::PollMedia) -> PollAnswerCreateRequest {
         PollAnswerCreateRequest {
             poll_media: Box::new(poll_media),
         }
@@ -1,33 +0,0 @@
-/*
- * Discord HTTP API (Preview)
- *
- * Preview of the Discord v10 HTTP API specification. See https://discord.com/developers/docs for more details.
- *
- * The version of the OpenAPI document: 10
- * 
- * Generated by: https://openapi-generator.tech
- */
-
-use crate::models;
-use serde::{Deserialize, Serialize};
-
-#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
-pub struct PollEmojiCreateRequest {
-    #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    #[serde(rename = "name", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
-    pub name: Option<Option<String>>,
-    #[serde(rename = "animated", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
-    pub animated: Option<Option<bool>>,
-}
-
-impl PollEmojiCreateRequest {
-    pub fn new() -> PollEmojiCreateRequest {
-        PollEmojiCreateRequest {
-            id: None,
-            name: None,
-            animated: None,
-        }
-    }
-}
-
@@ -1,30 +0,0 @@
-/*
- * Discord HTTP API (Preview)
- *
- * Preview of the Discord v10 HTTP API specification. See https://discord.com/developers/docs for more details.
- *
- * The version of the OpenAPI document: 10
- * 
- * Generated by: https://openapi-generator.tech
- */
-
-use crate::models;
-use serde::{Deserialize, Serialize};
-
-#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
-pub struct PollMediaCreateRequest {
-    #[serde(rename = "text", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
-    pub text: Option<Option<String>>,
-    #[serde(rename = "emoji", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
-    pub emoji: Option<Option<Box<models::PollEmojiCreateRequest>>>,
-}
-
-impl PollMediaCreateRequest {
-    pub fn new() -> PollMediaCreateRequest {
-        PollMediaCreateRequest {
-            text: None,
-            emoji: None,
-        }
-    }
-}
-
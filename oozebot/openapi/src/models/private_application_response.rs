@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PrivateApplicationResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "icon", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -26,13 +26,13 @@ pub struct PrivateApplicationResponse {
     #[serde(rename = "cover_image", skip_serializing_if = "Option::is_none")]
     pub cover_image: Option<String>,
     #[serde(rename = "primary_sku_id", skip_serializing_if = "Option::is_none")]
-    pub primary_sku_id: Option<String>,
+    pub primary_sku_id: Option<models::Snowflake>,
     #[serde(rename = "bot", skip_serializing_if = "Option::is_none")]
     pub bot: Option<Box<models::UserResponse>>,
     #[serde(rename = "slug", skip_serializing_if = "Option::is_none")]
     pub slug: Option<String>,
     #[serde(rename = "guild_id", skip_serializing_if = "Option::is_none")]
-    pub guild_id: Option<String>,
+    pub guild_id: Option<models::Snowflake>,
     #[serde(rename = "rpc_origins", skip_serializing_if = "Option::is_none")]
     pub rpc_origins: Option<Vec<String>>,
     #[serde(rename = "bot_public", skip_serializing_if = "Option::is_none")]
@@ -78,7 +78,7 @@ pub struct PrivateApplicationResponse {
 }
 
 impl PrivateApplicationResponse {
-    pub fn new(id: String, name: String, description: String, verify_key: String, flags: i32, redirect_uris: Vec<String>, owner: models::UserResponse, approximate_user_install_count: i32, approximate_user_authorization_count: i32, explicit_content_filter: i32) -> PrivateApplicationResponse {
+    pub fn new(id: models::Snowflake, name: String, description: String, verify_key: String, flags: i32, redirect_uris: Vec<String>, owner: models::UserResponse, approximate_user_install_count: i32, approximate_user_authorization_count: i32, explicit_content_filter: i32) -> PrivateApplicationResponse {
         PrivateApplicationResponse {
             id,
             name,
@@ -18,11 +18,11 @@ pub struct PrivateChannelLocation {
     #[serde(rename = "kind")]
     pub kind: String,
     #[serde(rename = "channel_id")]
-    pub channel_id: String,
+    pub channel_id: models::Snowflake,
 }
 
 impl PrivateChannelLocation {
-    pub fn new(id: String, kind: String, channel_id: String) -> PrivateChannelLocation {
+    pub fn new(id: String, kind: String, channel_id: models::Snowflake) -> PrivateChannelLocation {
         PrivateChannelLocation {
             id,
             kind,
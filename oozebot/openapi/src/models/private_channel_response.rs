@@ -14,11 +14,11 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PrivateChannelResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "type")]
     pub r#type: i32,
     #[serde(rename = "last_message_id", skip_serializing_if = "Option::is_none")]
-    pub last_message_id: Option<String>,
+    pub last_message_id: Option<models::Snowflake>,
     #[serde(rename = "flags")]
     pub flags: i32,
     #[serde(rename = "last_pin_timestamp", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -28,7 +28,7 @@ pub struct PrivateChannelResponse {
 }
 
 impl PrivateChannelResponse {
-    pub fn new(id: String, r#type: i32, flags: i32, recipients: Vec<models::UserResponse>) -> PrivateChannelResponse {
+    pub fn new(id: models::Snowflake, r#type: i32, flags: i32, recipients: Vec<models::UserResponse>) -> PrivateChannelResponse {
         PrivateChannelResponse {
             id,
             r#type,
@@ -14,11 +14,11 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PrivateGroupChannelResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "type")]
     pub r#type: i32,
     #[serde(rename = "last_message_id", skip_serializing_if = "Option::is_none")]
-    pub last_message_id: Option<String>,
+    pub last_message_id: Option<models::Snowflake>,
     #[serde(rename = "flags")]
     pub flags: i32,
     #[serde(rename = "last_pin_timestamp", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -30,15 +30,15 @@ pub struct PrivateGroupChannelResponse {
     #[serde(rename = "icon", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub icon: Option<Option<String>>,
     #[serde(rename = "owner_id")]
-    pub owner_id: String,
+    pub owner_id: models::Snowflake,
     #[serde(rename = "managed", skip_serializing_if = "Option::is_none")]
     pub managed: Option<bool>,
     #[serde(rename = "application_id", skip_serializing_if = "Option::is_none")]
-    pub application_id: Option<String>,
+    pub application_id: Option<models::Snowflake>,
 }
 
 impl PrivateGroupChannelResponse {
-    pub fn new(id: String, r#type: i32, flags: i32, recipients: Vec<models::UserResponse>, owner_id: String) -> PrivateGroupChannelResponse {
+    pub fn new(id: models::Snowflake, r#type: i32, flags: i32, recipients: Vec<models::UserResponse>, owner_id: models::Snowflake) -> PrivateGroupChannelResponse {
         PrivateGroupChannelResponse {
             id,
             r#type,
@@ -32,7 +32,7 @@ pub struct PrivateGuildMemberResponse {
     #[serde(rename = "premium_since", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub premium_since: Option<Option<String>>,
     #[serde(rename = "roles")]
-    pub roles: Vec<String>,
+    pub roles: Vec<models::Snowflake>,
     #[serde(rename = "collectibles", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub collectibles: Option<Option<Box<models::UserCollectiblesResponse>>>,
     #[serde(rename = "user")]
@@ -46,7 +46,7 @@ pub struct PrivateGuildMemberResponse {
 }
 
 impl PrivateGuildMemberResponse {
-    pub fn new(flags: i32, joined_at: String, pending: bool, roles: Vec<String>, user: models::UserResponse, mute: bool, deaf: bool) -> PrivateGuildMemberResponse {
+    pub fn new(flags: i32, joined_at: String, pending: bool, roles: Vec<models::Snowflake>, user: models::UserResponse, mute: bool, deaf: bool) -> PrivateGuildMemberResponse {
         PrivateGuildMemberResponse {
             avatar: None,
             avatar_decoration_data: None,
@@ -18,7 +18,7 @@ pub struct ResolvedObjectsResponse {
     #[serde(rename = "members", skip_serializing_if = "Option::is_none")]
     pub members: Option<std::collections::HashMap<String, models::BasicGuildMemberResponse>>,
     #[serde(rename = "channels", skip_serializing_if = "Option::is_none")]
-    pub channels: Option<std::collections::HashMap<String, models::GetChannel200Response>>,
+    pub channels: Option<std::collections::HashMap<String, models::ChannelResponse>>,
     #[serde(rename = "roles", skip_serializing_if = "Option::is_none")]
     pub roles: Option<std::collections::HashMap<String, models::GuildRoleResponse>>,
 }
@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ResourceChannelResponse {
     #[serde(rename = "channel_id")]
-    pub channel_id: String,
+    pub channel_id: models::Snowflake,
     #[serde(rename = "title")]
     pub title: String,
     #[serde(rename = "emoji", skip_serializing_if = "Option::is_none")]
@@ -26,7 +26,7 @@ pub struct ResourceChannelResponse {
 }
 
 impl ResourceChannelResponse {
-    pub fn new(channel_id: String, title: String, description: String) -> ResourceChannelResponse {
+    pub fn new(channel_id: models::Snowflake, title: String, description: String) -> ResourceChannelResponse {
         ResourceChannelResponse {
             channel_id,
             title,
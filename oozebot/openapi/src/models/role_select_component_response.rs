@@ -28,7 +28,7 @@ pub struct RoleSelectComponentResponse {
     #[serde(rename = "disabled", skip_serializing_if = "Option::is_none")]
     pub disabled: Option<bool>,
     #[serde(rename = "default_values", skip_serializing_if = "Option::is_none")]
-    pub default_values: Option<Vec<models::RoleSelectDefaultValueResponse>>,
+    pub default_values: Option<Vec<models::RoleSelectDefaultValue>>,
 }
 
 impl RoleSelectComponentResponse {
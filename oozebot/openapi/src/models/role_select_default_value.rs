@@ -16,11 +16,11 @@ pub struct RoleSelectDefaultValue {
     #[serde(rename = "type", deserialize_with = "Option::deserialize")]
     pub r#type: Option<String>,
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
 }
 
 impl RoleSelectDefaultValue {
-    pub fn new(r#type: Option<String>, id: String) -> RoleSelectDefaultValue {
+    pub fn new(r#type: Option<String>, id: models::Snowflake) -> RoleSelectDefaultValue {
         RoleSelectDefaultValue {
             r#type,
             id,
@@ -14,9 +14,9 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ScheduledEventUserResponse {
     #[serde(rename = "guild_scheduled_event_id")]
-    pub guild_scheduled_event_id: String,
+    pub guild_scheduled_event_id: models::Snowflake,
     #[serde(rename = "user_id")]
-    pub user_id: String,
+    pub user_id: models::Snowflake,
     #[serde(rename = "user", skip_serializing_if = "Option::is_none")]
     pub user: Option<Box<models::UserResponse>>,
     #[serde(rename = "member", skip_serializing_if = "Option::is_none")]
@@ -24,7 +24,7 @@ pub struct ScheduledEventUserResponse {
 }
 
 impl ScheduledEventUserResponse {
-    pub fn new(guild_scheduled_event_id: String, user_id: String) -> ScheduledEventUserResponse {
+    pub fn new(guild_scheduled_event_id: models::Snowflake, user_id: models::Snowflake) -> ScheduledEventUserResponse {
         ScheduledEventUserResponse {
             guild_scheduled_event_id,
             user_id,
@@ -18,13 +18,13 @@ pub struct SectionComponentForMessageRequest {
     #[serde(rename = "id", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub id: Option<Option<i32>>,
     #[serde(rename = "components")]
-    pub components: Vec<models::TextDisplayComponentForMessageRequest>,
+    pub components: Vec<models::TextDisplayComponentForModalRequest>,
     #[serde(rename = "accessory")]
     pub accessory: Box<models::SectionComponentForMessageRequestAccessory>,
 }
 
 impl SectionComponentForMessageRequest {
-    pub fn new(r#type: i32, components: Vec<models::TextDisplayComponentForMessageRequest>, accessory: models::SectionComponentForMessageRequestAccessory) -> SectionComponentForMessageRequest {
+    pub fn new(r#type: i32, components: Vec<models::TextDisplayComponentForModalRequest>, accessory: models::SectionComponentForMessageRequestAccessory) -> SectionComponentForMessageRequest {
         SectionComponentForMessageRequest {
             r#type,
             id: None,
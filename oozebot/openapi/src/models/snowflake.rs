@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+/// A Discord id. Sent as a string so it survives JSON parsers that read numbers as doubles.
+#[derive(Clone, Default, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Snowflake(pub String);
+
+impl Snowflake {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for Snowflake {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::borrow::Borrow<str> for Snowflake {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for Snowflake {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for Snowflake {
+    fn from(id: String) -> Self {
+        Snowflake(id)
+    }
+}
+
+impl From<&str> for Snowflake {
+    fn from(id: &str) -> Self {
+        Snowflake(id.to_string())
+    }
+}
+
+impl From<Snowflake> for String {
+    fn from(id: Snowflake) -> Self {
+        id.0
+    }
+}
+
+impl PartialEq<str> for Snowflake {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Snowflake {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<String> for Snowflake {
+    fn eq(&self, other: &String) -> bool {
+        &self.0 == other
+    }
+}
+
+impl PartialEq<Snowflake> for String {
+    fn eq(&self, other: &Snowflake) -> bool {
+        self == &other.0
+    }
+}
@@ -16,15 +16,15 @@ pub struct SoundboardSoundResponse {
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "sound_id")]
-    pub sound_id: String,
+    pub sound_id: models::Snowflake,
     #[serde(rename = "volume")]
     pub volume: f64,
     #[serde(rename = "emoji_id", skip_serializing_if = "Option::is_none")]
-    pub emoji_id: Option<String>,
+    pub emoji_id: Option<models::Snowflake>,
     #[serde(rename = "emoji_name", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub emoji_name: Option<Option<String>>,
     #[serde(rename = "guild_id", skip_serializing_if = "Option::is_none")]
-    pub guild_id: Option<String>,
+    pub guild_id: Option<models::Snowflake>,
     #[serde(rename = "available")]
     pub available: bool,
     #[serde(rename = "user", skip_serializing_if = "Option::is_none")]
@@ -32,7 +32,7 @@ pub struct SoundboardSoundResponse {
 }
 
 impl SoundboardSoundResponse {
-    pub fn new(name: String, sound_id: String, volume: f64, available: bool) -> SoundboardSoundResponse {
+    pub fn new(name: String, sound_id: models::Snowflake, volume: f64, available: bool) -> SoundboardSoundResponse {
         SoundboardSoundResponse {
             name,
             sound_id,
@@ -14,13 +14,13 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SoundboardSoundSendRequest {
     #[serde(rename = "sound_id")]
-    pub sound_id: String,
+    pub sound_id: models::Snowflake,
     #[serde(rename = "source_guild_id", skip_serializing_if = "Option::is_none")]
-    pub source_guild_id: Option<String>,
+    pub source_guild_id: Option<models::Snowflake>,
 }
 
 impl SoundboardSoundSendRequest {
-    pub fn new(sound_id: String) -> SoundboardSoundSendRequest {
+    pub fn new(sound_id: models::Snowflake) -> SoundboardSoundSendRequest {
         SoundboardSoundSendRequest {
             sound_id,
             source_guild_id: None,
@@ -14,23 +14,23 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct StageInstanceResponse {
     #[serde(rename = "guild_id")]
-    pub guild_id: String,
+    pub guild_id: models::Snowflake,
     #[serde(rename = "channel_id")]
-    pub channel_id: String,
+    pub channel_id: models::Snowflake,
     #[serde(rename = "topic")]
     pub topic: String,
     #[serde(rename = "privacy_level")]
     pub privacy_level: i32,
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "discoverable_disabled")]
     pub discoverable_disabled: bool,
     #[serde(rename = "guild_scheduled_event_id", skip_serializing_if = "Option::is_none")]
-    pub guild_scheduled_event_id: Option<String>,
+    pub guild_scheduled_event_id: Option<models::Snowflake>,
 }
 
 impl StageInstanceResponse {
-    pub fn new(guild_id: String, channel_id: String, topic: String, privacy_level: i32, id: String, discoverable_disabled: bool) -> StageInstanceResponse {
+    pub fn new(guild_id: models::Snowflake, channel_id: models::Snowflake, topic: String, privacy_level: i32, id: models::Snowflake, discoverable_disabled: bool) -> StageInstanceResponse {
         StageInstanceResponse {
             guild_id,
             channel_id,
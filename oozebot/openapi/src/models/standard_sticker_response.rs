@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct StandardStickerResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "tags")]
@@ -26,13 +26,13 @@ pub struct StandardStickerResponse {
     #[serde(rename = "description", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub description: Option<Option<String>>,
     #[serde(rename = "pack_id")]
-    pub pack_id: String,
+    pub pack_id: models::Snowflake,
     #[serde(rename = "sort_value")]
     pub sort_value: i32,
 }
 
 impl StandardStickerResponse {
-    pub fn new(id: String, name: String, tags: String, r#type: i32, pack_id: String, sort_value: i32) -> StandardStickerResponse {
+    pub fn new(id: models::Snowflake, name: String, tags: String, r#type: i32, pack_id: models::Snowflake, sort_value: i32) -> StandardStickerResponse {
         StandardStickerResponse {
             id,
             name,
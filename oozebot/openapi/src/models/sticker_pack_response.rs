@@ -14,9 +14,9 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct StickerPackResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "sku_id")]
-    pub sku_id: String,
+    pub sku_id: models::Snowflake,
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "description", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -24,13 +24,13 @@ pub struct StickerPackResponse {
     #[serde(rename = "stickers")]
     pub stickers: Vec<models::StandardStickerResponse>,
     #[serde(rename = "cover_sticker_id", skip_serializing_if = "Option::is_none")]
-    pub cover_sticker_id: Option<String>,
+    pub cover_sticker_id: Option<models::Snowflake>,
     #[serde(rename = "banner_asset_id", skip_serializing_if = "Option::is_none")]
-    pub banner_asset_id: Option<String>,
+    pub banner_asset_id: Option<models::Snowflake>,
 }
 
 impl StickerPackResponse {
-    pub fn new(id: String, sku_id: String, name: String, stickers: Vec<models::StandardStickerResponse>) -> StickerPackResponse {
+    pub fn new(id: models::Snowflake, sku_id: models::Snowflake, name: String, stickers: Vec<models::StandardStickerResponse>) -> StickerPackResponse {
         StickerPackResponse {
             id,
             sku_id,
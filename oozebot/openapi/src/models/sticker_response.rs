@@ -13,12 +13,12 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
-pub enum GetSticker200Response {
+pub enum StickerResponse {
     GuildStickerResponse(Box<models::GuildStickerResponse>),
     StandardStickerResponse(Box<models::StandardStickerResponse>),
 }
 
-impl Default for GetSticker200Response {
+impl Default for StickerResponse {
     fn default() -> Self {
         Self::GuildStickerResponse(Default::default())
     }
@@ -16,13 +16,13 @@ pub struct TeamMemberResponse {
     #[serde(rename = "user")]
     pub user: Box<models::UserResponse>,
     #[serde(rename = "team_id")]
-    pub team_id: String,
+    pub team_id: models::Snowflake,
     #[serde(rename = "membership_state")]
     pub membership_state: i32,
 }
 
 impl TeamMemberResponse {
-    pub fn new(user: models::UserResponse, team_id: String, membership_state: i32) -> TeamMemberResponse {
+    pub fn new(user: models::UserResponse, team_id: models::Snowflake, membership_state: i32) -> TeamMemberResponse {
         TeamMemberResponse {
             user: Box::new(user),
             team_id,
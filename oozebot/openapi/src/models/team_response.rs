@@ -14,19 +14,19 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TeamResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "icon", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub icon: Option<Option<String>>,
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "owner_user_id")]
-    pub owner_user_id: String,
+    pub owner_user_id: models::Snowflake,
     #[serde(rename = "members")]
     pub members: Vec<models::TeamMemberResponse>,
 }
 
 impl TeamResponse {
-    pub fn new(id: String, name: String, owner_user_id: String, members: Vec<models::TeamMemberResponse>) -> TeamResponse {
+    pub fn new(id: models::Snowflake, name: String, owner_user_id: models::Snowflake, members: Vec<models::TeamMemberResponse>) -> TeamResponse {
         TeamResponse {
             id,
             icon: None,
@@ -14,9 +14,9 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ThreadMemberResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "user_id")]
-    pub user_id: String,
+    pub user_id: models::Snowflake,
     #[serde(rename = "join_timestamp")]
     pub join_timestamp: String,
     #[serde(rename = "flags")]
@@ -26,7 +26,7 @@ pub struct ThreadMemberResponse {
 }
 
 impl ThreadMemberResponse {
-    pub fn new(id: String, user_id: String, join_timestamp: String, flags: i32) -> ThreadMemberResponse {
+    pub fn new(id: models::Snowflake, user_id: models::Snowflake, join_timestamp: String, flags: i32) -> ThreadMemberResponse {
         ThreadMemberResponse {
             id,
             user_id,
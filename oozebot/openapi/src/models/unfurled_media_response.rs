@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UnfurledMediaResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "url")]
     pub url: String,
     #[serde(rename = "proxy_url")]
@@ -26,11 +26,11 @@ pub struct UnfurledMediaResponse {
     #[serde(rename = "content_type", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub content_type: Option<Option<String>>,
     #[serde(rename = "attachment_id", skip_serializing_if = "Option::is_none")]
-    pub attachment_id: Option<String>,
+    pub attachment_id: Option<models::Snowflake>,
 }
 
 impl UnfurledMediaResponse {
-    pub fn new(id: String, url: String, proxy_url: String) -> UnfurledMediaResponse {
+    pub fn new(id: models::Snowflake, url: String, proxy_url: String) -> UnfurledMediaResponse {
         UnfurledMediaResponse {
             id,
             url,
@@ -18,7 +18,7 @@ pub struct UpdateGuildOnboardingRequest {
     #[serde(rename = "enabled", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub enabled: Option<Option<bool>>,
     #[serde(rename = "default_channel_ids", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
-    pub default_channel_ids: Option<Option<Vec<String>>>,
+    pub default_channel_ids: Option<Option<Vec<models::Snowflake>>>,
     #[serde(rename = "mode", skip_serializing_if = "Option::is_none")]
     pub mode: Option<i32>,
 }
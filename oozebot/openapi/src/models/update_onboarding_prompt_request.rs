@@ -26,11 +26,11 @@ pub struct UpdateOnboardingPromptRequest {
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub r#type: Option<i32>,
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
 }
 
 impl UpdateOnboardingPromptRequest {
-    pub fn new(title: String, options: Vec<models::OnboardingPromptOptionRequest>, id: String) -> UpdateOnboardingPromptRequest {
+    pub fn new(title: String, options: Vec<models::OnboardingPromptOptionRequest>, id: models::Snowflake) -> UpdateOnboardingPromptRequest {
         UpdateOnboardingPromptRequest {
             title,
             options,
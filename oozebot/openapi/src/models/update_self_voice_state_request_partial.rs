@@ -18,7 +18,7 @@ pub struct UpdateSelfVoiceStateRequestPartial {
     #[serde(rename = "suppress", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub suppress: Option<Option<bool>>,
     #[serde(rename = "channel_id", skip_serializing_if = "Option::is_none")]
-    pub channel_id: Option<String>,
+    pub channel_id: Option<models::Snowflake>,
 }
 
 impl UpdateSelfVoiceStateRequestPartial {
@@ -28,7 +28,7 @@ pub struct UpdateThreadRequestPartial {
     #[serde(rename = "flags", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub flags: Option<Option<i32>>,
     #[serde(rename = "applied_tags", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
-    pub applied_tags: Option<Option<Vec<String>>>,
+    pub applied_tags: Option<Option<Vec<models::Snowflake>>>,
     #[serde(rename = "bitrate", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub bitrate: Option<Option<i32>>,
     #[serde(rename = "user_limit", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -16,7 +16,7 @@ pub struct UserAvatarDecorationResponse {
     #[serde(rename = "asset")]
     pub asset: String,
     #[serde(rename = "sku_id", skip_serializing_if = "Option::is_none")]
-    pub sku_id: Option<String>,
+    pub sku_id: Option<models::Snowflake>,
 }
 
 impl UserAvatarDecorationResponse {
@@ -14,17 +14,17 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UserGuildOnboardingResponse {
     #[serde(rename = "guild_id")]
-    pub guild_id: String,
+    pub guild_id: models::Snowflake,
     #[serde(rename = "prompts")]
     pub prompts: Vec<models::OnboardingPromptResponse>,
     #[serde(rename = "default_channel_ids")]
-    pub default_channel_ids: Vec<String>,
+    pub default_channel_ids: Vec<models::Snowflake>,
     #[serde(rename = "enabled")]
     pub enabled: bool,
 }
 
 impl UserGuildOnboardingResponse {
-    pub fn new(guild_id: String, prompts: Vec<models::OnboardingPromptResponse>, default_channel_ids: Vec<String>, enabled: bool) -> UserGuildOnboardingResponse {
+    pub fn new(guild_id: models::Snowflake, prompts: Vec<models::OnboardingPromptResponse>, default_channel_ids: Vec<models::Snowflake>, enabled: bool) -> UserGuildOnboardingResponse {
         UserGuildOnboardingResponse {
             guild_id,
             prompts,
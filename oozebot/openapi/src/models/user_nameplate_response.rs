@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UserNameplateResponse {
     #[serde(rename = "sku_id", skip_serializing_if = "Option::is_none")]
-    pub sku_id: Option<String>,
+    pub sku_id: Option<models::Snowflake>,
     #[serde(rename = "asset")]
     pub asset: String,
     #[serde(rename = "label")]
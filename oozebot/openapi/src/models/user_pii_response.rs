@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UserPiiResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "username")]
     pub username: String,
     #[serde(rename = "avatar", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -54,7 +54,7 @@ pub struct UserPiiResponse {
 }
 
 impl UserPiiResponse {
-    pub fn new(id: String, username: String, discriminator: String, public_flags: i32, flags: i64, mfa_enabled: bool, locale: String) -> UserPiiResponse {
+    pub fn new(id: models::Snowflake, username: String, discriminator: String, public_flags: i32, flags: i64, mfa_enabled: bool, locale: String) -> UserPiiResponse {
         UserPiiResponse {
             id,
             username,
@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UserPrimaryGuildResponse {
     #[serde(rename = "identity_guild_id", skip_serializing_if = "Option::is_none")]
-    pub identity_guild_id: Option<String>,
+    pub identity_guild_id: Option<models::Snowflake>,
     #[serde(rename = "identity_enabled", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub identity_enabled: Option<Option<bool>>,
     #[serde(rename = "tag", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
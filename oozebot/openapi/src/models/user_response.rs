@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UserResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "username")]
     pub username: String,
     #[serde(rename = "avatar", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -44,7 +44,7 @@ pub struct UserResponse {
 }
 
 impl UserResponse {
-    pub fn new(id: String, username: String, discriminator: String, public_flags: i32, flags: i64) -> UserResponse {
+    pub fn new(id: models::Snowflake, username: String, discriminator: String, public_flags: i32, flags: i64) -> UserResponse {
         UserResponse {
             id,
             username,
@@ -16,11 +16,11 @@ pub struct UserSelectDefaultValue {
     #[serde(rename = "type", deserialize_with = "Option::deserialize")]
     pub r#type: Option<String>,
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
 }
 
 impl UserSelectDefaultValue {
-    pub fn new(r#type: Option<String>, id: String) -> UserSelectDefaultValue {
+    pub fn new(r#type: Option<String>, id: models::Snowflake) -> UserSelectDefaultValue {
         UserSelectDefaultValue {
             r#type,
             id,
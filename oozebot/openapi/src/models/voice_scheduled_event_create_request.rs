@@ -28,7 +28,7 @@ pub struct VoiceScheduledEventCreateRequest {
     #[serde(rename = "entity_type", deserialize_with = "Option::deserialize")]
     pub entity_type: Option<i32>,
     #[serde(rename = "channel_id", skip_serializing_if = "Option::is_none")]
-    pub channel_id: Option<String>,
+    pub channel_id: Option<models::Snowflake>,
     #[serde(rename = "entity_metadata", skip_serializing_if = "Option::is_none")]
     pub entity_metadata: Option<serde_json::Value>,
 }
@@ -14,11 +14,11 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct VoiceStateResponse {
     #[serde(rename = "channel_id", skip_serializing_if = "Option::is_none")]
-    pub channel_id: Option<String>,
+    pub channel_id: Option<models::Snowflake>,
     #[serde(rename = "deaf")]
     pub deaf: bool,
     #[serde(rename = "guild_id", skip_serializing_if = "Option::is_none")]
-    pub guild_id: Option<String>,
+    pub guild_id: Option<models::Snowflake>,
     #[serde(rename = "member", skip_serializing_if = "Option::is_none")]
     pub member: Option<Box<models::GuildMemberResponse>>,
     #[serde(rename = "mute")]
@@ -38,11 +38,11 @@ pub struct VoiceStateResponse {
     #[serde(rename = "session_id")]
     pub session_id: String,
     #[serde(rename = "user_id")]
-    pub user_id: String,
+    pub user_id: models::Snowflake,
 }
 
 impl VoiceStateResponse {
-    pub fn new(deaf: bool, mute: bool, suppress: bool, self_deaf: bool, self_mute: bool, self_video: bool, session_id: String, user_id: String) -> VoiceStateResponse {
+    pub fn new(deaf: bool, mute: bool, suppress: bool, self_deaf: bool, self_mute: bool, self_video: bool, session_id: String, user_id: models::Snowflake) -> VoiceStateResponse {
         VoiceStateResponse {
             channel_id: None,
             deaf,
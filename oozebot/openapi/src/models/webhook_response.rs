@@ -13,13 +13,13 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
-pub enum ListChannelWebhooks200ResponseInner {
+pub enum WebhookResponse {
     ApplicationIncomingWebhookResponse(Box<models::ApplicationIncomingWebhookResponse>),
     ChannelFollowerWebhookResponse(Box<models::ChannelFollowerWebhookResponse>),
     GuildIncomingWebhookResponse(Box<models::GuildIncomingWebhookResponse>),
 }
 
-impl Default for ListChannelWebhooks200ResponseInner {
+impl Default for WebhookResponse {
     fn default() -> Self {
         Self::ApplicationIncomingWebhookResponse(Default::default())
     }
@@ -14,13 +14,13 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct WebhookSourceChannelResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "name")]
     pub name: String,
 }
 
 impl WebhookSourceChannelResponse {
-    pub fn new(id: String, name: String) -> WebhookSourceChannelResponse {
+    pub fn new(id: models::Snowflake, name: String) -> WebhookSourceChannelResponse {
         WebhookSourceChannelResponse {
             id,
             name,
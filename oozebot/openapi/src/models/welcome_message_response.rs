@@ -14,13 +14,13 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct WelcomeMessageResponse {
     #[serde(rename = "author_ids")]
-    pub author_ids: Vec<String>,
+    pub author_ids: Vec<models::Snowflake>,
     #[serde(rename = "message")]
     pub message: String,
 }
 
 impl WelcomeMessageResponse {
-    pub fn new(author_ids: Vec<String>, message: String) -> WelcomeMessageResponse {
+    pub fn new(author_ids: Vec<models::Snowflake>, message: String) -> WelcomeMessageResponse {
         WelcomeMessageResponse {
             author_ids,
             message,
@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct WidgetChannel {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "position")]
@@ -22,7 +22,7 @@ pub struct WidgetChannel {
 }
 
 impl WidgetChannel {
-    pub fn new(id: String, name: String, position: i32) -> WidgetChannel {
+    pub fn new(id: models::Snowflake, name: String, position: i32) -> WidgetChannel {
         WidgetChannel {
             id,
             name,
@@ -20,7 +20,7 @@ pub struct WidgetMember {
     #[serde(rename = "discriminator", deserialize_with = "Option::deserialize")]
     pub discriminator: Option<String>,
     #[serde(rename = "avatar", skip_serializing_if = "Option::is_none")]
-    pub avatar: Option<models::Null>,
+    pub avatar: Option<serde_json::Value>,
     #[serde(rename = "status")]
     pub status: String,
     #[serde(rename = "avatar_url")]
@@ -38,7 +38,7 @@ pub struct WidgetMember {
     #[serde(rename = "suppress", skip_serializing_if = "Option::is_none")]
     pub suppress: Option<bool>,
     #[serde(rename = "channel_id", skip_serializing_if = "Option::is_none")]
-    pub channel_id: Option<String>,
+    pub channel_id: Option<models::Snowflake>,
 }
 
 impl WidgetMember {
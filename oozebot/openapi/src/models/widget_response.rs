@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct WidgetResponse {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: models::Snowflake,
     #[serde(rename = "name")]
     pub name: String,
     #[serde(rename = "instant_invite", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -28,7 +28,7 @@ pub struct WidgetResponse {
 }
 
 impl WidgetResponse {
-    pub fn new(id: String, name: String, channels: Vec<models::WidgetChannel>, members: Vec<models::WidgetMember>, presence_count: i32) -> WidgetResponse {
+    pub fn new(id: models::Snowflake, name: String, channels: Vec<models::WidgetChannel>, members: Vec<models::WidgetMember>, presence_count: i32) -> WidgetResponse {
         WidgetResponse {
             id,
             name,
@@ -16,7 +16,7 @@ pub struct WidgetSettingsResponse {
     #[serde(rename = "enabled")]
     pub enabled: bool,
     #[serde(rename = "channel_id", skip_serializing_if = "Option::is_none")]
-    pub channel_id: Option<String>,
+    pub channel_id: Option<models::Snowflake>,
 }
 
 impl WidgetSettingsResponse {
@@ -0,0 +1,221 @@
+use serde_json::Value;
+
+use crate::{
+    models::{union_name, Models},
+    spec::{Operation, QueryKind, Spec},
+};
+
+
+const HEADER: &str = "//! Every Discord endpoint, sent through `RestClient` so calls share its rate limit
+//! buckets. Generated by `cargo run --bin codegen`, do not edit.
+#![allow(clippy::too_many_arguments)]
+
+use openapi::models;
+use reqwest::Method;
+
+use crate::rest::{Request, RestClient, RestError};
+
+
+pub struct Api<'a> {
+    rest: &'a RestClient,
+}
+
+impl RestClient {
+    /// Typed calls for endpoints without a hand written helper.
+    pub fn api(&self) -> Api<'_> {
+        Api { rest: self }
+    }
+}
+
+impl Api<'_> {
+";
+
+const KEYWORDS: &[&str] = &["type", "for", "in", "match", "ref", "self", "mod", "use", "where", "loop", "move", "box"];
+
+fn camel_case(snake: &str) -> String {
+    snake
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map(|first| first.to_ascii_uppercase().to_string() + chars.as_str()).unwrap_or_default()
+        })
+        .collect()
+}
+
+fn ident(name: &str) -> String {
+    let name: String = name.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect();
+    if KEYWORDS.contains(&name.as_str()) { format!("r#{name}") } else { name }
+}
+
+/// Rust type of a response schema. `anonymous` is the name the generator gives the
+/// schema if it is an inline union.
+fn response_type(schema: &Value, models: &Models, anonymous: &str) -> String {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        let name = reference.rsplit('/').next().unwrap_or_default();
+        return match models.resolve(name) {
+            Some(model) => format!("models::{model}"),
+            None if Spec::is_snowflake(schema) => "String".to_string(),
+            None => "serde_json::Value".to_string(),
+        }
+    }
+
+    if let Some(variants) = schema.get("oneOf").and_then(Value::as_array) {
+        let null = Value::from("null");
+        let typed: Vec<&Value> = variants.iter().filter(|v| v.get("type") != Some(&null)).collect();
+        if typed.len() == 1 {
+            let inner = response_type(typed[0], models, anonymous);
+            return if typed.len() < variants.len() { format!("Option<{inner}>") } else { inner }
+        }
+
+        let mut names: Vec<String> = typed
+            .iter()
+            .filter_map(|variant| models.resolve(variant.get("$ref")?.as_str()?.rsplit('/').next()?))
+            .collect();
+        names.sort();
+        return match union_name(&names).filter(|name| models.contains(name)) {
+            Some(name) => format!("models::{name}"),
+            None if models.contains(anonymous) => format!("models::{anonymous}"),
+            None => "serde_json::Value".to_string(),
+        }
+    }
+
+    let types: Vec<&str> = match schema.get("type") {
+        Some(Value::String(kind)) => vec![kind],
+        Some(Value::Array(kinds)) => kinds.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    };
+    if types.contains(&"array") {
+        let items = schema.get("items").unwrap_or(&Value::Null);
+        return format!("Vec<{}>", response_type(items, models, &format!("{anonymous}Inner")))
+    }
+    if types.contains(&"object")
+        && let Some(values) = schema.get("additionalProperties").filter(|values| values.is_object())
+    {
+        return format!("std::collections::HashMap<String, {}>", response_type(values, models, anonymous))
+    }
+    if types.contains(&"string") {
+        return "String".to_string()
+    }
+    if types.contains(&"integer") {
+        return "i64".to_string()
+    }
+    if types.contains(&"boolean") {
+        return "bool".to_string()
+    }
+    "serde_json::Value".to_string()
+}
+
+fn body_type(schema: &Value, models: &Models, operation: &Operation) -> String {
+    if let Some(items) = schema.get("items")
+        && let Some(model) = items.get("$ref").and_then(Value::as_str).and_then(|reference| models.resolve(reference.rsplit('/').next()?))
+    {
+        return format!("&[models::{model}]")
+    }
+
+    let named = schema
+        .get("$ref")
+        .and_then(Value::as_str)
+        .and_then(|reference| models.resolve(reference.rsplit('/').next()?))
+        .or_else(|| models.resolve(&format!("{}Request", camel_case(&operation.id))));
+    match named {
+        Some(model) => format!("&models::{model}"),
+        None => "&serde_json::Value".to_string(),
+    }
+}
+
+fn method(operation: &Operation, models: &Models) -> String {
+    let mut parameters = vec!["&self".to_string()];
+    parameters.extend(operation.path_params.iter().map(|name| format!("{}: &str", ident(name))));
+    if let Some(body) = &operation.body {
+        parameters.push(format!("body: {}", body_type(body, models, operation)));
+    }
+    for (name, kind) in &operation.query {
+        let ty = match kind {
+            QueryKind::Integer => "i64",
+            QueryKind::Boolean => "bool",
+            QueryKind::Text => "&str",
+        };
+        parameters.push(format!("{}: Option<{ty}>", ident(name)));
+    }
+
+    let response = match &operation.response {
+        Some((code, schema)) => response_type(schema, models, &format!("{}{code}Response", camel_case(&operation.id))),
+        None => "()".to_string(),
+    };
+
+    let mut path = operation.path.clone();
+    for name in &operation.path_params {
+        path = path.replace(&format!("{{{name}}}"), &format!("{{{}}}", ident(name).trim_start_matches("r#")));
+    }
+    let path = if operation.path_params.is_empty() { format!("\"{path}\"") } else { format!("format!(\"{path}\")") };
+    let mutable = if operation.query.is_empty() { "" } else { "mut " };
+
+    let mut source = format!("    /// `{} {}`\n", operation.method, operation.path);
+    source += &format!("    pub async fn {}({}) -> Result<{response}, RestError> {{\n", operation.id, parameters.join(", "));
+    source += &format!("        let {mutable}request = Request::new(Method::{}, {path})", operation.method);
+    source += if operation.body.is_some() { ".json(body)?;\n" } else { ";\n" };
+    for (name, _) in &operation.query {
+        let value = ident(name);
+        source += &format!("        if let Some({value}) = {value} {{\n            request = request.query(\"{name}\", {value});\n        }}\n");
+    }
+    source += "        self.rest.send(request).await\n    }\n";
+    source
+}
+
+/// Source of `src/rest/api.rs`, and the ids of the operations left out because they
+/// only take multipart bodies.
+pub fn emit(spec: &Spec, models: &Models) -> (String, Vec<String>) {
+    let mut source = HEADER.to_string();
+    let mut skipped = Vec::new();
+
+    let methods: Vec<String> = spec
+        .operations()
+        .into_iter()
+        .filter(|operation| {
+            if operation.multipart_only {
+                skipped.push(operation.id.clone());
+            }
+            !operation.multipart_only
+        })
+        .map(|operation| method(&operation, models))
+        .collect();
+    source += &methods.join("\n");
+    source += "}\n";
+    (source, skipped)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn emits_typed_call() {
+        let spec = Spec::from_value(json!({
+            "paths": {
+                "/channels/{channel_id}/messages": {
+                    "parameters": [{ "name": "channel_id", "in": "path", "schema": { "$ref": "#/components/schemas/SnowflakeType" } }],
+                    "get": {
+                        "operationId": "list_messages",
+                        "parameters": [{ "name": "limit", "in": "query", "schema": { "type": "integer" } }],
+                        "responses": { "200": { "content": { "application/json": { "schema": {
+                            "type": ["array", "null"],
+                            "items": { "$ref": "#/components/schemas/MessageResponse" },
+                        } } } } },
+                    },
+                },
+            },
+        }));
+        let models = Models::default();
+
+        let (source, skipped) = emit(&spec, &models);
+        assert!(skipped.is_empty());
+        assert!(source.contains(
+            "pub async fn list_messages(&self, channel_id: &str, limit: Option<i64>) -> Result<Vec<serde_json::Value>, RestError>"
+        ));
+        assert!(source.contains("Request::new(Method::GET, format!(\"/channels/{channel_id}/messages\"))"));
+        assert!(source.contains("request = request.query(\"limit\", limit);"));
+    }
+}
@@ -0,0 +1,114 @@
+//! Regenerates the `openapi` crate from Discord's spec and applies the fixes the raw
+//! OpenAPI Generator output needs, then rewrites `src/rest/api.rs`.
+//!
+//! ```text
+//! cargo run --bin codegen -- [--spec PATH] [--crate DIR] [--api PATH] [--skip-generate]
+//! ```
+//!
+//! The generator is run as `$OPENAPI_GENERATOR` (default `openapi-generator-cli`).
+//! With `--skip-generate` only the post-processing passes run, which is a no-op on
+//! an already processed crate.
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::{Command, ExitStatus},
+};
+
+use thiserror::Error;
+
+use crate::{models::Models, spec::Spec};
+
+mod api;
+mod models;
+mod spec;
+
+
+#[derive(Debug, Error)]
+pub enum CodegenError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid spec: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("OpenAPI Generator failed with {0}")]
+    Generator(ExitStatus),
+
+    #[error("{0}")]
+    Usage(String),
+}
+
+struct Options {
+    spec: PathBuf,
+    crate_dir: PathBuf,
+    api: PathBuf,
+    generate: bool,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, CodegenError> {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let mut options = Options {
+            spec: root.join("openapi_preview.json"),
+            crate_dir: root.join("openapi"),
+            api: root.join("src/rest/api.rs"),
+            generate: true,
+        };
+
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().map(PathBuf::from).ok_or_else(|| CodegenError::Usage(format!("{arg} needs a value")));
+            match arg.as_str() {
+                "--spec" => options.spec = value()?,
+                "--crate" => options.crate_dir = value()?,
+                "--api" => options.api = value()?,
+                "--skip-generate" => options.generate = false,
+                _ => return Err(CodegenError::Usage(format!("Unknown argument {arg}"))),
+            }
+        }
+        Ok(options)
+    }
+}
+
+fn generate(options: &Options) -> Result<(), CodegenError> {
+    let generator = env::var("OPENAPI_GENERATOR").unwrap_or_else(|_| "openapi-generator-cli".to_string());
+    let status = Command::new(generator)
+        .args(["generate", "-g", "rust", "--skip-validate-spec", "--additional-properties", "packageName=openapi"])
+        .arg("-i")
+        .arg(&options.spec)
+        .arg("-o")
+        .arg(&options.crate_dir)
+        .status()?;
+    if !status.success() {
+        return Err(CodegenError::Generator(status))
+    }
+    Ok(())
+}
+
+fn run(options: Options) -> Result<(), CodegenError> {
+    let spec = Spec::load(&options.spec)?;
+    if options.generate {
+        generate(&options)?;
+    }
+
+    let mut models = Models::load(options.crate_dir.join("src"))?;
+    println!("Replaced {} uses of models::Null", models.fix_null());
+    println!("Typed {} snowflake fields", models.type_snowflakes(&spec.snowflake_fields()));
+    for (old, new) in models.name_unions() {
+        println!("Named {old} as {new}");
+    }
+    println!("Collapsed {} duplicate models", models.collapse_duplicates());
+    models.save()?;
+
+    let (source, skipped) = api::emit(&spec, &models);
+    std::fs::write(&options.api, source)?;
+    println!("Wrote {} ({} multipart-only operations skipped)", options.api.display(), skipped.len());
+    Ok(())
+}
+
+fn main() {
+    if let Err(e) = Options::parse(env::args().skip(1)).and_then(run) {
+        eprintln!("codegen: {e}");
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,487 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fs,
+    path::PathBuf,
+};
+
+use crate::CodegenError;
+
+
+/// Names for the untagged unions the generator emits for inline `oneOf` responses,
+/// keyed by their sorted variants.
+const UNION_NAMES: &[(&[&str], &str)] = &[
+    (&["GuildChannelResponse", "PrivateChannelResponse", "PrivateGroupChannelResponse", "ThreadResponse"], "ChannelResponse"),
+    (&["PrivateChannelResponse", "PrivateGroupChannelResponse"], "DmChannelResponse"),
+    (&["DefaultKeywordRuleResponse", "KeywordRuleResponse", "MentionSpamRuleResponse", "MlSpamRuleResponse", "SpamLinkRuleResponse"], "AutoModerationRuleResponse"),
+    (&["ExternalScheduledEventResponse", "StageScheduledEventResponse", "VoiceScheduledEventResponse"], "GuildScheduledEventResponse"),
+    (&["FriendInviteResponse", "GroupDmInviteResponse", "GuildInviteResponse"], "InviteResponse"),
+    (&["GuildStickerResponse", "StandardStickerResponse"], "StickerResponse"),
+];
+
+const SNOWFLAKE_MODEL: &str = r#"use serde::{Deserialize, Serialize};
+
+/// A Discord id. Sent as a string so it survives JSON parsers that read numbers as doubles.
+#[derive(Clone, Default, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Snowflake(pub String);
+
+impl Snowflake {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for Snowflake {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::borrow::Borrow<str> for Snowflake {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for Snowflake {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for Snowflake {
+    fn from(id: String) -> Self {
+        Snowflake(id)
+    }
+}
+
+impl From<&str> for Snowflake {
+    fn from(id: &str) -> Self {
+        Snowflake(id.to_string())
+    }
+}
+
+impl From<Snowflake> for String {
+    fn from(id: Snowflake) -> Self {
+        id.0
+    }
+}
+
+impl PartialEq<str> for Snowflake {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Snowflake {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<String> for Snowflake {
+    fn eq(&self, other: &String) -> bool {
+        &self.0 == other
+    }
+}
+
+impl PartialEq<Snowflake> for String {
+    fn eq(&self, other: &Snowflake) -> bool {
+        self == &other.0
+    }
+}
+"#;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Model {
+    module: String,
+    source: String,
+}
+
+/// The `models` module of a generated crate, rewritten in memory and written back by `save`.
+#[derive(Debug, Default)]
+pub struct Models {
+    src: PathBuf,
+    models: BTreeMap<String, Model>,
+    /// Removed models kept as aliases of the one that replaced them.
+    aliases: BTreeMap<String, String>,
+    removed_modules: Vec<String>,
+    /// Renames still to be applied to `apis`.
+    renames: Vec<(String, String)>,
+}
+
+/// Generator style module name, `GroupDmInviteResponse` to `group_dm_invite_response`.
+pub fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|next| next.is_ascii_lowercase());
+            if previous.is_ascii_lowercase() || previous.is_ascii_digit() || (previous.is_ascii_uppercase() && next_lower) {
+                snake.push('_');
+            }
+        }
+        snake.push(c.to_ascii_lowercase());
+    }
+    snake
+}
+
+/// Spec and generated names compared case and punctuation blind: `GroupDMInviteResponse`
+/// is generated as `GroupDmInviteResponse`.
+pub fn normalize(name: &str) -> String {
+    name.chars().filter(char::is_ascii_alphanumeric).map(|c| c.to_ascii_lowercase()).collect()
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Replaces whole identifiers only, so `PollEmoji` leaves `PollEmojiCreateRequest` alone.
+pub fn replace_ident(source: &str, from: &str, to: &str) -> String {
+    let mut replaced = String::with_capacity(source.len());
+    let mut copied = 0;
+    for (index, _) in source.match_indices(from) {
+        let before = source[..index].chars().next_back();
+        let after = source[index + from.len()..].chars().next();
+        if before.is_some_and(is_ident_char) || after.is_some_and(is_ident_char) {
+            continue
+        }
+        replaced.push_str(&source[copied..index]);
+        replaced.push_str(to);
+        copied = index + from.len();
+    }
+    replaced.push_str(&source[copied..]);
+    replaced
+}
+
+/// `OperationId200Response` and `OperationId200ResponseInner`, the names the generator
+/// gives inline response schemas.
+pub fn is_anonymous(name: &str) -> bool {
+    let name = name.strip_suffix("Inner").unwrap_or(name);
+    name.strip_suffix("Response")
+        .is_some_and(|name| name.len() > 3 && name[name.len() - 3..].bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Variant types of an untagged union model.
+fn union_variants(source: &str) -> Option<Vec<String>> {
+    if !source.contains("#[serde(untagged)]") {
+        return None
+    }
+    let mut variants: Vec<String> = source
+        .lines()
+        .filter_map(|line| line.trim().strip_suffix(">),")?.split_once("(Box<models::").map(|(_, ty)| ty.to_string()))
+        .collect();
+    variants.sort();
+    Some(variants)
+}
+
+/// Name for a union of `variants`: from `UNION_NAMES`, or the longest shared suffix of
+/// at least two words, like `WebhookResponse`.
+pub fn union_name(variants: &[String]) -> Option<String> {
+    if let Some((_, name)) = UNION_NAMES.iter().find(|(known, _)| known.iter().eq(variants.iter())) {
+        return Some(name.to_string())
+    }
+
+    let words = |name: &str| {
+        let mut words = Vec::new();
+        let mut start = 0;
+        for (i, c) in name.char_indices().skip(1) {
+            if c.is_ascii_uppercase() {
+                words.push(name[start..i].to_string());
+                start = i;
+            }
+        }
+        words.push(name[start..].to_string());
+        words
+    };
+    let split: Vec<Vec<String>> = variants.iter().map(|variant| words(variant)).collect();
+    let shortest = split.iter().map(Vec::len).min()?;
+    let shared = (1..=shortest)
+        .take_while(|&n| split.iter().all(|words| words[words.len() - n] == split[0][split[0].len() - n]))
+        .last()?;
+    (shared >= 2 && variants.len() > 1).then(|| split[0][split[0].len() - shared..].concat())
+}
+
+/// Words the generator appends to a schema for its request and response flavours.
+const FLAVOURS: &[&str] = &["Response", "Request", "Partial", "Create", "Patch", "ForMessage", "ForModal"];
+
+/// `PollEmojiCreateRequest` and `PollEmoji` are both `PollEmoji`.
+fn base_name(name: &str) -> &str {
+    let mut base = name;
+    while let Some(stripped) = FLAVOURS.iter().find_map(|flavour| base.strip_suffix(flavour).filter(|rest| !rest.is_empty())) {
+        base = stripped;
+    }
+    base
+}
+
+/// A model's source with its own name and doc comments blanked out, so models of the
+/// same shape compare equal.
+fn shape(name: &str, source: &str) -> String {
+    let source: Vec<&str> = source
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("//"))
+        .collect();
+    replace_ident(&source.join("\n"), name, "Self_")
+}
+
+impl Models {
+    pub fn load(src: PathBuf) -> Result<Self, CodegenError> {
+        let index = fs::read_to_string(src.join("models/mod.rs"))?;
+        let mut models = Models { src, ..Default::default() };
+
+        for line in index.lines() {
+            if let Some(alias) = line.strip_prefix("pub type ")
+                && let Some((alias, target)) = alias.trim_end_matches(';').split_once(" = ")
+            {
+                models.aliases.insert(alias.to_string(), target.trim_start_matches("self::").to_string());
+            } else if let Some(path) = line.strip_prefix("pub use self::")
+                && let Some((module, name)) = path.trim_end_matches(';').split_once("::")
+            {
+                let source = fs::read_to_string(models.src.join(format!("models/{module}.rs")))?;
+                models.models.insert(name.to_string(), Model { module: module.to_string(), source });
+            }
+        }
+        Ok(models)
+    }
+
+    #[cfg(test)]
+    fn from_sources(sources: &[(&str, &str)]) -> Self {
+        let mut models = Models::default();
+        for (name, source) in sources {
+            models.models.insert(name.to_string(), Model { module: snake_case(name), source: source.to_string() });
+        }
+        models
+    }
+
+    /// Generated name for a spec schema, following aliases of collapsed models.
+    pub fn resolve(&self, schema_name: &str) -> Option<String> {
+        let key = normalize(schema_name);
+        let name = self.models.keys().chain(self.aliases.keys()).find(|name| normalize(name) == key)?;
+        Some(self.aliases.get(name).unwrap_or(name).clone())
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.models.contains_key(name)
+    }
+
+    fn rename_everywhere(&mut self, from: &str, to: &str) {
+        for model in self.models.values_mut() {
+            model.source = replace_ident(&model.source, from, to);
+        }
+        for target in self.aliases.values_mut() {
+            if target == from {
+                *target = to.to_string();
+            }
+        }
+        self.renames.push((from.to_string(), to.to_string()));
+    }
+
+    fn remove(&mut self, name: &str) -> Option<Model> {
+        let model = self.models.remove(name)?;
+        self.removed_modules.push(model.module.clone());
+        Some(model)
+    }
+
+    /// The spec leaves some fields untyped, which the generator turns into a `models::Null`
+    /// that does not exist.
+    pub fn fix_null(&mut self) -> usize {
+        let mut fixed = 0;
+        for model in self.models.values_mut() {
+            fixed += model.source.matches("models::Null").count();
+            model.source = model.source.replace("models::Null", "serde_json::Value");
+        }
+        fixed
+    }
+
+    /// Types the `String` fields of `fields` (normalized schema name to JSON property
+    /// names) as `models::Snowflake`.
+    pub fn type_snowflakes(&mut self, fields: &HashMap<String, HashSet<String>>) -> usize {
+        let mut typed = 0;
+        for (name, model) in &mut self.models {
+            let Some(properties) = fields.get(&normalize(name)) else { continue };
+
+            let mut json_name = None;
+            let mut rust_names = Vec::new();
+            let mut lines: Vec<String> = Vec::new();
+            for line in model.source.lines() {
+                let trimmed = line.trim();
+                if let Some(rename) = trimmed.strip_prefix("#[serde(rename = \"") {
+                    json_name = rename.split('"').next().map(str::to_string);
+                } else if let Some(field) = trimmed.strip_prefix("pub ")
+                    && let Some((rust_name, ty)) = field.split_once(": ")
+                    && json_name.take().is_some_and(|json_name| properties.contains(&json_name))
+                    && ty.contains("String")
+                {
+                    typed += 1;
+                    rust_names.push(rust_name.to_string());
+                    lines.push(line.replacen(ty, &replace_ident(ty, "String", "models::Snowflake"), 1));
+                    continue
+                }
+                lines.push(line.to_string());
+            }
+
+            for line in lines.iter_mut().filter(|line| line.trim_start().starts_with("pub fn new(")) {
+                for rust_name in &rust_names {
+                    for ty in ["String", "Vec<String>", "Option<String>"] {
+                        let typed = ty.replace("String", "models::Snowflake");
+                        *line = line.replace(&format!("{rust_name}: {ty},"), &format!("{rust_name}: {typed},"));
+                        *line = line.replace(&format!("{rust_name}: {ty})"), &format!("{rust_name}: {typed})"));
+                    }
+                }
+            }
+            model.source = lines.join("\n") + "\n";
+        }
+
+        if typed > 0 && !self.models.contains_key("Snowflake") {
+            self.models.insert("Snowflake".to_string(), Model { module: "snowflake".to_string(), source: SNOWFLAKE_MODEL.to_string() });
+        }
+        typed
+    }
+
+    /// Renames anonymous response unions after their variants. Unions of the same
+    /// variants become one model.
+    pub fn name_unions(&mut self) -> Vec<(String, String)> {
+        let anonymous: Vec<String> = self.models.keys().filter(|name| is_anonymous(name)).cloned().collect();
+        let mut named = Vec::new();
+        let mut created = HashSet::new();
+
+        for old in anonymous {
+            let Some(new) = union_variants(&self.models[&old].source).and_then(|variants| union_name(&variants)) else { continue };
+            if self.models.contains_key(&new) && !created.contains(&new) {
+                eprintln!("Not renaming {old}, {new} already exists");
+                continue
+            }
+
+            let model = self.remove(&old).expect("listed above");
+            if created.insert(new.clone()) {
+                let source = replace_ident(&model.source, &old, &new);
+                self.models.insert(new.clone(), Model { module: snake_case(&new), source });
+            }
+            self.rename_everywhere(&old, &new);
+            named.push((old, new));
+        }
+        named
+    }
+
+    /// Replaces request and response flavours of a model that have the same shape with
+    /// the shortest named one, keeping the other names as aliases. Repeats until nothing changes, as collapsing a field's type can
+    /// make its containers identical too.
+    pub fn collapse_duplicates(&mut self) -> usize {
+        let mut collapsed = 0;
+        loop {
+            let mut shapes: HashMap<(&str, String), Vec<String>> = HashMap::new();
+            for (name, model) in &self.models {
+                shapes.entry((base_name(name), shape(name, &model.source))).or_default().push(name.clone());
+            }
+
+            let mut duplicates: Vec<Vec<String>> = shapes.into_values().filter(|names| names.len() > 1).collect();
+            if duplicates.is_empty() {
+                return collapsed
+            }
+            duplicates.sort();
+
+            for mut names in duplicates {
+                names.sort_by(|a, b| a.len().cmp(&b.len()).then(a.cmp(b)));
+                let canonical = names.remove(0);
+                for name in names {
+                    self.remove(&name);
+                    self.rename_everywhere(&name, &canonical);
+                    self.aliases.insert(name, canonical.clone());
+                    collapsed += 1;
+                }
+            }
+        }
+    }
+
+    pub fn save(&self) -> Result<(), CodegenError> {
+        let models_dir = self.src.join("models");
+        for module in &self.removed_modules {
+            let path = models_dir.join(format!("{module}.rs"));
+            if path.exists() {
+                fs::remove_file(path)?;
+            }
+        }
+
+        let mut index = String::new();
+        let mut modules: Vec<(&String, &Model)> = self.models.iter().collect();
+        modules.sort_by(|a, b| a.1.module.cmp(&b.1.module));
+        for (name, model) in modules {
+            fs::write(models_dir.join(format!("{}.rs", model.module)), &model.source)?;
+            index.push_str(&format!("pub mod {};\npub use self::{}::{};\n", model.module, model.module, name));
+        }
+        for (alias, target) in &self.aliases {
+            index.push_str(&format!("pub type {alias} = self::{target};\n"));
+        }
+        fs::write(models_dir.join("mod.rs"), index)?;
+
+        for entry in fs::read_dir(self.src.join("apis"))? {
+            let path = entry?.path();
+            let source = fs::read_to_string(&path)?;
+            let renamed = self.renames.iter().fold(source.clone(), |source, (from, to)| replace_ident(&source, from, to));
+            if renamed != source {
+                fs::write(path, renamed)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EMOJI: &str = "#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NAME {
+    #[serde(rename = \"id\", skip_serializing_if = \"Option::is_none\")]
+    pub id: Option<String>,
+}
+impl NAME {
+    pub fn new() -> NAME {
+        NAME { id: None }
+    }
+}
+";
+
+    #[test]
+    fn collapses_models_of_the_same_shape() {
+        let media = |emoji: &str| format!("pub struct NAME {{\n    pub emoji: Option<Box<models::{emoji}>>,\n}}\n");
+        let mut models = Models::from_sources(&[
+            ("PollEmoji", &EMOJI.replace("NAME", "PollEmoji")),
+            ("PollEmojiCreateRequest", &EMOJI.replace("NAME", "PollEmojiCreateRequest")),
+            ("PollMedia", &media("PollEmoji").replace("NAME", "PollMedia")),
+            ("PollMediaCreateRequest", &media("PollEmojiCreateRequest").replace("NAME", "PollMediaCreateRequest")),
+        ]);
+
+        assert_eq!(models.collapse_duplicates(), 2);
+        assert!(models.contains("PollMedia") && !models.contains("PollMediaCreateRequest"));
+        assert_eq!(models.resolve("PollEmojiCreateRequest").as_deref(), Some("PollEmoji"));
+        assert_eq!(models.collapse_duplicates(), 0);
+    }
+
+    #[test]
+    fn names_anonymous_unions() {
+        let union = |variants: &[&str]| {
+            let variants: String = variants.iter().map(|v| format!("    {v}(Box<models::{v}>),\n")).collect();
+            format!("#[serde(untagged)]\npub enum NAME {{\n{variants}}}\nimpl Default for NAME {{}}\n")
+        };
+        let webhooks = union(&["ApplicationIncomingWebhookResponse", "ChannelFollowerWebhookResponse"]);
+        let stickers = union(&["StandardStickerResponse", "GuildStickerResponse"]);
+        let mut models = Models::from_sources(&[
+            ("GetWebhook200Response", &webhooks.replace("NAME", "GetWebhook200Response")),
+            ("ListChannelWebhooks200ResponseInner", &webhooks.replace("NAME", "ListChannelWebhooks200ResponseInner")),
+            ("GetSticker200Response", &stickers.replace("NAME", "GetSticker200Response")),
+            ("MessageResponse", "pub stickers: Option<Vec<models::GetSticker200Response>>,"),
+        ]);
+
+        let named = models.name_unions();
+        assert_eq!(named.len(), 3);
+        assert!(models.contains("WebhookResponse") && models.contains("StickerResponse"));
+        assert!(!models.contains("GetWebhook200Response"));
+        assert!(models.models["MessageResponse"].source.contains("models::StickerResponse"));
+        assert_eq!(snake_case("GroupDmInviteResponse"), "group_dm_invite_response");
+    }
+}
@@ -0,0 +1,159 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::Path,
+};
+
+use serde_json::{Map, Value};
+
+use crate::{models::normalize, CodegenError};
+
+
+const SNOWFLAKE_SCHEMA: &str = "#/components/schemas/SnowflakeType";
+const METHODS: &[&str] = &["get", "put", "post", "delete", "patch"];
+
+/// The parts of Discord's OpenAPI document the passes need.
+#[derive(Debug)]
+pub struct Spec {
+    document: Value,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryKind {
+    Integer,
+    Boolean,
+    /// Anything else, passed already encoded.
+    Text,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Operation {
+    pub id: String,
+    pub method: String,
+    pub path: String,
+    pub path_params: Vec<String>,
+    pub query: Vec<(String, QueryKind)>,
+    /// JSON request body, if any.
+    pub body: Option<Value>,
+    /// No JSON variant of the request body exists.
+    pub multipart_only: bool,
+    /// Status code and schema of the first success response, `None` for no content.
+    pub response: Option<(String, Value)>,
+}
+
+fn ref_name(schema: &Value) -> Option<&str> {
+    schema.get("$ref")?.as_str()?.rsplit('/').next()
+}
+
+impl Spec {
+    pub fn load(path: &Path) -> Result<Self, CodegenError> {
+        Ok(Spec::from_value(serde_json::from_str(&fs::read_to_string(path)?)?))
+    }
+
+    pub fn from_value(document: Value) -> Self {
+        Spec { document }
+    }
+
+    fn schemas(&self) -> Option<&Map<String, Value>> {
+        self.document.pointer("/components/schemas")?.as_object()
+    }
+
+    /// Whether `schema` is a snowflake, a nullable one or a list of them.
+    pub fn is_snowflake(schema: &Value) -> bool {
+        if schema.get("$ref").and_then(Value::as_str) == Some(SNOWFLAKE_SCHEMA) {
+            return true
+        }
+        if let Some(items) = schema.get("items") {
+            return Spec::is_snowflake(items)
+        }
+        ["oneOf", "anyOf"]
+            .iter()
+            .filter_map(|key| schema.get(key)?.as_array())
+            .any(|variants| {
+                let typed: Vec<&Value> = variants.iter().filter(|v| v.get("type") != Some(&Value::from("null"))).collect();
+                typed.len() == 1 && Spec::is_snowflake(typed[0])
+            })
+    }
+
+    /// JSON property names holding snowflakes, by normalized schema name.
+    pub fn snowflake_fields(&self) -> HashMap<String, HashSet<String>> {
+        let Some(schemas) = self.schemas() else { return HashMap::new() };
+        schemas
+            .iter()
+            .filter_map(|(name, schema)| {
+                let fields: HashSet<String> = schema
+                    .get("properties")?
+                    .as_object()?
+                    .iter()
+                    .filter(|(_, property)| Spec::is_snowflake(property))
+                    .map(|(property, _)| property.clone())
+                    .collect();
+                (!fields.is_empty()).then(|| (normalize(name), fields))
+            })
+            .collect()
+    }
+
+    fn resolve_parameter<'a>(&'a self, parameter: &'a Value) -> &'a Value {
+        ref_name(parameter)
+            .and_then(|name| self.document.pointer(&format!("/components/parameters/{name}")))
+            .unwrap_or(parameter)
+    }
+
+    pub fn operations(&self) -> Vec<Operation> {
+        let Some(paths) = self.document.get("paths").and_then(Value::as_object) else { return Vec::new() };
+        let mut operations = Vec::new();
+
+        for (path, item) in paths {
+            let shared = item.get("parameters").and_then(Value::as_array).cloned().unwrap_or_default();
+            for &method in METHODS {
+                let Some(operation) = item.get(method) else { continue };
+                let Some(id) = operation.get("operationId").and_then(Value::as_str) else { continue };
+
+                let mut path_params = Vec::new();
+                let mut query = Vec::new();
+                let own = operation.get("parameters").and_then(Value::as_array).cloned().unwrap_or_default();
+                for parameter in shared.iter().chain(&own) {
+                    let parameter = self.resolve_parameter(parameter);
+                    let Some(name) = parameter.get("name").and_then(Value::as_str) else { continue };
+                    match parameter.get("in").and_then(Value::as_str) {
+                        Some("path") => path_params.push(name.to_string()),
+                        Some("query") => {
+                            let kind = match parameter.pointer("/schema/type").and_then(Value::as_str) {
+                                Some("integer") => QueryKind::Integer,
+                                Some("boolean") => QueryKind::Boolean,
+                                _ => QueryKind::Text,
+                            };
+                            query.push((name.to_string(), kind));
+                        }
+                        _ => {}
+                    }
+                }
+
+                let content = operation.pointer("/requestBody/content");
+                let body = content.and_then(|content| content.pointer("/application~1json/schema")).cloned();
+                let multipart_only = content.is_some() && body.is_none();
+
+                let response = operation
+                    .get("responses")
+                    .and_then(Value::as_object)
+                    .and_then(|responses| responses.iter().find(|(code, _)| code.starts_with('2')))
+                    .and_then(|(code, response)| {
+                        let schema = response.pointer("/content/application~1json/schema")?;
+                        Some((code.clone(), schema.clone()))
+                    });
+
+                operations.push(Operation {
+                    id: id.to_string(),
+                    method: method.to_uppercase(),
+                    path: path.clone(),
+                    path_params,
+                    query,
+                    body,
+                    multipart_only,
+                    response,
+                });
+            }
+        }
+        operations
+    }
+}
@@ -0,0 +1,106 @@
+//! Manages test entitlements so premium commands can be tried without purchases.
+//!
+//! ```text
+//! cargo run --bin entitlements -- create SKU_ID (--user ID | --guild ID)
+//! cargo run --bin entitlements -- delete ENTITLEMENT_ID
+//! cargo run --bin entitlements -- list [--user ID] [--guild ID]
+//! ```
+//!
+//! Authenticates with the bot token in `$DISCORD_TOKEN`.
+
+use std::env;
+
+use futures::TryStreamExt;
+use oozebot::{
+    entitlements::EntitlementOwner,
+    rest::{RestClient, RestError},
+};
+use thiserror::Error;
+
+
+#[derive(Debug, Error)]
+enum DevError {
+    #[error(transparent)]
+    Rest(#[from] RestError),
+
+    #[error("{0}")]
+    Usage(String),
+}
+
+enum Action {
+    Create { sku_id: String, owner: EntitlementOwner },
+    Delete { entitlement_id: String },
+    List { user_id: Option<String>, guild_id: Option<String> },
+}
+
+impl Action {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, DevError> {
+        let usage = || DevError::Usage("Usage: entitlements create SKU_ID (--user ID | --guild ID) | delete ENTITLEMENT_ID | list [--user ID] [--guild ID]".to_string());
+        let action = args.next().ok_or_else(usage)?;
+        let positional = match action.as_str() {
+            "create" | "delete" => Some(args.next().ok_or_else(usage)?),
+            _ => None,
+        };
+
+        let (mut user_id, mut guild_id) = (None, None);
+        while let Some(arg) = args.next() {
+            let value = args.next().ok_or_else(|| DevError::Usage(format!("{arg} needs a value")))?;
+            match arg.as_str() {
+                "--user" => user_id = Some(value),
+                "--guild" => guild_id = Some(value),
+                _ => return Err(DevError::Usage(format!("Unknown argument {arg}"))),
+            }
+        }
+
+        match (action.as_str(), positional) {
+            ("create", Some(sku_id)) => {
+                let owner = match (user_id, guild_id) {
+                    (Some(user), None) => EntitlementOwner::User(user),
+                    (None, Some(guild)) => EntitlementOwner::Guild(guild),
+                    _ => return Err(DevError::Usage("create needs exactly one of --user or --guild".to_string())),
+                };
+                Ok(Action::Create { sku_id, owner })
+            }
+            ("delete", Some(entitlement_id)) => Ok(Action::Delete { entitlement_id }),
+            ("list", None) => Ok(Action::List { user_id, guild_id }),
+            _ => Err(usage()),
+        }
+    }
+}
+
+async fn run(action: Action) -> Result<(), DevError> {
+    let token = env::var("DISCORD_TOKEN").map_err(|_| DevError::Usage("DISCORD_TOKEN is not set".to_string()))?;
+    let rest = RestClient::new(token);
+    let application_id = rest.api().get_my_application().await?.id;
+
+    match action {
+        Action::Create { sku_id, owner } => {
+            let entitlement = rest.create_test_entitlement(&application_id, &sku_id, &owner).await?;
+            println!("Created entitlement {} for SKU {sku_id}", entitlement.id);
+        }
+        Action::Delete { entitlement_id } => {
+            rest.api().delete_entitlement(&application_id, &entitlement_id).await?;
+            println!("Deleted entitlement {entitlement_id}");
+        }
+        Action::List { user_id, guild_id } => {
+            let mut entitlements = std::pin::pin!(rest.paginate_entitlements(&application_id, user_id.as_deref(), guild_id.as_deref()).into_stream());
+            while let Some(entitlement) = entitlements.try_next().await? {
+                let owner = entitlement.guild_id.as_ref().map_or(format!("user {}", entitlement.user_id), |guild| format!("guild {guild}"));
+                println!("{}  SKU {}  {owner}  type {:?}", entitlement.id, entitlement.sku_id, entitlement.r#type);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() {
+    let result = match Action::parse(env::args().skip(1)) {
+        Ok(action) => run(action).await,
+        Err(e) => Err(e),
+    };
+    if let Err(e) = result {
+        eprintln!("entitlements: {e}");
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,422 @@
+use std::collections::HashMap;
+
+use openapi::models::{GuildChannelResponse, GuildMemberResponse, GuildResponse, GuildRoleResponse, SoundboardSoundResponse, ThreadResponse};
+
+use crate::{
+    events::{DispatchEvent, GuildMemberUpdate, ThreadListSync},
+    permissions::{base_permissions, overwrite_permissions, Permissions},
+};
+
+
+/// In-memory view of the guild state the bot has seen so far.
+///
+/// Roles are kept per guild and are the source of truth over `GuildResponse::roles`,
+/// which is only read when the guild is first inserted.
+#[derive(Debug, Default)]
+pub struct Cache {
+    guilds: HashMap<String, GuildResponse>,
+    roles: HashMap<String, HashMap<String, GuildRoleResponse>>,
+    channels: HashMap<String, GuildChannelResponse>,
+    threads: HashMap<String, ThreadResponse>,
+    members: HashMap<(String, String), GuildMemberResponse>,
+    sounds: HashMap<String, HashMap<String, SoundboardSoundResponse>>,
+}
+
+impl Cache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert_guild(&mut self, guild: GuildResponse) {
+        let roles = guild.roles
+            .iter()
+            .map(|role| (role.id.to_string(), role.clone()))
+            .collect();
+        self.roles.insert(guild.id.to_string(), roles);
+        self.guilds.insert(guild.id.to_string(), guild);
+    }
+
+    pub fn remove_guild(&mut self, guild_id: &str) -> Option<GuildResponse> {
+        self.roles.remove(guild_id);
+        self.channels.retain(|_, channel| channel.guild_id != guild_id);
+        self.threads.retain(|_, thread| thread.guild_id != guild_id);
+        self.sounds.remove(guild_id);
+        self.members.retain(|(guild, _), _| guild != guild_id);
+        self.guilds.remove(guild_id)
+    }
+
+    pub fn guild(&self, guild_id: &str) -> Option<&GuildResponse> {
+        self.guilds.get(guild_id)
+    }
+
+    pub fn insert_role(&mut self, guild_id: &str, role: GuildRoleResponse) {
+        self.roles
+            .entry(guild_id.to_string())
+            .or_default()
+            .insert(role.id.to_string(), role);
+    }
+
+    pub fn remove_role(&mut self, guild_id: &str, role_id: &str) -> Option<GuildRoleResponse> {
+        self.roles.get_mut(guild_id)?.remove(role_id)
+    }
+
+    pub fn roles(&self, guild_id: &str) -> impl Iterator<Item = &GuildRoleResponse> {
+        self.roles.get(guild_id).into_iter().flat_map(|roles| roles.values())
+    }
+
+    pub fn insert_channel(&mut self, channel: GuildChannelResponse) {
+        self.channels.insert(channel.id.to_string(), channel);
+    }
+
+    pub fn remove_channel(&mut self, channel_id: &str) -> Option<GuildChannelResponse> {
+        self.channels.remove(channel_id)
+    }
+
+    pub fn channel(&self, channel_id: &str) -> Option<&GuildChannelResponse> {
+        self.channels.get(channel_id)
+    }
+
+    /// Stores a thread. A `member` of `None` keeps the bot's known membership, as
+    /// `THREAD_UPDATE` does not carry it.
+    pub fn insert_thread(&mut self, mut thread: ThreadResponse) {
+        if thread.member.is_none() {
+            thread.member = self.threads.get(thread.id.as_str()).and_then(|old| old.member.clone());
+        }
+        self.threads.insert(thread.id.to_string(), thread);
+    }
+
+    pub fn remove_thread(&mut self, thread_id: &str) -> Option<ThreadResponse> {
+        self.threads.remove(thread_id)
+    }
+
+    pub fn thread(&self, thread_id: &str) -> Option<&ThreadResponse> {
+        self.threads.get(thread_id)
+    }
+
+    /// Cached threads whose parent is `channel_id`.
+    pub fn threads_in<'a>(&'a self, channel_id: &'a str) -> impl Iterator<Item = &'a ThreadResponse> {
+        self.threads.values().filter(move |thread| thread.parent_id.as_deref() == Some(channel_id))
+    }
+
+    /// Cached threads the bot is a member of.
+    pub fn joined_threads(&self) -> impl Iterator<Item = &ThreadResponse> {
+        self.threads.values().filter(|thread| thread.member.is_some())
+    }
+
+    fn sync_threads(&mut self, sync: &ThreadListSync) {
+        // Synced channels lose every thread not in the list, which were archived or
+        // became inaccessible while we were not watching.
+        self.threads.retain(|_, thread| {
+            thread.guild_id != sync.guild_id
+                || sync.channel_ids.as_ref().is_some_and(|ids| !thread.parent_id.as_ref().is_some_and(|parent| ids.iter().any(|id| parent == id)))
+        });
+
+        for thread in &sync.threads {
+            let mut thread = thread.clone();
+            thread.member = sync.members
+                .iter()
+                .find(|member| member.id == thread.id)
+                .map(|member| Box::new(member.clone()));
+            self.threads.insert(thread.id.to_string(), thread);
+        }
+    }
+
+    /// Replaces every cached soundboard sound of a guild.
+    pub fn set_sounds(&mut self, guild_id: &str, sounds: Vec<SoundboardSoundResponse>) {
+        let sounds = sounds
+            .into_iter()
+            .map(|sound| (sound.sound_id.to_string(), sound))
+            .collect();
+        self.sounds.insert(guild_id.to_string(), sounds);
+    }
+
+    pub fn insert_sound(&mut self, guild_id: &str, sound: SoundboardSoundResponse) {
+        self.sounds
+            .entry(guild_id.to_string())
+            .or_default()
+            .insert(sound.sound_id.to_string(), sound);
+    }
+
+    pub fn remove_sound(&mut self, guild_id: &str, sound_id: &str) -> Option<SoundboardSoundResponse> {
+        self.sounds.get_mut(guild_id)?.remove(sound_id)
+    }
+
+    /// Soundboard sounds of a guild, or `None` if they were never requested.
+    pub fn sounds(&self, guild_id: &str) -> Option<impl Iterator<Item = &SoundboardSoundResponse>> {
+        self.sounds.get(guild_id).map(|sounds| sounds.values())
+    }
+
+    /// Looks a sound up by its case-insensitive name.
+    pub fn sound_named(&self, guild_id: &str, name: &str) -> Option<&SoundboardSoundResponse> {
+        self.sounds(guild_id)?.find(|sound| sound.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Applies a member update to the cached member, caching the member if it was
+    /// not known yet.
+    fn update_member(&mut self, update: &GuildMemberUpdate) {
+        let member = self.members
+            .entry((update.guild_id.clone(), update.user.id.to_string()))
+            .or_default();
+        *member.user = update.user.clone();
+        member.roles = update.roles.iter().map(|role| role.as_str().into()).collect();
+        member.nick = Some(update.nick.clone());
+        member.communication_disabled_until = Some(update.communication_disabled_until.clone());
+    }
+
+    /// Applies the gateway events the cache tracks, ignoring the rest.
+    pub fn update(&mut self, event: &DispatchEvent) {
+        match event {
+            DispatchEvent::GuildCreate(create) => {
+                let guild_id = create.guild.id.to_string();
+                self.insert_guild(create.guild.clone());
+                for member in &create.members {
+                    self.insert_member(&guild_id, member.clone());
+                }
+                for channel in &create.channels {
+                    self.insert_channel(channel.clone());
+                }
+                for thread in &create.threads {
+                    self.insert_thread(thread.clone());
+                }
+            }
+            DispatchEvent::GuildDelete(delete) => {
+                self.remove_guild(&delete.id);
+            }
+            DispatchEvent::GuildRoleCreate(role) | DispatchEvent::GuildRoleUpdate(role) => {
+                self.insert_role(&role.guild_id, role.role.clone());
+            }
+            DispatchEvent::GuildRoleDelete(delete) => {
+                self.remove_role(&delete.guild_id, &delete.role_id);
+            }
+            DispatchEvent::GuildMemberAdd(add) => self.insert_member(&add.guild_id, add.member.clone()),
+            DispatchEvent::GuildMemberUpdate(update) => self.update_member(update),
+            DispatchEvent::GuildMemberRemove(remove) => {
+                self.remove_member(&remove.guild_id, &remove.user.id);
+            }
+            DispatchEvent::ChannelCreate(channel) | DispatchEvent::ChannelUpdate(channel) => self.insert_channel(channel.clone()),
+            DispatchEvent::ChannelDelete(channel) => {
+                self.remove_channel(&channel.id);
+            }
+            DispatchEvent::ThreadCreate(create) => self.insert_thread(create.thread.clone()),
+            DispatchEvent::ThreadUpdate(thread) => {
+                // Archived threads are no longer sent updates and are dropped, like
+                // Discord clients do.
+                if thread.thread_metadata.archived {
+                    self.remove_thread(&thread.id);
+                } else {
+                    self.insert_thread(thread.clone());
+                }
+            }
+            DispatchEvent::ThreadDelete(delete) => {
+                self.remove_thread(&delete.id);
+            }
+            DispatchEvent::ThreadListSync(sync) => self.sync_threads(sync),
+            DispatchEvent::ThreadMemberUpdate(update) => {
+                if let Some(thread) = self.threads.get_mut(update.member.id.as_str()) {
+                    thread.member = Some(Box::new(update.member.clone()));
+                }
+            }
+            DispatchEvent::ThreadMembersUpdate(update) => {
+                if let Some(thread) = self.threads.get_mut(update.id.as_str()) {
+                    thread.member_count = update.member_count;
+                    if let Some(own) = &thread.member
+                        && update.removed_member_ids.iter().any(|id| own.user_id == *id)
+                    {
+                        thread.member = None;
+                    }
+                }
+            }
+            DispatchEvent::SoundboardSounds(sounds) | DispatchEvent::GuildSoundboardSoundsUpdate(sounds) => {
+                self.set_sounds(&sounds.guild_id, sounds.soundboard_sounds.clone());
+            }
+            DispatchEvent::GuildSoundboardSoundCreate(sound) | DispatchEvent::GuildSoundboardSoundUpdate(sound) => {
+                if let Some(guild_id) = &sound.guild_id {
+                    self.insert_sound(guild_id, sound.clone());
+                }
+            }
+            DispatchEvent::GuildSoundboardSoundDelete(delete) => {
+                self.remove_sound(&delete.guild_id, &delete.sound_id);
+            }
+            DispatchEvent::GuildAuditLogEntryCreate(_)
+            | DispatchEvent::MessagePollVoteAdd(_)
+            | DispatchEvent::MessagePollVoteRemove(_)
+            | DispatchEvent::EntitlementCreate(_)
+            | DispatchEvent::EntitlementUpdate(_)
+            | DispatchEvent::EntitlementDelete(_)
+            | DispatchEvent::Unknown(_) => {}
+        }
+    }
+
+    pub fn insert_member(&mut self, guild_id: &str, member: GuildMemberResponse) {
+        self.members.insert((guild_id.to_string(), member.user.id.to_string()), member);
+    }
+
+    pub fn remove_member(&mut self, guild_id: &str, user_id: &str) -> Option<GuildMemberResponse> {
+        self.members.remove(&(guild_id.to_string(), user_id.to_string()))
+    }
+
+    pub fn member(&self, guild_id: &str, user_id: &str) -> Option<&GuildMemberResponse> {
+        self.members.get(&(guild_id.to_string(), user_id.to_string()))
+    }
+
+    /// Guild wide permissions of a member, or `None` if the guild or member is not cached.
+    pub fn guild_permissions(&self, guild_id: &str, user_id: &str) -> Option<Permissions> {
+        let guild = self.guild(guild_id)?;
+        let member = self.member(guild_id, user_id)?;
+        let roles: Vec<_> = self.roles(guild_id).collect();
+
+        Some(base_permissions(guild_id, &guild.owner_id, member, &roles))
+    }
+
+    /// Effective permissions of a member in a channel, including overwrites.
+    /// Threads use the permissions of their parent channel.
+    pub fn channel_permissions(&self, channel_id: &str, user_id: &str) -> Option<Permissions> {
+        let channel = match self.channel(channel_id) {
+            Some(channel) => channel,
+            None => self.channel(self.thread(channel_id)?.parent_id.as_deref()?)?,
+        };
+        let base = self.guild_permissions(&channel.guild_id, user_id)?;
+        let member = self.member(&channel.guild_id, user_id)?;
+        let overwrites = channel.permission_overwrites.as_deref().unwrap_or_default();
+
+        Some(overwrite_permissions(base, &channel.guild_id, member, overwrites))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use openapi::models::{ChannelPermissionOverwriteResponse, GuildChannelResponse, GuildMemberResponse, GuildResponse, GuildRoleResponse, UserResponse};
+
+    use oozebot_protocol::events::receive::Dispatch;
+    use serde_json::json;
+
+    use crate::{events::DispatchEvent, permissions::Permissions};
+
+    use super::Cache;
+
+    #[test]
+    fn channel_permissions_from_cache() {
+        let mut cache = Cache::new();
+
+        cache.insert_guild(GuildResponse {
+            id: "1".into(),
+            owner_id: "owner".into(),
+            roles: vec![GuildRoleResponse {
+                id: "1".into(),
+                permissions: (Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES).bits().to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        cache.insert_role("1", GuildRoleResponse {
+            id: "2".into(),
+            permissions: Permissions::BAN_MEMBERS.bits().to_string(),
+            ..Default::default()
+        });
+        cache.insert_member("1", GuildMemberResponse {
+            user: Box::new(UserResponse { id: "bot".into(), ..Default::default() }),
+            roles: vec!["2".into()],
+            ..Default::default()
+        });
+        cache.insert_channel(GuildChannelResponse {
+            id: "10".into(),
+            guild_id: "1".into(),
+            permission_overwrites: Some(vec![ChannelPermissionOverwriteResponse::new(
+                "1".into(),
+                Some(0),
+                "0".to_string(),
+                Permissions::SEND_MESSAGES.bits().to_string(),
+            )]),
+            ..Default::default()
+        });
+
+        let guild = cache.guild_permissions("1", "bot").unwrap();
+        assert!(guild.require(Permissions::BAN_MEMBERS | Permissions::SEND_MESSAGES).is_ok());
+
+        let channel = cache.channel_permissions("10", "bot").unwrap();
+        assert!(channel.require(Permissions::SEND_MESSAGES).is_err());
+        assert!(channel.contains(Permissions::VIEW_CHANNEL));
+
+        assert_eq!(cache.channel_permissions("10", "stranger"), None);
+        assert_eq!(cache.channel_permissions("10", "owner"), None);
+    }
+
+    #[test]
+    fn guild_state_follows_gateway_events() {
+        let mut cache = Cache::new();
+        let event = |t: &str, d: serde_json::Value| {
+            DispatchEvent::try_from(Dispatch { op: 0, d, s: None, t: Some(t.to_string()) }).unwrap()
+        };
+        let role = |id: &str, permissions: Permissions| GuildRoleResponse {
+            id: id.into(),
+            permissions: permissions.bits().to_string(),
+            ..Default::default()
+        };
+        let user = json!(UserResponse { id: "mod".into(), ..Default::default() });
+
+        let mut guild = json!(GuildResponse {
+            id: "1".into(),
+            owner_id: "owner".into(),
+            roles: vec![role("1", Permissions::VIEW_CHANNEL)],
+            ..Default::default()
+        });
+        guild["members"] = json!([GuildMemberResponse { user: Box::new(UserResponse { id: "mod".into(), ..Default::default() }), ..Default::default() }]);
+        let mut channel = json!(GuildChannelResponse { id: "10".into(), ..Default::default() });
+        channel.as_object_mut().unwrap().remove("guild_id");
+        guild["channels"] = json!([channel]);
+        cache.update(&event("GUILD_CREATE", guild));
+        assert_eq!(cache.channel_permissions("10", "mod"), Some(Permissions::VIEW_CHANNEL));
+
+        cache.update(&event("GUILD_ROLE_CREATE", json!({ "guild_id": "1", "role": role("2", Permissions::BAN_MEMBERS) })));
+        cache.update(&event("GUILD_MEMBER_UPDATE", json!({ "guild_id": "1", "user": user, "roles": ["2"] })));
+        assert!(cache.guild_permissions("1", "mod").unwrap().contains(Permissions::BAN_MEMBERS));
+
+        cache.update(&event("GUILD_ROLE_DELETE", json!({ "guild_id": "1", "role_id": "2" })));
+        assert_eq!(cache.guild_permissions("1", "mod"), Some(Permissions::VIEW_CHANNEL));
+
+        cache.update(&event("GUILD_MEMBER_REMOVE", json!({ "guild_id": "1", "user": user })));
+        assert_eq!(cache.guild_permissions("1", "mod"), None);
+        cache.update(&event("GUILD_DELETE", json!({ "id": "1" })));
+        assert!(cache.channel("10").is_none());
+    }
+
+    #[test]
+    fn threads_follow_gateway_events() {
+        let mut cache = Cache::new();
+        let thread = |id: &str, parent: &str| json!({
+            "id": id,
+            "type": 11,
+            "flags": 0,
+            "guild_id": "1",
+            "name": "thread",
+            "parent_id": parent,
+            "owner_id": "owner",
+            "thread_metadata": { "archived": false, "auto_archive_duration": 1440, "locked": false },
+            "message_count": 0,
+            "member_count": 1,
+            "total_message_sent": 0,
+        });
+        let event = |t: &str, d: serde_json::Value| {
+            DispatchEvent::try_from(Dispatch { op: 0, d, s: None, t: Some(t.to_string()) }).unwrap()
+        };
+
+        cache.update(&event("THREAD_CREATE", thread("100", "10")));
+        cache.update(&event("THREAD_CREATE", thread("200", "20")));
+        cache.update(&event("THREAD_LIST_SYNC", json!({
+            "guild_id": "1",
+            "channel_ids": ["10"],
+            "threads": [thread("101", "10")],
+            "members": [{ "id": "101", "user_id": "bot", "join_timestamp": "2025-01-01T00:00:00Z", "flags": 1 }],
+        })));
+
+        assert!(cache.thread("100").is_none());
+        assert!(cache.thread("200").is_some());
+        assert_eq!(cache.joined_threads().map(|t| t.id.as_str()).collect::<Vec<_>>(), vec!["101"]);
+
+        let mut archived = thread("101", "10");
+        archived["thread_metadata"]["archived"] = json!(true);
+        cache.update(&event("THREAD_UPDATE", archived));
+        assert_eq!(cache.threads_in("10").count(), 0);
+    }
+}
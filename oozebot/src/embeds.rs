@@ -0,0 +1,479 @@
+use std::time::SystemTime;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use openapi::models::{
+    MessageCreateRequest, RichEmbed, RichEmbedAuthor, RichEmbedField, RichEmbedFooter, RichEmbedImage,
+    RichEmbedThumbnail,
+};
+use thiserror::Error;
+
+use crate::rest::attachments::Attachment;
+
+
+pub const MAX_TITLE: usize = 256;
+pub const MAX_DESCRIPTION: usize = 4096;
+pub const MAX_FIELDS: usize = 25;
+pub const MAX_FIELD_NAME: usize = 256;
+pub const MAX_FIELD_VALUE: usize = 1024;
+pub const MAX_FOOTER_TEXT: usize = 2048;
+pub const MAX_AUTHOR_NAME: usize = 256;
+/// Combined length of all text in all embeds of one message.
+pub const MAX_TOTAL: usize = 6000;
+pub const MAX_EMBEDS: usize = 10;
+pub const MAX_CONTENT: usize = 2000;
+
+/// The part of an embed a limit applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmbedPart {
+    Title,
+    Description,
+    Fields,
+    FieldName(usize),
+    FieldValue(usize),
+    FooterText,
+    AuthorName,
+    Total,
+    Embeds,
+    Content,
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("{part:?} is {length} long, the limit is {max}")]
+pub struct LimitError {
+    pub part: EmbedPart,
+    pub length: usize,
+    pub max: usize,
+}
+
+fn check(errors: &mut Vec<LimitError>, part: EmbedPart, length: usize, max: usize) {
+    if length > max {
+        errors.push(LimitError { part, length, max });
+    }
+}
+
+fn length(text: &Option<String>) -> usize {
+    text.as_deref().map_or(0, |text| text.chars().count())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Colour(pub u32);
+
+impl Colour {
+    pub const BLURPLE: Colour = Colour(0x5865F2);
+    pub const GREEN: Colour = Colour(0x57F287);
+    pub const YELLOW: Colour = Colour(0xFEE75C);
+    pub const RED: Colour = Colour(0xED4245);
+    pub const WHITE: Colour = Colour(0xFFFFFF);
+
+    pub const fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        Colour(((r as u32) << 16) | ((g as u32) << 8) | b as u32)
+    }
+
+    /// Parses `#rrggbb` or `rrggbb`.
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+        if hex.len() != 6 {
+            return None
+        }
+        u32::from_str_radix(hex, 16).ok().map(Colour)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Field {
+    name: String,
+    value: String,
+    inline: bool,
+}
+
+/// Fluent builder for `RichEmbed`. Nothing is checked until `build`, which reports
+/// every exceeded limit at once.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EmbedBuilder {
+    title: Option<String>,
+    description: Option<String>,
+    url: Option<String>,
+    colour: Option<Colour>,
+    timestamp: Option<String>,
+    fields: Vec<Field>,
+    footer_text: Option<String>,
+    footer_icon_url: Option<String>,
+    author_name: Option<String>,
+    author_url: Option<String>,
+    author_icon_url: Option<String>,
+    image_url: Option<String>,
+    thumbnail_url: Option<String>,
+}
+
+impl EmbedBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    pub fn colour(mut self, colour: Colour) -> Self {
+        self.colour = Some(colour);
+        self
+    }
+
+    pub fn timestamp(mut self, time: impl Into<DateTime<Utc>>) -> Self {
+        self.timestamp = Some(time.into().to_rfc3339_opts(SecondsFormat::Millis, true));
+        self
+    }
+
+    pub fn timestamp_now(self) -> Self {
+        self.timestamp(SystemTime::now())
+    }
+
+    pub fn field(mut self, name: impl Into<String>, value: impl Into<String>, inline: bool) -> Self {
+        self.fields.push(Field {
+            name: name.into(),
+            value: value.into(),
+            inline,
+        });
+        self
+    }
+
+    pub fn footer(mut self, text: impl Into<String>, icon_url: Option<String>) -> Self {
+        self.footer_text = Some(text.into());
+        self.footer_icon_url = icon_url;
+        self
+    }
+
+    pub fn author(mut self, name: impl Into<String>, url: Option<String>, icon_url: Option<String>) -> Self {
+        self.author_name = Some(name.into());
+        self.author_url = url;
+        self.author_icon_url = icon_url;
+        self
+    }
+
+    pub fn image(mut self, url: impl Into<String>) -> Self {
+        self.image_url = Some(url.into());
+        self
+    }
+
+    /// Shows a file uploaded with the same message as the embed image.
+    pub fn image_attachment(self, attachment: &Attachment) -> Self {
+        self.image(attachment.url())
+    }
+
+    pub fn thumbnail(mut self, url: impl Into<String>) -> Self {
+        self.thumbnail_url = Some(url.into());
+        self
+    }
+
+    pub fn thumbnail_attachment(self, attachment: &Attachment) -> Self {
+        self.thumbnail(attachment.url())
+    }
+
+    /// Number of characters counted towards the 6000 character total.
+    pub fn total_length(&self) -> usize {
+        length(&self.title)
+            + length(&self.description)
+            + length(&self.footer_text)
+            + length(&self.author_name)
+            + self.fields
+                .iter()
+                .map(|field| field.name.chars().count() + field.value.chars().count())
+                .sum::<usize>()
+    }
+
+    pub fn validate(&self) -> Vec<LimitError> {
+        let mut errors = Vec::new();
+
+        check(&mut errors, EmbedPart::Title, length(&self.title), MAX_TITLE);
+        check(&mut errors, EmbedPart::Description, length(&self.description), MAX_DESCRIPTION);
+        check(&mut errors, EmbedPart::Fields, self.fields.len(), MAX_FIELDS);
+        for (index, field) in self.fields.iter().enumerate() {
+            check(&mut errors, EmbedPart::FieldName(index), field.name.chars().count(), MAX_FIELD_NAME);
+            check(&mut errors, EmbedPart::FieldValue(index), field.value.chars().count(), MAX_FIELD_VALUE);
+        }
+        check(&mut errors, EmbedPart::FooterText, length(&self.footer_text), MAX_FOOTER_TEXT);
+        check(&mut errors, EmbedPart::AuthorName, length(&self.author_name), MAX_AUTHOR_NAME);
+        check(&mut errors, EmbedPart::Total, self.total_length(), MAX_TOTAL);
+
+        errors
+    }
+
+    pub fn build(self) -> Result<RichEmbed, Vec<LimitError>> {
+        let errors = self.validate();
+        if !errors.is_empty() {
+            return Err(errors)
+        }
+        Ok(self.into_embed())
+    }
+
+    /// Builds one or more embeds, moving description overflow and fields past the
+    /// 25 field limit into continuation embeds. Limits that cannot be fixed by
+    /// splitting (title, single field, footer, author) are still reported.
+    pub fn build_split(self) -> Result<Vec<RichEmbed>, Vec<LimitError>> {
+        let mut overflow = self.clone();
+        overflow.description = None;
+        overflow.fields.clear();
+        let mut errors = overflow.validate();
+        for (index, field) in self.fields.iter().enumerate() {
+            check(&mut errors, EmbedPart::FieldName(index), field.name.chars().count(), MAX_FIELD_NAME);
+            check(&mut errors, EmbedPart::FieldValue(index), field.value.chars().count(), MAX_FIELD_VALUE);
+        }
+        if !errors.is_empty() {
+            return Err(errors)
+        }
+
+        let continuation = EmbedBuilder {
+            colour: self.colour,
+            ..Default::default()
+        };
+        let mut embeds = Vec::new();
+        let mut current = EmbedBuilder {
+            description: None,
+            fields: Vec::new(),
+            ..self.clone()
+        };
+
+        // The first chunk shares the total with the title, footer and author.
+        let mut descriptions = Vec::new();
+        if let Some(description) = self.description.as_deref() {
+            let first_max = MAX_DESCRIPTION.min(MAX_TOTAL.saturating_sub(current.total_length())).max(1);
+            let first = split_text(description, first_max).into_iter().next().unwrap_or_default();
+            let rest = &description[first.len()..];
+            descriptions.push(first);
+            descriptions.extend(split_text(rest, MAX_DESCRIPTION));
+        }
+
+        for description in descriptions {
+            if current.description.is_some() {
+                embeds.push(std::mem::replace(&mut current, continuation.clone()));
+            }
+            current.description = Some(description);
+        }
+        for field in self.fields {
+            let field_length = field.name.chars().count() + field.value.chars().count();
+            if current.fields.len() == MAX_FIELDS || current.total_length() + field_length > MAX_TOTAL {
+                embeds.push(std::mem::replace(&mut current, continuation.clone()));
+            }
+            current.fields.push(field);
+        }
+        embeds.push(current);
+
+        Ok(embeds.into_iter().map(EmbedBuilder::into_embed).collect())
+    }
+
+    fn into_embed(self) -> RichEmbed {
+        let footer = self.footer_text.map(|text| Box::new(RichEmbedFooter {
+            text: Some(Some(text)),
+            icon_url: self.footer_icon_url.map(Some),
+        }));
+        let author = self.author_name.map(|name| Box::new(RichEmbedAuthor {
+            name: Some(Some(name)),
+            url: self.author_url.map(Some),
+            icon_url: self.author_icon_url.map(Some),
+        }));
+        let fields = (!self.fields.is_empty()).then(|| {
+            self.fields
+                .into_iter()
+                .map(|field| RichEmbedField {
+                    name: field.name,
+                    value: field.value,
+                    inline: Some(Some(field.inline)),
+                })
+                .collect()
+        });
+
+        RichEmbed {
+            title: self.title.map(Some),
+            description: self.description.map(Some),
+            url: self.url.map(Some),
+            color: self.colour.map(|colour| Some(colour.0 as i32)),
+            timestamp: self.timestamp.map(Some),
+            fields: fields.map(Some),
+            footer: footer.map(Some),
+            author: author.map(Some),
+            image: self.image_url.map(|url| Some(Box::new(RichEmbedImage { url: Some(Some(url)), ..Default::default() }))),
+            thumbnail: self.thumbnail_url.map(|url| Some(Box::new(RichEmbedThumbnail { url: Some(Some(url)), ..Default::default() }))),
+            ..Default::default()
+        }
+    }
+}
+
+fn embed_length(embed: &RichEmbed) -> usize {
+    let text = |value: &Option<Option<String>>| value.as_ref().and_then(Option::as_deref).map_or(0, |s| s.chars().count());
+
+    text(&embed.title)
+        + text(&embed.description)
+        + embed.footer.as_ref().and_then(Option::as_ref).map_or(0, |footer| text(&footer.text))
+        + embed.author.as_ref().and_then(Option::as_ref).map_or(0, |author| text(&author.name))
+        + embed.fields
+            .as_ref()
+            .and_then(Option::as_ref)
+            .map_or(0, |fields| fields.iter().map(|f| f.name.chars().count() + f.value.chars().count()).sum())
+}
+
+/// Checks the per message limits: content length, embed count and combined embed text.
+pub fn validate_message(content: Option<&str>, embeds: &[RichEmbed]) -> Vec<LimitError> {
+    let mut errors = Vec::new();
+    check(&mut errors, EmbedPart::Content, content.map_or(0, |c| c.chars().count()), MAX_CONTENT);
+    check(&mut errors, EmbedPart::Embeds, embeds.len(), MAX_EMBEDS);
+    check(&mut errors, EmbedPart::Total, embeds.iter().map(embed_length).sum(), MAX_TOTAL);
+    errors
+}
+
+/// Spreads `content` and `embeds` over as many messages as needed. Content is
+/// split on line or word boundaries and goes first, embeds follow in order.
+pub fn split_into_messages(content: Option<&str>, embeds: Vec<RichEmbed>) -> Vec<MessageCreateRequest> {
+    let mut messages: Vec<MessageCreateRequest> = content
+        .map(|content| split_text(content, MAX_CONTENT))
+        .unwrap_or_default()
+        .into_iter()
+        .map(|chunk| MessageCreateRequest {
+            content: Some(Some(chunk)),
+            ..Default::default()
+        })
+        .collect();
+
+    let mut batch: Vec<RichEmbed> = Vec::new();
+    let mut batch_length = 0;
+    for embed in embeds {
+        let length = embed_length(&embed);
+        if batch.len() == MAX_EMBEDS || (!batch.is_empty() && batch_length + length > MAX_TOTAL) {
+            messages.push(message_with_embeds(std::mem::take(&mut batch)));
+            batch_length = 0;
+        }
+        batch_length += length;
+        batch.push(embed);
+    }
+
+    if !batch.is_empty() {
+        // The last content chunk can carry the first batch of embeds.
+        match messages.last_mut() {
+            Some(last) if last.embeds.is_none() => last.embeds = Some(Some(batch)),
+            _ => messages.push(message_with_embeds(batch)),
+        }
+    }
+
+    messages
+}
+
+fn message_with_embeds(embeds: Vec<RichEmbed>) -> MessageCreateRequest {
+    MessageCreateRequest {
+        embeds: Some(Some(embeds)),
+        ..Default::default()
+    }
+}
+
+/// Splits `text` into chunks of at most `max` characters, preferring to break
+/// after a newline, then after a space.
+pub fn split_text(text: &str, max: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut rest = text;
+
+    while rest.chars().count() > max {
+        let hard_limit = rest.char_indices().nth(max).map_or(rest.len(), |(index, _)| index);
+        let window = &rest[..hard_limit];
+        let split_at = window
+            .rfind('\n')
+            .or_else(|| window.rfind(' '))
+            .map(|index| index + 1)
+            .filter(|&index| index > 0)
+            .unwrap_or(hard_limit);
+
+        chunks.push(rest[..split_at].to_string());
+        rest = &rest[split_at..];
+    }
+    if !rest.is_empty() {
+        chunks.push(rest.to_string());
+    }
+
+    chunks
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_reports_every_exceeded_limit() {
+        let mut builder = EmbedBuilder::new()
+            .title("t".repeat(300))
+            .footer("f".repeat(10), None);
+        for i in 0..26 {
+            builder = builder.field(format!("field {i}"), "v", true);
+        }
+        builder = builder.field("name", "v".repeat(1025), false);
+
+        let errors = builder.build().unwrap_err();
+
+        assert_eq!(errors, vec![
+            LimitError { part: EmbedPart::Title, length: 300, max: MAX_TITLE },
+            LimitError { part: EmbedPart::Fields, length: 27, max: MAX_FIELDS },
+            LimitError { part: EmbedPart::FieldValue(26), length: 1025, max: MAX_FIELD_VALUE },
+        ]);
+    }
+
+    #[test]
+    fn build_sets_helpers() {
+        let image = Attachment::from_bytes("chart.png", vec![]);
+        let embed = EmbedBuilder::new()
+            .title("Stream stats")
+            .colour(Colour::from_hex("#5865F2").unwrap())
+            .timestamp(DateTime::parse_from_rfc3339("2025-01-02T03:04:05Z").unwrap().with_timezone(&Utc))
+            .image_attachment(&image)
+            .build()
+            .unwrap();
+
+        assert_eq!(embed.color, Some(Some(Colour::BLURPLE.0 as i32)));
+        assert_eq!(embed.timestamp, Some(Some("2025-01-02T03:04:05.000Z".to_string())));
+        assert_eq!(embed.image.flatten().unwrap().url, Some(Some("attachment://chart.png".to_string())));
+    }
+
+    #[test]
+    fn long_embeds_and_content_are_split() {
+        let mut builder = EmbedBuilder::new().title("Chat log").description("word ".repeat(1000));
+        for i in 0..30 {
+            builder = builder.field(format!("{i}"), "value", false);
+        }
+        let embeds = builder.build_split().unwrap();
+
+        assert_eq!(embeds.len(), 3);
+        assert!(embeds.iter().all(|embed| embed_length(embed) <= MAX_TOTAL));
+        assert_eq!(embeds[0].title, Some(Some("Chat log".to_string())));
+        assert_eq!(embeds[1].title, None);
+        assert_eq!(embeds[2].fields.clone().flatten().unwrap().len(), 5);
+
+        let content = "line\n".repeat(500);
+        let messages = split_into_messages(Some(&content), embeds);
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[1].embeds.clone().flatten().unwrap().len(), 3);
+        assert!(messages.iter().all(|m| validate_message(m.content.clone().flatten().as_deref(), &m.embeds.clone().flatten().unwrap_or_default()).is_empty()));
+    }
+
+    #[test]
+    fn split_description_leaves_room_for_title_and_footer() {
+        let description = "word ".repeat(1000);
+        let embeds = EmbedBuilder::new()
+            .title("t".repeat(MAX_TITLE))
+            .footer("f".repeat(MAX_FOOTER_TEXT), None)
+            .description(description.clone())
+            .build_split()
+            .unwrap();
+
+        assert_eq!(embeds.len(), 2);
+        assert!(embeds.iter().all(|embed| embed_length(embed) <= MAX_TOTAL));
+        let joined: String = embeds.iter().filter_map(|embed| embed.description.clone().flatten()).collect();
+        assert_eq!(joined, description);
+    }
+}
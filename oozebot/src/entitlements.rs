@@ -0,0 +1,297 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use futures::TryStreamExt;
+use openapi::models::{
+    ActionRowComponentForMessageRequest, ActionRowComponentForMessageRequestComponentsInner,
+    BaseCreateMessageCreateRequestComponentsInner, ButtonComponentForMessageRequest, CreateEntitlementRequestData,
+    CreateInteractionResponseRequest, EntitlementResponse, IncomingWebhookUpdateForInteractionCallbackRequestPartial,
+};
+use thiserror::Error;
+
+use crate::{
+    events::DispatchEvent,
+    rest::{
+        pagination::{Cursor, Paginator},
+        RestClient, RestError,
+    },
+};
+
+
+/// Entitlement type of the ones made with `create_test_entitlement`.
+pub const TEST_MODE_PURCHASE: i32 = 4;
+
+const COMPONENT_ACTION_ROW: i32 = 1;
+const COMPONENT_BUTTON: i32 = 2;
+const BUTTON_PREMIUM: i32 = 6;
+const CHANNEL_MESSAGE_WITH_SOURCE: i32 = 4;
+const EPHEMERAL: i32 = 1 << 6;
+
+/// Who a test entitlement is granted to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EntitlementOwner {
+    Guild(String),
+    User(String),
+}
+
+impl EntitlementOwner {
+    fn request(&self, sku_id: &str) -> CreateEntitlementRequestData {
+        let (owner_id, owner_type) = match self {
+            EntitlementOwner::Guild(id) => (id, 1),
+            EntitlementOwner::User(id) => (id, 2),
+        };
+        CreateEntitlementRequestData {
+            sku_id: sku_id.into(),
+            owner_id: owner_id.as_str().into(),
+            owner_type,
+        }
+    }
+}
+
+fn parse_time(time: &Option<Option<String>>) -> Option<DateTime<Utc>> {
+    let time = time.as_ref()?.as_deref()?;
+    DateTime::parse_from_rfc3339(time).ok().map(|time| time.with_timezone(&Utc))
+}
+
+/// Whether an entitlement grants its SKU at `now`. Consumed one-time purchases and
+/// entitlements outside their subscription period do not.
+pub fn is_active(entitlement: &EntitlementResponse, now: DateTime<Utc>) -> bool {
+    !entitlement.deleted
+        && entitlement.consumed != Some(Some(true))
+        && parse_time(&entitlement.starts_at).is_none_or(|starts| starts <= now)
+        && parse_time(&entitlement.ends_at).is_none_or(|ends| now < ends)
+}
+
+/// Entitlements of the application by id, loaded once through REST and kept up to
+/// date by the `ENTITLEMENT_*` gateway events.
+#[derive(Debug, Default)]
+pub struct Entitlements {
+    entitlements: HashMap<String, EntitlementResponse>,
+}
+
+impl Entitlements {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every entitlement of the application that has not ended.
+    pub async fn load(rest: &RestClient, application_id: &str) -> Result<Self, RestError> {
+        let mut entitlements = Entitlements::new();
+        rest.paginate_entitlements(application_id, None, None)
+            .into_stream()
+            .try_for_each(|entitlement| {
+                entitlements.insert(entitlement);
+                futures::future::ready(Ok(()))
+            })
+            .await?;
+        Ok(entitlements)
+    }
+
+    pub fn insert(&mut self, entitlement: EntitlementResponse) {
+        self.entitlements.insert(entitlement.id.to_string(), entitlement);
+    }
+
+    pub fn remove(&mut self, entitlement_id: &str) -> Option<EntitlementResponse> {
+        self.entitlements.remove(entitlement_id)
+    }
+
+    pub fn get(&self, entitlement_id: &str) -> Option<&EntitlementResponse> {
+        self.entitlements.get(entitlement_id)
+    }
+
+    /// Active entitlements held by the user, or by the guild they are in.
+    pub fn active_for<'a>(&'a self, user_id: &'a str, guild_id: Option<&'a str>) -> impl Iterator<Item = &'a EntitlementResponse> {
+        let now = Utc::now();
+        self.entitlements.values().filter(move |entitlement| {
+            let owned = match &entitlement.guild_id {
+                Some(guild) => guild_id == Some(guild.as_str()),
+                None => entitlement.user_id == user_id,
+            };
+            owned && is_active(entitlement, now)
+        })
+    }
+
+    pub fn has_sku(&self, sku_id: &str, user_id: &str, guild_id: Option<&str>) -> bool {
+        self.active_for(user_id, guild_id).any(|entitlement| entitlement.sku_id == sku_id)
+    }
+
+    pub fn update(&mut self, event: &DispatchEvent) {
+        match event {
+            DispatchEvent::EntitlementCreate(entitlement) | DispatchEvent::EntitlementUpdate(entitlement) => {
+                self.insert(entitlement.clone());
+            }
+            DispatchEvent::EntitlementDelete(entitlement) => {
+                self.remove(&entitlement.id);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The invoking user holds none of the SKUs a command requires.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("{message}")]
+pub struct PremiumRequired {
+    pub sku_id: String,
+    pub message: String,
+}
+
+impl PremiumRequired {
+    /// An ephemeral reply with a button to buy the SKU.
+    pub fn response(&self) -> CreateInteractionResponseRequest {
+        let button = ButtonComponentForMessageRequest {
+            r#type: COMPONENT_BUTTON,
+            style: Some(BUTTON_PREMIUM),
+            sku_id: Some(self.sku_id.as_str().into()),
+            ..Default::default()
+        };
+        let row = ActionRowComponentForMessageRequest {
+            r#type: COMPONENT_ACTION_ROW,
+            components: vec![ActionRowComponentForMessageRequestComponentsInner::ButtonComponentForMessageRequest(Box::new(button))],
+            ..Default::default()
+        };
+        let data = IncomingWebhookUpdateForInteractionCallbackRequestPartial {
+            content: Some(Some(self.message.clone())),
+            components: Some(Some(vec![BaseCreateMessageCreateRequestComponentsInner::ActionRowComponentForMessageRequest(Box::new(row))])),
+            flags: Some(Some(EPHEMERAL)),
+            ..Default::default()
+        };
+        CreateInteractionResponseRequest::new(Some(CHANNEL_MESSAGE_WITH_SOURCE), data)
+    }
+}
+
+/// Guard for a premium command, passing if the user or their guild holds any of
+/// the SKUs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequiresSku {
+    sku_ids: Vec<String>,
+    message: String,
+}
+
+pub fn requires_sku(sku_id: impl Into<String>) -> RequiresSku {
+    RequiresSku {
+        sku_ids: vec![sku_id.into()],
+        message: "This command is part of premium.".to_string(),
+    }
+}
+
+impl RequiresSku {
+    /// Also lets holders of `sku_id` through. The first SKU is the one offered.
+    pub fn or_sku(mut self, sku_id: impl Into<String>) -> Self {
+        self.sku_ids.push(sku_id.into());
+        self
+    }
+
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    pub fn check(&self, entitlements: &Entitlements, user_id: &str, guild_id: Option<&str>) -> Result<(), PremiumRequired> {
+        if self.sku_ids.iter().any(|sku| entitlements.has_sku(sku, user_id, guild_id)) {
+            return Ok(())
+        }
+        Err(PremiumRequired {
+            sku_id: self.sku_ids[0].clone(),
+            message: self.message.clone(),
+        })
+    }
+}
+
+impl RestClient {
+    /// Entitlements of the application that have not ended, 100 per request,
+    /// optionally only those of one user or guild.
+    pub fn paginate_entitlements<'a>(&'a self, application_id: &str, user_id: Option<&str>, guild_id: Option<&str>) -> Paginator<'a, EntitlementResponse> {
+        let application_id = application_id.to_string();
+        let user_id = user_id.map(str::to_string);
+        let guild_id = guild_id.map(str::to_string);
+        Paginator::new(Cursor::oldest(), 100, |entitlement: &EntitlementResponse| &entitlement.id, move |cursor, limit| {
+            let (application_id, user_id, guild_id) = (application_id.clone(), user_id.clone(), guild_id.clone());
+            Box::pin(async move {
+                let page = self
+                    .api()
+                    .get_entitlements(
+                        &application_id,
+                        user_id.as_deref(),
+                        None,
+                        guild_id.as_deref(),
+                        None,
+                        cursor.after(),
+                        Some(limit.into()),
+                        Some(true),
+                        None,
+                        None,
+                    )
+                    .await?;
+                Ok(page.into_iter().flatten().collect())
+            })
+        })
+    }
+
+    /// Grants a SKU without a purchase, for trying premium features. Test
+    /// entitlements never end and are removed with `delete_entitlement`.
+    pub async fn create_test_entitlement(&self, application_id: &str, sku_id: &str, owner: &EntitlementOwner) -> Result<EntitlementResponse, RestError> {
+        self.api().create_entitlement(application_id, &owner.request(sku_id)).await
+    }
+
+    /// Answers an interaction whose command failed a `RequiresSku` guard.
+    pub async fn respond_premium_required(&self, interaction_id: &str, interaction_token: &str, premium: &PremiumRequired) -> Result<(), RestError> {
+        self.api().create_interaction_response(interaction_id, interaction_token, &premium.response(), None).await?;
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use oozebot_protocol::events::receive::Dispatch;
+    use serde_json::json;
+
+    use super::*;
+
+    fn event(t: &str, d: serde_json::Value) -> DispatchEvent {
+        DispatchEvent::try_from(Dispatch { op: 0, d, s: None, t: Some(t.to_string()) }).unwrap()
+    }
+
+    fn entitlement(id: &str, sku_id: &str, guild_id: Option<&str>) -> serde_json::Value {
+        json!({
+            "id": id,
+            "sku_id": sku_id,
+            "application_id": "1",
+            "user_id": "10",
+            "guild_id": guild_id,
+            "deleted": false,
+            "type": TEST_MODE_PURCHASE,
+        })
+    }
+
+    #[test]
+    fn guard_follows_entitlement_events() {
+        let guard = requires_sku("500").or_sku("501");
+        let mut entitlements = Entitlements::new();
+        assert_eq!(guard.check(&entitlements, "10", None).unwrap_err().sku_id, "500");
+
+        entitlements.update(&event("ENTITLEMENT_CREATE", entitlement("7", "501", Some("20"))));
+        assert!(guard.check(&entitlements, "11", Some("20")).is_ok());
+        assert!(guard.check(&entitlements, "10", None).is_err());
+
+        let mut ended = entitlement("7", "501", Some("20"));
+        ended["ends_at"] = json!("2020-01-01T00:00:00+00:00");
+        entitlements.update(&event("ENTITLEMENT_UPDATE", ended));
+        assert!(guard.check(&entitlements, "11", Some("20")).is_err());
+
+        entitlements.update(&event("ENTITLEMENT_CREATE", entitlement("8", "500", None)));
+        assert!(guard.check(&entitlements, "10", Some("30")).is_ok());
+        entitlements.update(&event("ENTITLEMENT_DELETE", entitlement("8", "500", None)));
+        assert!(entitlements.get("8").is_none());
+    }
+
+    #[test]
+    fn premium_response_offers_sku() {
+        let premium = requires_sku("500").check(&Entitlements::new(), "10", None).unwrap_err();
+        let response = serde_json::to_value(premium.response()).unwrap();
+        assert_eq!(response["type"], 4);
+        assert_eq!(response["data"]["flags"], 64);
+        assert_eq!(response["data"]["components"][0]["components"][0], json!({ "type": 2, "style": 6, "sku_id": "500" }));
+    }
+}
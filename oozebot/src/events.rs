@@ -0,0 +1,227 @@
+use oozebot_protocol::events::receive::Dispatch;
+use openapi::models::{AuditLogEntryResponse, EntitlementResponse, GuildChannelResponse, GuildMemberResponse, GuildResponse, GuildRoleResponse, SoundboardSoundResponse, ThreadMemberResponse, ThreadResponse, UserResponse};
+use serde::Deserialize;
+
+
+/// Typed payload of a gateway dispatch. Event names we do not handle yet are
+/// kept as the raw `Dispatch`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DispatchEvent {
+    GuildCreate(Box<GuildCreate>),
+    GuildDelete(GuildDelete),
+    GuildRoleCreate(GuildRole),
+    GuildRoleUpdate(GuildRole),
+    GuildRoleDelete(GuildRoleDelete),
+    GuildMemberAdd(GuildMember),
+    GuildMemberUpdate(GuildMemberUpdate),
+    GuildMemberRemove(GuildMemberRemove),
+    ChannelCreate(GuildChannelResponse),
+    ChannelUpdate(GuildChannelResponse),
+    ChannelDelete(GuildChannelResponse),
+    GuildAuditLogEntryCreate(GuildAuditLogEntry),
+    ThreadCreate(ThreadCreate),
+    ThreadUpdate(ThreadResponse),
+    ThreadDelete(ThreadDelete),
+    ThreadListSync(ThreadListSync),
+    ThreadMemberUpdate(ThreadMemberUpdate),
+    ThreadMembersUpdate(ThreadMembersUpdate),
+    SoundboardSounds(SoundboardSounds),
+    GuildSoundboardSoundCreate(SoundboardSoundResponse),
+    GuildSoundboardSoundUpdate(SoundboardSoundResponse),
+    GuildSoundboardSoundDelete(GuildSoundboardSoundDelete),
+    GuildSoundboardSoundsUpdate(SoundboardSounds),
+    MessagePollVoteAdd(PollVote),
+    MessagePollVoteRemove(PollVote),
+    EntitlementCreate(EntitlementResponse),
+    EntitlementUpdate(EntitlementResponse),
+    EntitlementDelete(EntitlementResponse),
+    Unknown(Dispatch),
+}
+
+/// A guild becoming available, with the members and channels the bot can see.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct GuildCreate {
+    #[serde(flatten)]
+    pub guild: GuildResponse,
+    #[serde(default)]
+    pub members: Vec<GuildMemberResponse>,
+    #[serde(default)]
+    pub channels: Vec<GuildChannelResponse>,
+    #[serde(default)]
+    pub threads: Vec<ThreadResponse>,
+}
+
+/// The bot leaving a guild, or the guild going `unavailable` in an outage.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct GuildDelete {
+    pub id: String,
+    #[serde(default)]
+    pub unavailable: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct GuildRole {
+    pub guild_id: String,
+    pub role: GuildRoleResponse,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct GuildRoleDelete {
+    pub guild_id: String,
+    pub role_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct GuildMember {
+    pub guild_id: String,
+    #[serde(flatten)]
+    pub member: GuildMemberResponse,
+}
+
+/// The fields of a member that `GUILD_MEMBER_UPDATE` always carries.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct GuildMemberUpdate {
+    pub guild_id: String,
+    pub user: UserResponse,
+    pub roles: Vec<String>,
+    #[serde(default)]
+    pub nick: Option<String>,
+    #[serde(default)]
+    pub communication_disabled_until: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct GuildMemberRemove {
+    pub guild_id: String,
+    pub user: UserResponse,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct GuildAuditLogEntry {
+    pub guild_id: String,
+    #[serde(flatten)]
+    pub entry: AuditLogEntryResponse,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ThreadCreate {
+    #[serde(flatten)]
+    pub thread: ThreadResponse,
+    /// `false` when the event is sent because the bot was added to an existing thread.
+    #[serde(default)]
+    pub newly_created: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ThreadDelete {
+    pub id: String,
+    pub guild_id: String,
+    pub parent_id: Option<String>,
+    #[serde(rename = "type")]
+    pub kind: i32,
+}
+
+/// Active threads sent when the bot gains access to channels. Without `channel_ids`
+/// the whole guild is being synced.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ThreadListSync {
+    pub guild_id: String,
+    pub channel_ids: Option<Vec<String>>,
+    pub threads: Vec<ThreadResponse>,
+    /// The bot's own membership of each synced thread it has joined.
+    pub members: Vec<ThreadMemberResponse>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ThreadMemberUpdate {
+    pub guild_id: String,
+    #[serde(flatten)]
+    pub member: ThreadMemberResponse,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ThreadMembersUpdate {
+    pub id: String,
+    pub guild_id: String,
+    pub member_count: i32,
+    #[serde(default)]
+    pub added_members: Vec<ThreadMemberResponse>,
+    #[serde(default)]
+    pub removed_member_ids: Vec<String>,
+}
+
+/// Reply to a `RequestSoundboardSounds`, and the payload of `GUILD_SOUNDBOARD_SOUNDS_UPDATE`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SoundboardSounds {
+    pub guild_id: String,
+    pub soundboard_sounds: Vec<SoundboardSoundResponse>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct GuildSoundboardSoundDelete {
+    pub sound_id: String,
+    pub guild_id: String,
+}
+
+/// A user adding or removing their vote for one answer of a poll.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct PollVote {
+    pub user_id: String,
+    pub channel_id: String,
+    pub message_id: String,
+    pub guild_id: Option<String>,
+    pub answer_id: i32,
+}
+
+impl TryFrom<Dispatch> for DispatchEvent {
+    type Error = serde_json::Error;
+
+    fn try_from(mut dispatch: Dispatch) -> Result<Self, Self::Error> {
+        match dispatch.t.as_deref() {
+            // Sent for guilds that are still unavailable after connecting.
+            Some("GUILD_CREATE") if dispatch.d["unavailable"] == true => Ok(DispatchEvent::Unknown(dispatch)),
+            Some("GUILD_CREATE") => {
+                // Channels and threads inside the guild leave out its id.
+                let guild_id = dispatch.d["id"].clone();
+                for key in ["channels", "threads"] {
+                    if let Some(channels) = dispatch.d.get_mut(key).and_then(|channels| channels.as_array_mut()) {
+                        for channel in channels.iter_mut().filter_map(|channel| channel.as_object_mut()) {
+                            channel.entry("guild_id").or_insert_with(|| guild_id.clone());
+                        }
+                    }
+                }
+                serde_json::from_value(dispatch.d).map(|guild| DispatchEvent::GuildCreate(Box::new(guild)))
+            }
+            Some("GUILD_DELETE") => serde_json::from_value(dispatch.d).map(DispatchEvent::GuildDelete),
+            Some("GUILD_ROLE_CREATE") => serde_json::from_value(dispatch.d).map(DispatchEvent::GuildRoleCreate),
+            Some("GUILD_ROLE_UPDATE") => serde_json::from_value(dispatch.d).map(DispatchEvent::GuildRoleUpdate),
+            Some("GUILD_ROLE_DELETE") => serde_json::from_value(dispatch.d).map(DispatchEvent::GuildRoleDelete),
+            Some("GUILD_MEMBER_ADD") => serde_json::from_value(dispatch.d).map(DispatchEvent::GuildMemberAdd),
+            Some("GUILD_MEMBER_UPDATE") => serde_json::from_value(dispatch.d).map(DispatchEvent::GuildMemberUpdate),
+            Some("GUILD_MEMBER_REMOVE") => serde_json::from_value(dispatch.d).map(DispatchEvent::GuildMemberRemove),
+            Some("CHANNEL_CREATE") => serde_json::from_value(dispatch.d).map(DispatchEvent::ChannelCreate),
+            Some("CHANNEL_UPDATE") => serde_json::from_value(dispatch.d).map(DispatchEvent::ChannelUpdate),
+            Some("CHANNEL_DELETE") => serde_json::from_value(dispatch.d).map(DispatchEvent::ChannelDelete),
+            Some("GUILD_AUDIT_LOG_ENTRY_CREATE") => {
+                serde_json::from_value(dispatch.d).map(DispatchEvent::GuildAuditLogEntryCreate)
+            }
+            Some("THREAD_CREATE") => serde_json::from_value(dispatch.d).map(DispatchEvent::ThreadCreate),
+            Some("THREAD_UPDATE") => serde_json::from_value(dispatch.d).map(DispatchEvent::ThreadUpdate),
+            Some("THREAD_DELETE") => serde_json::from_value(dispatch.d).map(DispatchEvent::ThreadDelete),
+            Some("THREAD_LIST_SYNC") => serde_json::from_value(dispatch.d).map(DispatchEvent::ThreadListSync),
+            Some("THREAD_MEMBER_UPDATE") => serde_json::from_value(dispatch.d).map(DispatchEvent::ThreadMemberUpdate),
+            Some("THREAD_MEMBERS_UPDATE") => serde_json::from_value(dispatch.d).map(DispatchEvent::ThreadMembersUpdate),
+            Some("SOUNDBOARD_SOUNDS") => serde_json::from_value(dispatch.d).map(DispatchEvent::SoundboardSounds),
+            Some("GUILD_SOUNDBOARD_SOUND_CREATE") => serde_json::from_value(dispatch.d).map(DispatchEvent::GuildSoundboardSoundCreate),
+            Some("GUILD_SOUNDBOARD_SOUND_UPDATE") => serde_json::from_value(dispatch.d).map(DispatchEvent::GuildSoundboardSoundUpdate),
+            Some("GUILD_SOUNDBOARD_SOUND_DELETE") => serde_json::from_value(dispatch.d).map(DispatchEvent::GuildSoundboardSoundDelete),
+            Some("GUILD_SOUNDBOARD_SOUNDS_UPDATE") => serde_json::from_value(dispatch.d).map(DispatchEvent::GuildSoundboardSoundsUpdate),
+            Some("MESSAGE_POLL_VOTE_ADD") => serde_json::from_value(dispatch.d).map(DispatchEvent::MessagePollVoteAdd),
+            Some("MESSAGE_POLL_VOTE_REMOVE") => serde_json::from_value(dispatch.d).map(DispatchEvent::MessagePollVoteRemove),
+            Some("ENTITLEMENT_CREATE") => serde_json::from_value(dispatch.d).map(DispatchEvent::EntitlementCreate),
+            Some("ENTITLEMENT_UPDATE") => serde_json::from_value(dispatch.d).map(DispatchEvent::EntitlementUpdate),
+            Some("ENTITLEMENT_DELETE") => serde_json::from_value(dispatch.d).map(DispatchEvent::EntitlementDelete),
+            _ => Ok(DispatchEvent::Unknown(dispatch)),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+pub mod cache;
+pub mod embeds;
+pub mod entitlements;
+pub mod events;
+pub mod linked_roles;
+pub mod moderation;
+pub mod oauth;
+pub mod permissions;
+pub mod polls;
+pub mod rest;
+pub mod scheduled_events;
+pub mod snowflake;
+pub mod soundboard;
+pub mod threads;
+pub mod webhook;
+pub mod youtube;
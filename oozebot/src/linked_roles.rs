@@ -0,0 +1,305 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use openapi::models::{
+    ApplicationRoleConnectionsMetadataItemRequest, ApplicationRoleConnectionsMetadataItemResponse,
+    ApplicationUserRoleConnectionResponse, ConnectedAccountResponse, UpdateApplicationUserRoleConnectionRequest,
+};
+use reqwest::Method;
+use thiserror::Error;
+
+use crate::{
+    oauth::TokenJar,
+    rest::{Request, RestClient, RestError},
+    youtube,
+};
+
+
+/// An application may register at most 5 metadata records.
+pub const MAX_METADATA_FIELDS: usize = 5;
+
+#[derive(Debug, Error)]
+pub enum LinkedRolesError {
+    #[error(transparent)]
+    Rest(#[from] RestError),
+
+    #[error("YouTube request failed: {0}")]
+    YouTube(#[from] reqwest::Error),
+
+    #[error("Invalid role connection metadata: {0}")]
+    InvalidSchema(String),
+
+    #[error("The user has not connected a YouTube channel to their Discord account")]
+    NoYouTubeConnection,
+}
+
+/// How a server's linked role compares its configured value against the user's.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i32)]
+pub enum MetadataType {
+    IntegerLessThanOrEqual = 1,
+    IntegerGreaterThanOrEqual = 2,
+    IntegerEqual = 3,
+    IntegerNotEqual = 4,
+    DatetimeLessThanOrEqual = 5,
+    DatetimeGreaterThanOrEqual = 6,
+    BooleanEqual = 7,
+    BooleanNotEqual = 8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MetadataField {
+    pub kind: MetadataType,
+    pub key: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetadataValue {
+    Integer(i64),
+    Datetime(DateTime<Utc>),
+    Boolean(bool),
+}
+
+impl MetadataValue {
+    fn matches(&self, kind: MetadataType) -> bool {
+        use MetadataType::*;
+        matches!(
+            (self, kind),
+            (MetadataValue::Integer(_), IntegerLessThanOrEqual | IntegerGreaterThanOrEqual | IntegerEqual | IntegerNotEqual)
+                | (MetadataValue::Datetime(_), DatetimeLessThanOrEqual | DatetimeGreaterThanOrEqual)
+                | (MetadataValue::Boolean(_), BooleanEqual | BooleanNotEqual)
+        )
+    }
+
+    /// Discord stores every value as a string: integers as is, datetimes as ISO 8601
+    /// and booleans as `1` or `0`.
+    fn encode(&self) -> String {
+        match self {
+            MetadataValue::Integer(value) => value.to_string(),
+            MetadataValue::Datetime(value) => value.to_rfc3339_opts(SecondsFormat::Secs, true),
+            MetadataValue::Boolean(value) => if *value { "1" } else { "0" }.to_string(),
+        }
+    }
+}
+
+/// A user's role connection, declared as a Rust type. `FIELDS` is registered once
+/// with `RestClient::register_role_connection_metadata`, `values` is sent for each user.
+pub trait RoleConnectionMetadata {
+    const FIELDS: &'static [MetadataField];
+
+    fn values(&self) -> Vec<(&'static str, MetadataValue)>;
+
+    fn schema() -> Result<Vec<ApplicationRoleConnectionsMetadataItemRequest>, LinkedRolesError> {
+        if Self::FIELDS.len() > MAX_METADATA_FIELDS {
+            return Err(LinkedRolesError::InvalidSchema(format!("{} fields, at most {} allowed", Self::FIELDS.len(), MAX_METADATA_FIELDS)))
+        }
+
+        Self::FIELDS
+            .iter()
+            .map(|field| {
+                let valid_key = (1..=50).contains(&field.key.len())
+                    && field.key.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_');
+                if !valid_key {
+                    return Err(LinkedRolesError::InvalidSchema(format!("key {:?} must be 1-50 characters of a-z, 0-9 or _", field.key)))
+                }
+                if !(1..=100).contains(&field.name.chars().count()) || !(1..=200).contains(&field.description.chars().count()) {
+                    return Err(LinkedRolesError::InvalidSchema(format!("name or description of {:?} is empty or too long", field.key)))
+                }
+                Ok(ApplicationRoleConnectionsMetadataItemRequest::new(
+                    field.kind as i32,
+                    field.key.to_string(),
+                    field.name.to_string(),
+                    field.description.to_string(),
+                ))
+            })
+            .collect()
+    }
+
+    /// Encodes `values`, checking each against the declared field of the same key.
+    fn metadata(&self) -> Result<HashMap<String, String>, LinkedRolesError> {
+        self.values()
+            .into_iter()
+            .map(|(key, value)| {
+                let field = Self::FIELDS
+                    .iter()
+                    .find(|field| field.key == key)
+                    .ok_or_else(|| LinkedRolesError::InvalidSchema(format!("{key:?} is not a declared field")))?;
+                if !value.matches(field.kind) {
+                    return Err(LinkedRolesError::InvalidSchema(format!("{key:?} expects a {:?} value", field.kind)))
+                }
+                Ok((key.to_string(), value.encode()))
+            })
+            .collect()
+    }
+}
+
+impl RestClient {
+    pub async fn get_role_connection_metadata(&self, application_id: &str) -> Result<Vec<ApplicationRoleConnectionsMetadataItemResponse>, RestError> {
+        let request = Request::new(Method::GET, format!("/applications/{application_id}/role-connections/metadata"));
+        self.send(request).await
+    }
+
+    /// Replaces the application's metadata records with `M`'s fields.
+    pub async fn register_role_connection_metadata<M: RoleConnectionMetadata>(&self, application_id: &str) -> Result<Vec<ApplicationRoleConnectionsMetadataItemResponse>, LinkedRolesError> {
+        let request = Request::new(Method::PUT, format!("/applications/{application_id}/role-connections/metadata"))
+            .json(&M::schema()?)?;
+        Ok(self.send(request).await?)
+    }
+
+    /// Sets the role connection of the user `jar` was authorized by. Needs the
+    /// `role_connections.write` scope.
+    pub async fn update_role_connection<M: RoleConnectionMetadata>(
+        &self,
+        jar: &TokenJar,
+        application_id: &str,
+        platform_username: Option<&str>,
+        metadata: &M,
+    ) -> Result<ApplicationUserRoleConnectionResponse, LinkedRolesError> {
+        let body = UpdateApplicationUserRoleConnectionRequest {
+            platform_name: None,
+            platform_username: platform_username.map(|name| Some(name.to_string())),
+            metadata: Some(metadata.metadata()?),
+        };
+        let request = Request::new(Method::PUT, format!("/users/@me/applications/{application_id}/role-connection"))
+            .json(&body)?
+            .bearer(jar.access_token());
+        Ok(self.send(request).await?)
+    }
+
+    /// Accounts the user linked to Discord. Needs the `connections` scope.
+    pub async fn get_user_connections(&self, jar: &TokenJar) -> Result<Vec<ConnectedAccountResponse>, RestError> {
+        let request = Request::new(Method::GET, "/users/@me/connections").bearer(jar.access_token());
+        self.send(request).await
+    }
+}
+
+/// Linked role data for viewers who are members of our YouTube channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YouTubeMembership {
+    pub channel_name: Option<String>,
+    pub is_member: bool,
+    pub member_since: Option<DateTime<Utc>>,
+    pub months: u32,
+}
+
+impl RoleConnectionMetadata for YouTubeMembership {
+    const FIELDS: &'static [MetadataField] = &[
+        MetadataField {
+            kind: MetadataType::BooleanEqual,
+            key: "is_member",
+            name: "Channel member",
+            description: "Is a member of the YouTube channel",
+        },
+        MetadataField {
+            kind: MetadataType::DatetimeLessThanOrEqual,
+            key: "member_since",
+            name: "Member for",
+            description: "Days since joining the channel membership",
+        },
+        MetadataField {
+            kind: MetadataType::IntegerGreaterThanOrEqual,
+            key: "months",
+            name: "Months as member",
+            description: "Total months of channel membership",
+        },
+    ];
+
+    fn values(&self) -> Vec<(&'static str, MetadataValue)> {
+        let mut values = vec![
+            ("is_member", MetadataValue::Boolean(self.is_member)),
+            ("months", MetadataValue::Integer(self.months.into())),
+        ];
+        if let Some(member_since) = self.member_since {
+            values.push(("member_since", MetadataValue::Datetime(member_since)));
+        }
+        values
+    }
+}
+
+impl YouTubeMembership {
+    /// Checks the YouTube channel the user connected to Discord against our channel's
+    /// members and stores the result as their role connection.
+    /// `youtube_access_token` must belong to the channel owner.
+    pub async fn link(
+        rest: &RestClient,
+        jar: &TokenJar,
+        application_id: &str,
+        youtube_access_token: &str,
+    ) -> Result<YouTubeMembership, LinkedRolesError> {
+        let connection = rest
+            .get_user_connections(jar)
+            .await?
+            .into_iter()
+            .find(|connection| connection.r#type.as_deref() == Some("youtube") && connection.revoked != Some(true))
+            .ok_or(LinkedRolesError::NoYouTubeConnection)?;
+
+        let member = youtube::fetch_membership(&reqwest::Client::new(), youtube_access_token, &connection.id).await?;
+        let membership = match member {
+            Some(member) => {
+                let duration = &member.snippet.membershipsDetails.membershipsDuration;
+                YouTubeMembership {
+                    channel_name: Some(member.snippet.memberDetails.displayName.clone()),
+                    is_member: true,
+                    member_since: DateTime::parse_from_rfc3339(&duration.memberSince).ok().map(|time| time.with_timezone(&Utc)),
+                    months: duration.memberTotalDurationMonths,
+                }
+            }
+            None => YouTubeMembership {
+                channel_name: connection.name.flatten(),
+                is_member: false,
+                member_since: None,
+                months: 0,
+            },
+        };
+
+        rest.update_role_connection(jar, application_id, membership.channel_name.as_deref(), &membership).await?;
+        Ok(membership)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Misdeclared;
+
+    impl RoleConnectionMetadata for Misdeclared {
+        const FIELDS: &'static [MetadataField] = &[MetadataField {
+            kind: MetadataType::IntegerEqual,
+            key: "level",
+            name: "Level",
+            description: "Level",
+        }];
+
+        fn values(&self) -> Vec<(&'static str, MetadataValue)> {
+            vec![("level", MetadataValue::Boolean(true))]
+        }
+    }
+
+    #[test]
+    fn youtube_membership_encodes_metadata() {
+        let schema = YouTubeMembership::schema().unwrap();
+        assert_eq!(schema.len(), 3);
+        assert_eq!(schema[0].r#type, MetadataType::BooleanEqual as i32);
+
+        let membership = YouTubeMembership {
+            channel_name: Some("@viewer".to_string()),
+            is_member: true,
+            member_since: Some(DateTime::parse_from_rfc3339("2024-05-01T12:00:00Z").unwrap().with_timezone(&Utc)),
+            months: 7,
+        };
+        let metadata = membership.metadata().unwrap();
+        assert_eq!(metadata["is_member"], "1");
+        assert_eq!(metadata["months"], "7");
+        assert_eq!(metadata["member_since"], "2024-05-01T12:00:00Z");
+    }
+
+    #[test]
+    fn values_must_match_declared_type() {
+        assert!(Misdeclared::schema().is_ok());
+        assert!(matches!(Misdeclared.metadata(), Err(LinkedRolesError::InvalidSchema(_))));
+    }
+}
@@ -0,0 +1,514 @@
+use std::{collections::HashMap, sync::{atomic::{AtomicUsize, Ordering}, Arc, Mutex}, time::SystemTime};
+
+use chrono::{DateTime, Utc};
+use futures::{Stream, StreamExt};
+use futures_combinators::combinators::{Event, ScheduleCommand, Scheduler};
+use openapi::models::{
+    BanUserFromGuildRequest, BulkBanUsersRequest, BulkBanUsersResponse, BulkDeleteMessagesRequest,
+    CreateAutoModerationRuleRequest, AutoModerationRuleResponse, MessageResponse,
+    UpdateGuildMemberRequest,
+};
+use reqwest::Method;
+use thiserror::Error;
+use tokio::{sync::{broadcast, mpsc}, time::Duration};
+use tokio_stream::wrappers::ReceiverStream;
+
+use crate::{events::{DispatchEvent, GuildAuditLogEntry}, rest::{Request, RestClient, RestError}, snowflake};
+
+
+/// Discord refuses timeouts longer than 28 days.
+pub const MAX_TIMEOUT: Duration = Duration::from_secs(28 * 24 * 60 * 60);
+
+/// Messages older than this cannot be bulk deleted.
+pub const BULK_DELETE_MAX_AGE: Duration = Duration::from_secs(14 * 24 * 60 * 60);
+
+const BULK_DELETE_MAX: usize = 100;
+const MESSAGE_PAGE_SIZE: usize = 100;
+
+// Audit log action types we mirror.
+const MEMBER_KICK: i32 = 20;
+const MEMBER_BAN_ADD: i32 = 22;
+const MEMBER_BAN_REMOVE: i32 = 23;
+const MEMBER_UPDATE: i32 = 24;
+const MESSAGE_DELETE: i32 = 72;
+const MESSAGE_BULK_DELETE: i32 = 73;
+const AUTO_MODERATION_BLOCK_MESSAGE: i32 = 143;
+const AUTO_MODERATION_USER_COMMUNICATION_DISABLED: i32 = 145;
+
+#[derive(Debug, Error)]
+pub enum ModerationError {
+    #[error("REST error: {0}")]
+    Rest(#[from] RestError),
+
+    #[error("Timeout of {0:?} exceeds the 28 day maximum")]
+    TimeoutTooLong(Duration),
+
+    #[error("Scheduler task is no longer running")]
+    SchedulerClosed,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ModerationKind {
+    Kick,
+    Ban,
+    Unban,
+    /// `until` is `None` when a timeout is lifted.
+    Timeout { until: Option<String> },
+    MessageDelete { count: usize },
+    AutoModerationBlock,
+}
+
+/// Where an action was observed: performed by this toolkit, or read back from the
+/// guild's audit log (e.g. a moderator using the Discord UI).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionSource {
+    Bot,
+    AuditLog,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModerationAction {
+    pub guild_id: String,
+    pub kind: ModerationKind,
+    pub moderator_id: Option<String>,
+    pub target_id: Option<String>,
+    pub reason: Option<String>,
+    pub source: ActionSource,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PurgeReport {
+    pub scanned: usize,
+    pub bulk_deleted: usize,
+    pub individually_deleted: usize,
+}
+
+#[derive(Debug, Clone)]
+enum ScheduledAction {
+    Unban { guild_id: String, user_id: String },
+}
+
+/// Moderation helpers on top of the REST client. Every action takes an optional
+/// reason that ends up in the guild's audit log, and is published to `subscribe`.
+pub struct Moderation {
+    rest: RestClient,
+    bot_user_id: String,
+    schedule_tx: mpsc::Sender<ScheduleCommand<ScheduledAction>>,
+    tempbans: Arc<Mutex<HashMap<(String, String), usize>>>,
+    next_job_id: AtomicUsize,
+    actions: broadcast::Sender<ModerationAction>,
+}
+
+impl Moderation {
+    /// Spawns the task that carries out scheduled unbans.
+    pub fn new(rest: RestClient, bot_user_id: impl Into<String>) -> Self {
+        let (schedule_tx, schedule_rx) = mpsc::channel(16);
+        let (actions, _) = broadcast::channel(64);
+        let tempbans = Arc::new(Mutex::new(HashMap::new()));
+
+        tokio::spawn(run_scheduled_actions(rest.clone(), schedule_rx, tempbans.clone()));
+
+        Moderation {
+            rest,
+            bot_user_id: bot_user_id.into(),
+            schedule_tx,
+            tempbans,
+            next_job_id: AtomicUsize::new(0),
+            actions,
+        }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<ModerationAction> {
+        self.actions.subscribe()
+    }
+
+    fn publish(&self, guild_id: &str, kind: ModerationKind, target_id: Option<&str>, reason: Option<&str>) {
+        let _ = self.actions.send(ModerationAction {
+            guild_id: guild_id.to_string(),
+            kind,
+            moderator_id: Some(self.bot_user_id.clone()),
+            target_id: target_id.map(str::to_string),
+            reason: reason.map(str::to_string),
+            source: ActionSource::Bot,
+        });
+    }
+
+    pub async fn timeout(&self, guild_id: &str, user_id: &str, duration: Duration, reason: Option<&str>) -> Result<(), ModerationError> {
+        if duration > MAX_TIMEOUT {
+            return Err(ModerationError::TimeoutTooLong(duration))
+        }
+        let until: DateTime<Utc> = (SystemTime::now() + duration).into();
+        let until = until.to_rfc3339();
+
+        self.update_timeout(guild_id, user_id, Some(until.clone()), reason).await?;
+        self.publish(guild_id, ModerationKind::Timeout { until: Some(until) }, Some(user_id), reason);
+        Ok(())
+    }
+
+    pub async fn remove_timeout(&self, guild_id: &str, user_id: &str, reason: Option<&str>) -> Result<(), ModerationError> {
+        self.update_timeout(guild_id, user_id, None, reason).await?;
+        self.publish(guild_id, ModerationKind::Timeout { until: None }, Some(user_id), reason);
+        Ok(())
+    }
+
+    async fn update_timeout(&self, guild_id: &str, user_id: &str, until: Option<String>, reason: Option<&str>) -> Result<(), ModerationError> {
+        let body = UpdateGuildMemberRequest {
+            communication_disabled_until: Some(until),
+            ..Default::default()
+        };
+        let request = Request::new(Method::PATCH, format!("/guilds/{guild_id}/members/{user_id}"))
+            .json(&body)?
+            .reason(reason);
+
+        let _: serde_json::Value = self.rest.send(request).await?;
+        Ok(())
+    }
+
+    pub async fn kick(&self, guild_id: &str, user_id: &str, reason: Option<&str>) -> Result<(), ModerationError> {
+        let request = Request::new(Method::DELETE, format!("/guilds/{guild_id}/members/{user_id}"))
+            .reason(reason);
+
+        self.rest.send::<()>(request).await?;
+        self.publish(guild_id, ModerationKind::Kick, Some(user_id), reason);
+        Ok(())
+    }
+
+    pub async fn ban(&self, guild_id: &str, user_id: &str, delete_message_seconds: Option<i32>, reason: Option<&str>) -> Result<(), ModerationError> {
+        let body = BanUserFromGuildRequest {
+            delete_message_seconds: Some(delete_message_seconds),
+            ..Default::default()
+        };
+        let request = Request::new(Method::PUT, format!("/guilds/{guild_id}/bans/{user_id}"))
+            .json(&body)?
+            .reason(reason);
+
+        self.rest.send::<()>(request).await?;
+        self.cancel_tempban(guild_id, user_id).await;
+        self.publish(guild_id, ModerationKind::Ban, Some(user_id), reason);
+        Ok(())
+    }
+
+    pub async fn bulk_ban(&self, guild_id: &str, user_ids: Vec<String>, delete_message_seconds: Option<i32>, reason: Option<&str>) -> Result<BulkBanUsersResponse, ModerationError> {
+        let body = BulkBanUsersRequest {
+            user_ids: user_ids.into_iter().map(Into::into).collect(),
+            delete_message_seconds: Some(delete_message_seconds),
+        };
+        let request = Request::new(Method::POST, format!("/guilds/{guild_id}/bulk-ban"))
+            .json(&body)?
+            .reason(reason);
+
+        let response: BulkBanUsersResponse = self.rest.send(request).await?;
+        for user_id in &response.banned_users {
+            self.cancel_tempban(guild_id, user_id).await;
+            self.publish(guild_id, ModerationKind::Ban, Some(user_id), reason);
+        }
+        Ok(response)
+    }
+
+    pub async fn unban(&self, guild_id: &str, user_id: &str, reason: Option<&str>) -> Result<(), ModerationError> {
+        unban(&self.rest, guild_id, user_id, reason).await?;
+        self.cancel_tempban(guild_id, user_id).await;
+        self.publish(guild_id, ModerationKind::Unban, Some(user_id), reason);
+        Ok(())
+    }
+
+    /// Bans `user_id` and schedules the matching unban after `duration`.
+    /// Banning the same user again replaces the pending unban.
+    pub async fn tempban(&self, guild_id: &str, user_id: &str, duration: Duration, reason: Option<&str>) -> Result<(), ModerationError> {
+        self.ban(guild_id, user_id, None, reason).await?;
+
+        let id = self.next_job_id.fetch_add(1, Ordering::Relaxed);
+        self.tempbans
+            .lock()
+            .expect("Tempban lock poisoned")
+            .insert((guild_id.to_string(), user_id.to_string()), id);
+
+        let action = ScheduledAction::Unban {
+            guild_id: guild_id.to_string(),
+            user_id: user_id.to_string(),
+        };
+        self.schedule_tx
+            .send(ScheduleCommand::Schedule(Event::event_in(action, duration, id)))
+            .await
+            .map_err(|_| ModerationError::SchedulerClosed)
+    }
+
+    async fn cancel_tempban(&self, guild_id: &str, user_id: &str) {
+        let id = self.tempbans
+            .lock()
+            .expect("Tempban lock poisoned")
+            .remove(&(guild_id.to_string(), user_id.to_string()));
+
+        if let Some(id) = id {
+            let _ = self.schedule_tx.send(ScheduleCommand::Cancel(id)).await;
+        }
+    }
+
+    /// Deletes messages matching `filter` among the newest `scan_limit` messages of a channel.
+    /// Messages younger than 14 days are bulk deleted, older ones one by one.
+    pub async fn purge<F>(&self, guild_id: &str, channel_id: &str, scan_limit: usize, filter: F, reason: Option<&str>) -> Result<PurgeReport, ModerationError>
+    where
+        F: Fn(&MessageResponse) -> bool,
+    {
+        let mut report = PurgeReport::default();
+        let mut matched = Vec::new();
+        let mut before: Option<String> = None;
+
+        while report.scanned < scan_limit {
+            let limit = MESSAGE_PAGE_SIZE.min(scan_limit - report.scanned);
+            let mut request = Request::new(Method::GET, format!("/channels/{channel_id}/messages"))
+                .query("limit", limit);
+            if let Some(before) = &before {
+                request = request.query("before", before);
+            }
+
+            let page: Vec<MessageResponse> = self.rest.send(request).await?;
+            report.scanned += page.len();
+            before = page.last().map(|message| message.id.to_string());
+
+            matched.extend(page.iter().filter(|m| filter(m)).map(|m| m.id.to_string()));
+
+            if page.len() < limit {
+                break
+            }
+        }
+
+        let (bulk, single) = partition_for_bulk_delete(matched, SystemTime::now());
+
+        for chunk in bulk.chunks(BULK_DELETE_MAX) {
+            if let [message_id] = chunk {
+                self.delete_message(channel_id, message_id, reason).await?;
+            } else {
+                let body = BulkDeleteMessagesRequest { messages: chunk.to_vec() };
+                let request = Request::new(Method::POST, format!("/channels/{channel_id}/messages/bulk-delete"))
+                    .json(&body)?
+                    .reason(reason);
+                self.rest.send::<()>(request).await?;
+            }
+            report.bulk_deleted += chunk.len();
+        }
+
+        for message_id in &single {
+            self.delete_message(channel_id, message_id, reason).await?;
+            report.individually_deleted += 1;
+        }
+
+        let count = report.bulk_deleted + report.individually_deleted;
+        if count > 0 {
+            self.publish(guild_id, ModerationKind::MessageDelete { count }, Some(channel_id), reason);
+        }
+        Ok(report)
+    }
+
+    async fn delete_message(&self, channel_id: &str, message_id: &str, reason: Option<&str>) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/channels/{channel_id}/messages/{message_id}"))
+            .reason(reason);
+        self.rest.send::<()>(request).await
+    }
+
+    pub async fn auto_moderation_rules(&self, guild_id: &str) -> Result<Vec<AutoModerationRuleResponse>, ModerationError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/auto-moderation/rules"));
+        Ok(self.rest.send(request).await?)
+    }
+
+    pub async fn create_auto_moderation_rule(&self, guild_id: &str, rule: CreateAutoModerationRuleRequest, reason: Option<&str>) -> Result<AutoModerationRuleResponse, ModerationError> {
+        let request = Request::new(Method::POST, format!("/guilds/{guild_id}/auto-moderation/rules"))
+            .json(&rule)?
+            .reason(reason);
+        Ok(self.rest.send(request).await?)
+    }
+
+    pub async fn delete_auto_moderation_rule(&self, guild_id: &str, rule_id: &str, reason: Option<&str>) -> Result<(), ModerationError> {
+        let request = Request::new(Method::DELETE, format!("/guilds/{guild_id}/auto-moderation/rules/{rule_id}"))
+            .reason(reason);
+        Ok(self.rest.send(request).await?)
+    }
+
+    /// Publishes audit log entries for actions taken outside this toolkit.
+    /// Entries made by the bot itself were already published when performed.
+    pub async fn mirror(&self, entry: &GuildAuditLogEntry) -> Option<ModerationAction> {
+        if entry.entry.user_id.as_deref() == Some(self.bot_user_id.as_str()) {
+            return None
+        }
+
+        let action = action_from_audit_log(entry)?;
+        // A ban or unban from elsewhere overrides a pending tempban.
+        if let (ModerationKind::Ban | ModerationKind::Unban, Some(target)) = (&action.kind, &action.target_id) {
+            self.cancel_tempban(&action.guild_id, target).await;
+        }
+
+        let _ = self.actions.send(action.clone());
+        Some(action)
+    }
+
+    /// Mirrors every GUILD_AUDIT_LOG_ENTRY_CREATE in `events` until the stream ends.
+    pub async fn follow<S>(&self, events: S)
+    where
+        S: Stream<Item = DispatchEvent>,
+    {
+        tokio::pin!(events);
+        while let Some(event) = events.next().await {
+            if let DispatchEvent::GuildAuditLogEntryCreate(entry) = event {
+                self.mirror(&entry).await;
+            }
+        }
+    }
+}
+
+async fn unban(rest: &RestClient, guild_id: &str, user_id: &str, reason: Option<&str>) -> Result<(), RestError> {
+    let request = Request::new(Method::DELETE, format!("/guilds/{guild_id}/bans/{user_id}"))
+        .reason(reason);
+    rest.send::<()>(request).await
+}
+
+async fn run_scheduled_actions(
+    rest: RestClient,
+    commands: mpsc::Receiver<ScheduleCommand<ScheduledAction>>,
+    tempbans: Arc<Mutex<HashMap<(String, String), usize>>>,
+) {
+    let scheduler = Scheduler::new(ReceiverStream::new(commands));
+    tokio::pin!(scheduler);
+
+    while let Some(event) = scheduler.next().await {
+        match event.value.value {
+            ScheduledAction::Unban { guild_id, user_id } => {
+                let key = (guild_id.clone(), user_id.clone());
+                if !claim_tempban(&mut tempbans.lock().expect("Tempban lock poisoned"), &key, event.id) {
+                    continue
+                }
+                if let Err(e) = unban(&rest, &guild_id, &user_id, Some("Temporary ban expired")).await {
+                    eprintln!("Failed to lift temporary ban of {} in {}: {}", user_id, guild_id, e);
+                }
+            }
+        }
+    }
+}
+
+/// Removes the tempban entry if it is still the one scheduled as `id`. An unban
+/// firing after the ban was replaced or made permanent is stale.
+fn claim_tempban(tempbans: &mut HashMap<(String, String), usize>, key: &(String, String), id: usize) -> bool {
+    if tempbans.get(key) != Some(&id) {
+        return false
+    }
+    tempbans.remove(key);
+    true
+}
+
+/// Splits message ids into those that can still be bulk deleted and those that are too old.
+fn partition_for_bulk_delete(message_ids: Vec<String>, now: SystemTime) -> (Vec<String>, Vec<String>) {
+    // Leave a minute of slack so a message does not age out between listing and deleting.
+    let cutoff = now - BULK_DELETE_MAX_AGE + Duration::from_secs(60);
+
+    message_ids
+        .into_iter()
+        .partition(|id| snowflake::timestamp(id).is_some_and(|created| created > cutoff))
+}
+
+fn action_from_audit_log(entry: &GuildAuditLogEntry) -> Option<ModerationAction> {
+    let log = &entry.entry;
+
+    let kind = match log.action_type? {
+        MEMBER_KICK => ModerationKind::Kick,
+        MEMBER_BAN_ADD => ModerationKind::Ban,
+        MEMBER_BAN_REMOVE => ModerationKind::Unban,
+        MEMBER_UPDATE => {
+            let change = log.changes
+                .iter()
+                .flatten()
+                .find(|change| change.key.as_ref().and_then(Option::as_deref) == Some("communication_disabled_until"))?;
+            let until = change.new_value
+                .clone()
+                .flatten()
+                .and_then(|value| value.as_str().map(str::to_string));
+            ModerationKind::Timeout { until }
+        }
+        MESSAGE_DELETE | MESSAGE_BULK_DELETE => {
+            let count = log.options
+                .as_ref()
+                .and_then(|options| options.get("count"))
+                .and_then(|count| count.parse().ok())
+                .unwrap_or(1);
+            ModerationKind::MessageDelete { count }
+        }
+        AUTO_MODERATION_BLOCK_MESSAGE => ModerationKind::AutoModerationBlock,
+        AUTO_MODERATION_USER_COMMUNICATION_DISABLED => ModerationKind::Timeout { until: None },
+        _ => return None,
+    };
+
+    Some(ModerationAction {
+        guild_id: entry.guild_id.clone(),
+        kind,
+        moderator_id: log.user_id.clone().map(Into::into),
+        target_id: log.target_id.clone().map(Into::into),
+        reason: log.reason.clone(),
+        source: ActionSource::AuditLog,
+    })
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, time::{Duration, SystemTime}};
+
+    use crate::{events::{DispatchEvent, GuildAuditLogEntry}, snowflake};
+    use oozebot_protocol::events::receive::Dispatch;
+    use serde_json::json;
+
+    use super::{action_from_audit_log, claim_tempban, partition_for_bulk_delete, ActionSource, ModerationKind};
+
+    #[test]
+    fn purge_splits_on_bulk_delete_age() {
+        let now = SystemTime::now();
+        let fresh = snowflake::from_timestamp(now - Duration::from_secs(60 * 60));
+        let stale = snowflake::from_timestamp(now - Duration::from_secs(15 * 24 * 60 * 60));
+
+        let (bulk, single) = partition_for_bulk_delete(vec![fresh.clone(), stale.clone()], now);
+
+        assert_eq!(bulk, vec![fresh]);
+        assert_eq!(single, vec![stale]);
+    }
+
+    #[test]
+    fn stale_unban_keeps_newer_tempban() {
+        let key = ("guild".to_string(), "user".to_string());
+        let mut tempbans = HashMap::from([(key.clone(), 2)]);
+
+        assert!(!claim_tempban(&mut tempbans, &key, 1));
+        assert_eq!(tempbans.get(&key), Some(&2));
+        assert!(claim_tempban(&mut tempbans, &key, 2));
+        assert!(tempbans.is_empty());
+    }
+
+    #[test]
+    fn audit_log_entries_are_mirrored() {
+        let dispatch = Dispatch {
+            op: 0,
+            s: Some(3),
+            t: Some("GUILD_AUDIT_LOG_ENTRY_CREATE".to_string()),
+            d: json!({
+                "guild_id": "1",
+                "id": "99",
+                "action_type": 24,
+                "user_id": "mod",
+                "target_id": "troll",
+                "reason": "spam",
+                "changes": [{
+                    "key": "communication_disabled_until",
+                    "new_value": "2030-01-01T00:00:00+00:00"
+                }]
+            }),
+        };
+
+        let entry: GuildAuditLogEntry = match DispatchEvent::try_from(dispatch).unwrap() {
+            DispatchEvent::GuildAuditLogEntryCreate(entry) => entry,
+            other => panic!("Unexpected event {:?}", other),
+        };
+        let action = action_from_audit_log(&entry).expect("timeout should be mirrored");
+
+        assert_eq!(action.kind, ModerationKind::Timeout { until: Some("2030-01-01T00:00:00+00:00".to_string()) });
+        assert_eq!(action.moderator_id.as_deref(), Some("mod"));
+        assert_eq!(action.target_id.as_deref(), Some("troll"));
+        assert_eq!(action.reason.as_deref(), Some("spam"));
+        assert_eq!(action.source, ActionSource::AuditLog);
+    }
+}
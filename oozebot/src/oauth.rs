@@ -0,0 +1,344 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::Path,
+    time::{Duration, SystemTime},
+};
+
+use oauth2::{
+    basic::{BasicClient, BasicTokenType},
+    AuthUrl, AuthorizationCode, ClientId, ClientSecret, CsrfToken, EmptyExtraTokenFields, EndpointNotSet, EndpointSet,
+    RedirectUrl, RevocationUrl, Scope, StandardRevocableToken, StandardTokenResponse, TokenResponse, TokenUrl,
+};
+use openapi::apis::configuration::Configuration;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::TcpListener,
+};
+use url::Url;
+
+
+const AUTHORIZE_URL: &str = "https://discord.com/oauth2/authorize";
+const TOKEN_URL: &str = "https://discord.com/api/v10/oauth2/token";
+const REVOKE_URL: &str = "https://discord.com/api/v10/oauth2/token/revoke";
+pub const DEFAULT_REDIRECT_URL: &str = "http://localhost:3000/oauth2callback";
+
+pub type DiscordToken = StandardTokenResponse<EmptyExtraTokenFields, BasicTokenType>;
+type Client = BasicClient<EndpointSet, EndpointNotSet, EndpointNotSet, EndpointSet, EndpointSet>;
+
+#[derive(Debug, Error)]
+pub enum OAuthError {
+    #[error("Environment variable {0} is not set")]
+    MissingEnv(&'static str),
+
+    #[error("Invalid url: {0}")]
+    Url(#[from] url::ParseError),
+
+    #[error("Token request failed: {0}")]
+    Token(#[source] Box<dyn std::error::Error + Send + Sync>),
+
+    #[error("Redirect did not carry an authorization code: {0}")]
+    MissingCode(String),
+
+    #[error("Redirect state did not match, the request may have been forged")]
+    StateMismatch,
+
+    #[error("Token has no refresh token")]
+    NoRefreshToken,
+
+    #[error("Could not open the browser: {0}")]
+    Browser(std::io::Error),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Could not (de)serialize token: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+fn token_error(e: impl std::error::Error + Send + Sync + 'static) -> OAuthError {
+    OAuthError::Token(Box::new(e))
+}
+
+/// An access token together with the moment it stops being valid, as stored on disk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenJar {
+    pub token: DiscordToken,
+    pub expiration_time: SystemTime,
+}
+
+impl TokenJar {
+    fn new(token: DiscordToken) -> Self {
+        // Discord always sends expires_in; fall back to its usual week just in case.
+        let expires_in = token.expires_in().unwrap_or(Duration::from_secs(604800));
+        TokenJar {
+            expiration_time: SystemTime::now() + expires_in,
+            token,
+        }
+    }
+
+    pub fn access_token(&self) -> &str {
+        self.token.access_token().secret()
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expiration_time <= SystemTime::now()
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, OAuthError> {
+        let string = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&string)?)
+    }
+
+    /// Writes the tokens readable by the owner only, replacing the file at `path`
+    /// in one step so a crash never leaves it half written.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), OAuthError> {
+        let path = path.as_ref();
+        let temporary = path.with_extension("tmp");
+        let mut options = OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+        let mut file = options.open(&temporary)?;
+        file.write_all(serde_json::to_string(self)?.as_bytes())?;
+        file.sync_all()?;
+        fs::rename(&temporary, path)?;
+        Ok(())
+    }
+
+    /// Configuration for calling the generated `openapi` functions as the user.
+    pub fn configuration(&self) -> Configuration {
+        Configuration {
+            oauth_access_token: Some(self.access_token().to_string()),
+            ..Configuration::default()
+        }
+    }
+}
+
+/// Discord's OAuth2 authorization code and client credentials flows.
+#[derive(Debug, Clone)]
+pub struct DiscordOAuth {
+    client: Client,
+    http: reqwest::Client,
+    redirect_url: Url,
+    scopes: Vec<String>,
+}
+
+impl DiscordOAuth {
+    /// `scopes` are requested by `authorize_url` and `client_credentials`, e.g.
+    /// `identify` and `role_connections.write` for linked roles.
+    pub fn new(
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        redirect_url: &str,
+        scopes: &[&str],
+    ) -> Result<Self, OAuthError> {
+        let client = BasicClient::new(ClientId::new(client_id.into()))
+            .set_client_secret(ClientSecret::new(client_secret.into()))
+            .set_auth_uri(AuthUrl::new(AUTHORIZE_URL.to_string())?)
+            .set_token_uri(TokenUrl::new(TOKEN_URL.to_string())?)
+            .set_revocation_url(RevocationUrl::new(REVOKE_URL.to_string())?)
+            .set_redirect_uri(RedirectUrl::new(redirect_url.to_string())?);
+
+        let http = reqwest::ClientBuilder::new()
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .expect("Failed to build client");
+
+        Ok(DiscordOAuth {
+            client,
+            http,
+            redirect_url: Url::parse(redirect_url)?,
+            scopes: scopes.iter().map(|scope| scope.to_string()).collect(),
+        })
+    }
+
+    /// Reads `DISCORD_CLIENT_ID` and `DISCORD_CLIENT_SECRET` from the environment.
+    pub fn from_env(scopes: &[&str]) -> Result<Self, OAuthError> {
+        let client_id = std::env::var("DISCORD_CLIENT_ID").map_err(|_| OAuthError::MissingEnv("DISCORD_CLIENT_ID"))?;
+        let client_secret = std::env::var("DISCORD_CLIENT_SECRET").map_err(|_| OAuthError::MissingEnv("DISCORD_CLIENT_SECRET"))?;
+        Self::new(client_id, client_secret, DEFAULT_REDIRECT_URL, scopes)
+    }
+
+    /// The url to send the user to, and the state the redirect must echo back.
+    pub fn authorize_url(&self) -> (Url, CsrfToken) {
+        self.client
+            .authorize_url(CsrfToken::new_random)
+            .add_scopes(self.scopes.iter().cloned().map(Scope::new))
+            .add_extra_param("prompt", "consent")
+            .url()
+    }
+
+    /// Accepts a single request on the redirect url's port and returns the code it carries.
+    pub async fn listen_for_code(&self, state: &CsrfToken) -> Result<AuthorizationCode, OAuthError> {
+        let port = self.redirect_url.port_or_known_default().unwrap_or(80);
+        let listener = TcpListener::bind(("127.0.0.1", port)).await?;
+        let (mut stream, _) = listener.accept().await?;
+
+        let mut request_line = String::new();
+        BufReader::new(&mut stream).read_line(&mut request_line).await?;
+        let result = parse_redirect(&request_line, state);
+
+        let message = match &result {
+            Ok(_) => "Go back to your terminal :)",
+            Err(_) => "Authorization failed, check your terminal.",
+        };
+        let response = format!("HTTP/1.1 200 OK\r\ncontent-length: {}\r\n\r\n{}", message.len(), message);
+        stream.write_all(response.as_bytes()).await?;
+
+        result
+    }
+
+    pub async fn exchange_code(&self, code: AuthorizationCode) -> Result<TokenJar, OAuthError> {
+        let token = self.client
+            .exchange_code(code)
+            .request_async(&self.http)
+            .await
+            .map_err(token_error)?;
+        Ok(TokenJar::new(token))
+    }
+
+    pub async fn refresh(&self, jar: &TokenJar) -> Result<TokenJar, OAuthError> {
+        let refresh_token = jar.token.refresh_token().ok_or(OAuthError::NoRefreshToken)?;
+        let token = self.client
+            .exchange_refresh_token(refresh_token)
+            .request_async(&self.http)
+            .await
+            .map_err(token_error)?;
+        Ok(TokenJar::new(token))
+    }
+
+    /// A token for the application's own owner, without a user in the loop.
+    pub async fn client_credentials(&self) -> Result<TokenJar, OAuthError> {
+        let token = self.client
+            .exchange_client_credentials()
+            .add_scopes(self.scopes.iter().cloned().map(Scope::new))
+            .request_async(&self.http)
+            .await
+            .map_err(token_error)?;
+        Ok(TokenJar::new(token))
+    }
+
+    /// Revokes the refresh token if there is one, which also invalidates the access token.
+    pub async fn revoke(&self, jar: TokenJar) -> Result<(), OAuthError> {
+        let token: StandardRevocableToken = match jar.token.refresh_token() {
+            Some(refresh_token) => refresh_token.into(),
+            None => jar.token.access_token().into(),
+        };
+        self.client
+            .revoke_token(token)
+            .map_err(token_error)?
+            .request_async(&self.http)
+            .await
+            .map_err(token_error)
+    }
+
+    /// Returns the token stored at `path`, refreshing it when expired and falling
+    /// back to the browser flow when there is none or refreshing fails.
+    pub async fn authorize(&self, path: impl AsRef<Path>) -> Result<TokenJar, OAuthError> {
+        let path = path.as_ref();
+
+        let jar = match TokenJar::load(path) {
+            Ok(jar) if !jar.is_expired() => return Ok(jar),
+            Ok(jar) => match self.refresh(&jar).await {
+                Ok(jar) => jar,
+                Err(e) => {
+                    eprintln!("Token refresh failed, requesting new token. {}", e);
+                    self.authorize_in_browser().await?
+                }
+            },
+            Err(_) => self.authorize_in_browser().await?,
+        };
+
+        jar.save(path)?;
+        Ok(jar)
+    }
+
+    async fn authorize_in_browser(&self) -> Result<TokenJar, OAuthError> {
+        let (url, state) = self.authorize_url();
+        println!("Opening browser for auth...");
+        open::that(url.as_str()).map_err(OAuthError::Browser)?;
+
+        let code = self.listen_for_code(&state).await?;
+        self.exchange_code(code).await
+    }
+}
+
+/// Pulls the code out of a redirect's request line, e.g.
+/// `GET /oauth2callback?code=abc&state=xyz HTTP/1.1`.
+fn parse_redirect(request_line: &str, state: &CsrfToken) -> Result<AuthorizationCode, OAuthError> {
+    let target = request_line
+        .split_whitespace()
+        .nth(1)
+        .ok_or_else(|| OAuthError::MissingCode(request_line.to_string()))?;
+    let url = Url::parse(&format!("http://localhost{target}"))?;
+    let param = |name: &str| url.query_pairs().find(|(key, _)| key == name).map(|(_, value)| value.into_owned());
+
+    if param("state").as_deref() != Some(state.secret().as_str()) {
+        return Err(OAuthError::StateMismatch)
+    }
+    param("code")
+        .map(AuthorizationCode::new)
+        .ok_or_else(|| OAuthError::MissingCode(param("error_description").unwrap_or_else(|| target.to_string())))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn authorize_url_carries_scopes_and_state() {
+        let oauth = DiscordOAuth::new("123", "secret", DEFAULT_REDIRECT_URL, &["identify", "role_connections.write"]).unwrap();
+        let (url, state) = oauth.authorize_url();
+
+        let pairs: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+        assert!(pairs.contains(&("scope".to_string(), "identify role_connections.write".to_string())));
+        assert!(pairs.contains(&("state".to_string(), state.secret().clone())));
+        assert!(pairs.contains(&("redirect_uri".to_string(), DEFAULT_REDIRECT_URL.to_string())));
+    }
+
+    #[test]
+    fn redirect_is_checked_against_state() {
+        let state = CsrfToken::new("xyz".to_string());
+
+        let code = parse_redirect("GET /oauth2callback?code=abc&state=xyz HTTP/1.1\r\n", &state).unwrap();
+        assert_eq!(code.secret(), "abc");
+
+        let forged = parse_redirect("GET /oauth2callback?code=abc&state=evil HTTP/1.1\r\n", &state);
+        assert!(matches!(forged, Err(OAuthError::StateMismatch)));
+
+        let denied = parse_redirect("GET /oauth2callback?error=access_denied&error_description=nope&state=xyz HTTP/1.1", &state);
+        assert!(matches!(denied, Err(OAuthError::MissingCode(reason)) if reason == "nope"));
+    }
+
+    #[test]
+    fn token_jar_round_trip() {
+        let token: DiscordToken = serde_json::from_value(serde_json::json!({
+            "access_token": "access",
+            "token_type": "Bearer",
+            "expires_in": 604800,
+            "refresh_token": "refresh",
+            "scope": "identify",
+        }))
+        .unwrap();
+        let jar = TokenJar::new(token);
+        assert!(!jar.is_expired());
+
+        let path = std::env::temp_dir().join(format!("oozebot-token-{}.json", std::process::id()));
+        jar.save(&path).unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(std::fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+        }
+        let loaded = TokenJar::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.access_token(), "access");
+        assert_eq!(loaded.configuration().oauth_access_token.as_deref(), Some("access"));
+    }
+}
@@ -0,0 +1,283 @@
+use bitflags::bitflags;
+use chrono::{DateTime, Utc};
+use openapi::models::{ChannelPermissionOverwriteResponse, GuildMemberResponse, GuildRoleResponse};
+use thiserror::Error;
+
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Permissions: u64 {
+        const CREATE_INSTANT_INVITE               = 1 << 0;
+        const KICK_MEMBERS                        = 1 << 1;
+        const BAN_MEMBERS                         = 1 << 2;
+        const ADMINISTRATOR                       = 1 << 3;
+        const MANAGE_CHANNELS                     = 1 << 4;
+        const MANAGE_GUILD                        = 1 << 5;
+        const ADD_REACTIONS                       = 1 << 6;
+        const VIEW_AUDIT_LOG                      = 1 << 7;
+        const PRIORITY_SPEAKER                    = 1 << 8;
+        const STREAM                              = 1 << 9;
+        const VIEW_CHANNEL                        = 1 << 10;
+        const SEND_MESSAGES                       = 1 << 11;
+        const SEND_TTS_MESSAGES                   = 1 << 12;
+        const MANAGE_MESSAGES                     = 1 << 13;
+        const EMBED_LINKS                         = 1 << 14;
+        const ATTACH_FILES                        = 1 << 15;
+        const READ_MESSAGE_HISTORY                = 1 << 16;
+        const MENTION_EVERYONE                    = 1 << 17;
+        const USE_EXTERNAL_EMOJIS                 = 1 << 18;
+        const VIEW_GUILD_INSIGHTS                 = 1 << 19;
+        const CONNECT                             = 1 << 20;
+        const SPEAK                               = 1 << 21;
+        const MUTE_MEMBERS                        = 1 << 22;
+        const DEAFEN_MEMBERS                      = 1 << 23;
+        const MOVE_MEMBERS                        = 1 << 24;
+        const USE_VAD                             = 1 << 25;
+        const CHANGE_NICKNAME                     = 1 << 26;
+        const MANAGE_NICKNAMES                    = 1 << 27;
+        const MANAGE_ROLES                        = 1 << 28;
+        const MANAGE_WEBHOOKS                     = 1 << 29;
+        const MANAGE_GUILD_EXPRESSIONS            = 1 << 30;
+        const USE_APPLICATION_COMMANDS            = 1 << 31;
+        const REQUEST_TO_SPEAK                    = 1 << 32;
+        const MANAGE_EVENTS                       = 1 << 33;
+        const MANAGE_THREADS                      = 1 << 34;
+        const CREATE_PUBLIC_THREADS               = 1 << 35;
+        const CREATE_PRIVATE_THREADS              = 1 << 36;
+        const USE_EXTERNAL_STICKERS               = 1 << 37;
+        const SEND_MESSAGES_IN_THREADS            = 1 << 38;
+        const USE_EMBEDDED_ACTIVITIES             = 1 << 39;
+        const MODERATE_MEMBERS                    = 1 << 40;
+        const VIEW_CREATOR_MONETIZATION_ANALYTICS = 1 << 41;
+        const USE_SOUNDBOARD                      = 1 << 42;
+        const CREATE_GUILD_EXPRESSIONS            = 1 << 43;
+        const CREATE_EVENTS                       = 1 << 44;
+        const USE_EXTERNAL_SOUNDS                 = 1 << 45;
+        const SEND_VOICE_MESSAGES                 = 1 << 46;
+        const SET_VOICE_CHANNEL_STATUS            = 1 << 48;
+        const SEND_POLLS                          = 1 << 49;
+        const USE_EXTERNAL_APPS                   = 1 << 50;
+        const PIN_MESSAGES                        = 1 << 51;
+        const BYPASS_SLOWMODE                     = 1 << 52;
+    }
+}
+
+/// Overwrite targets as sent in the `type` field of a permission overwrite.
+const OVERWRITE_TYPE_ROLE: i32 = 0;
+const OVERWRITE_TYPE_MEMBER: i32 = 1;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("Missing permissions: {}", .0.bits())]
+pub struct MissingPermissions(pub Permissions);
+
+impl Permissions {
+    /// Parses the decimal string Discord uses to serialize permission sets.
+    /// Unknown bits are dropped.
+    pub fn parse(value: &str) -> Self {
+        value
+            .parse::<u64>()
+            .map(Permissions::from_bits_truncate)
+            .unwrap_or_else(|_| Permissions::empty())
+    }
+
+    /// Returns `Err` with the subset of `required` that is not present.
+    pub fn require(self, required: Permissions) -> Result<(), MissingPermissions> {
+        let missing = required - self;
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(MissingPermissions(missing))
+        }
+    }
+}
+
+/// Guild level permissions of a member: the @everyone role plus every role the
+/// member has. Owners and administrators get everything.
+pub fn base_permissions(
+    guild_id: &str,
+    owner_id: &str,
+    member: &GuildMemberResponse,
+    roles: &[&GuildRoleResponse],
+) -> Permissions {
+    if member.user.id == owner_id {
+        return Permissions::all();
+    }
+
+    let mut permissions = roles
+        .iter()
+        .find(|role| role.id == guild_id)
+        .map(|everyone| Permissions::parse(&everyone.permissions))
+        .unwrap_or_default();
+
+    for role in roles.iter().filter(|role| member.roles.contains(&role.id)) {
+        permissions |= Permissions::parse(&role.permissions);
+    }
+
+    if permissions.contains(Permissions::ADMINISTRATOR) {
+        return Permissions::all();
+    }
+
+    permissions
+}
+
+/// Whether the member is timed out at `now`.
+pub fn is_timed_out(member: &GuildMemberResponse, now: DateTime<Utc>) -> bool {
+    member.communication_disabled_until
+        .as_ref()
+        .and_then(|until| until.as_deref())
+        .and_then(|until| DateTime::parse_from_rfc3339(until).ok())
+        .is_some_and(|until| until > now)
+}
+
+/// Applies channel overwrites on top of `base` in Discord's order:
+/// @everyone, then the member's roles combined, then the member itself.
+/// A timed out member keeps at most `VIEW_CHANNEL` and `READ_MESSAGE_HISTORY`.
+pub fn overwrite_permissions(
+    base: Permissions,
+    guild_id: &str,
+    member: &GuildMemberResponse,
+    overwrites: &[ChannelPermissionOverwriteResponse],
+) -> Permissions {
+    if base.contains(Permissions::ADMINISTRATOR) {
+        return Permissions::all();
+    }
+
+    let mut permissions = base;
+
+    if let Some(everyone) = overwrites.iter().find(|o| o.id == guild_id) {
+        permissions.remove(Permissions::parse(&everyone.deny));
+        permissions.insert(Permissions::parse(&everyone.allow));
+    }
+
+    let mut allow = Permissions::empty();
+    let mut deny = Permissions::empty();
+    for overwrite in overwrites.iter().filter(|o| {
+        o.r#type == Some(OVERWRITE_TYPE_ROLE) && o.id != guild_id && member.roles.contains(&o.id)
+    }) {
+        allow |= Permissions::parse(&overwrite.allow);
+        deny |= Permissions::parse(&overwrite.deny);
+    }
+    permissions.remove(deny);
+    permissions.insert(allow);
+
+    if let Some(own) = overwrites
+        .iter()
+        .find(|o| o.r#type == Some(OVERWRITE_TYPE_MEMBER) && o.id == member.user.id)
+    {
+        permissions.remove(Permissions::parse(&own.deny));
+        permissions.insert(Permissions::parse(&own.allow));
+    }
+
+    // Without VIEW_CHANNEL nothing else in the channel is usable.
+    if !permissions.contains(Permissions::VIEW_CHANNEL) {
+        return Permissions::empty();
+    }
+
+    if is_timed_out(member, Utc::now()) {
+        permissions &= Permissions::VIEW_CHANNEL | Permissions::READ_MESSAGE_HISTORY;
+    }
+
+    permissions
+}
+
+
+#[cfg(test)]
+mod tests {
+    use openapi::models::{ChannelPermissionOverwriteResponse, GuildMemberResponse, GuildRoleResponse, UserResponse};
+
+    use super::*;
+
+    const GUILD: &str = "100";
+
+    fn role(id: &str, permissions: Permissions) -> GuildRoleResponse {
+        GuildRoleResponse {
+            id: id.into(),
+            permissions: permissions.bits().to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn member(id: &str, roles: &[&str]) -> GuildMemberResponse {
+        GuildMemberResponse {
+            user: Box::new(UserResponse { id: id.into(), ..Default::default() }),
+            roles: roles.iter().map(|&r| r.into()).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn overwrite(id: &str, kind: i32, allow: Permissions, deny: Permissions) -> ChannelPermissionOverwriteResponse {
+        ChannelPermissionOverwriteResponse::new(id.into(), Some(kind), allow.bits().to_string(), deny.bits().to_string())
+    }
+
+    #[test]
+    fn base_permissions_combine_everyone_and_member_roles() {
+        let everyone = role(GUILD, Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES);
+        let moderator = role("1", Permissions::BAN_MEMBERS);
+        let other = role("2", Permissions::MANAGE_GUILD);
+
+        let perms = base_permissions(GUILD, "owner", &member("7", &["1"]), &[&everyone, &moderator, &other]);
+
+        assert_eq!(perms, Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES | Permissions::BAN_MEMBERS);
+        assert!(perms.require(Permissions::BAN_MEMBERS).is_ok());
+        assert_eq!(perms.require(Permissions::MANAGE_GUILD), Err(MissingPermissions(Permissions::MANAGE_GUILD)));
+    }
+
+    #[test]
+    fn owner_and_administrator_bypass_everything() {
+        let everyone = role(GUILD, Permissions::empty());
+        let admin = role("1", Permissions::ADMINISTRATOR);
+
+        assert_eq!(base_permissions(GUILD, "7", &member("7", &[]), &[&everyone]), Permissions::all());
+        let base = base_permissions(GUILD, "owner", &member("8", &["1"]), &[&everyone, &admin]);
+        assert_eq!(base, Permissions::all());
+
+        let deny_all = [overwrite(GUILD, OVERWRITE_TYPE_ROLE, Permissions::empty(), Permissions::all())];
+        assert_eq!(overwrite_permissions(base, GUILD, &member("8", &["1"]), &deny_all), Permissions::all());
+    }
+
+    #[test]
+    fn overwrites_apply_everyone_then_roles_then_member() {
+        let base = Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES;
+        let muted = member("7", &["1", "2"]);
+
+        let overwrites = [
+            overwrite(GUILD, OVERWRITE_TYPE_ROLE, Permissions::empty(), Permissions::SEND_MESSAGES),
+            overwrite("1", OVERWRITE_TYPE_ROLE, Permissions::SEND_MESSAGES, Permissions::empty()),
+            overwrite("2", OVERWRITE_TYPE_ROLE, Permissions::ATTACH_FILES, Permissions::SEND_MESSAGES),
+        ];
+        // Role allows win over role denies when they come from the same stage.
+        let perms = overwrite_permissions(base, GUILD, &muted, &overwrites);
+        assert_eq!(perms, Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES | Permissions::ATTACH_FILES);
+
+        let mut with_member = overwrites.to_vec();
+        with_member.push(overwrite("7", OVERWRITE_TYPE_MEMBER, Permissions::empty(), Permissions::SEND_MESSAGES));
+        let perms = overwrite_permissions(base, GUILD, &muted, &with_member);
+        assert_eq!(perms, Permissions::VIEW_CHANNEL | Permissions::ATTACH_FILES);
+
+        let hidden = [overwrite(GUILD, OVERWRITE_TYPE_ROLE, Permissions::empty(), Permissions::VIEW_CHANNEL)];
+        assert_eq!(overwrite_permissions(base, GUILD, &member("9", &[]), &hidden), Permissions::empty());
+    }
+
+    #[test]
+    fn timed_out_members_can_only_read() {
+        let base = Permissions::VIEW_CHANNEL | Permissions::READ_MESSAGE_HISTORY | Permissions::SEND_MESSAGES;
+        let mut timed_out = member("7", &[]);
+        timed_out.communication_disabled_until = Some(Some("2999-01-01T00:00:00+00:00".to_string()));
+        assert_eq!(
+            overwrite_permissions(base, GUILD, &timed_out, &[]),
+            Permissions::VIEW_CHANNEL | Permissions::READ_MESSAGE_HISTORY
+        );
+
+        timed_out.communication_disabled_until = Some(Some("2000-01-01T00:00:00+00:00".to_string()));
+        assert_eq!(overwrite_permissions(base, GUILD, &timed_out, &[]), base);
+    }
+
+    #[test]
+    fn parse_keeps_every_documented_bit() {
+        let value = ((1u64 << 48) | (1 << 49) | (1 << 52)).to_string();
+        assert_eq!(
+            Permissions::parse(&value),
+            Permissions::SET_VOICE_CHANNEL_STATUS | Permissions::SEND_POLLS | Permissions::BYPASS_SLOWMODE
+        );
+    }
+}
@@ -0,0 +1,550 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use openapi::models::{
+    MessageCreateRequest, MessageResponse, PollAnswerCreateRequest, PollAnswerDetailsResponse, PollCreateRequest,
+    PollEmojiCreateRequest, PollMedia, PollMediaCreateRequest, PollResponse, UserResponse,
+};
+use reqwest::Method;
+use thiserror::Error;
+use tokio::time::Duration;
+
+use crate::{
+    events::{DispatchEvent, PollVote},
+    rest::{errors::JsonErrorCode, Request, RestClient, RestError},
+    youtube::{
+        self,
+        livechatmessages::{LiveChatMessage, PollMetadata},
+    },
+};
+
+
+pub const MAX_QUESTION: usize = 300;
+pub const MAX_ANSWER: usize = 55;
+pub const MAX_ANSWERS: usize = 10;
+/// Polls run for at most 32 days.
+pub const MAX_DURATION_HOURS: u32 = 768;
+pub const LAYOUT_DEFAULT: i32 = 1;
+
+/// How long to wait before checking again whether Discord has finalized an expired poll.
+const FINALIZE_RETRY: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Error)]
+pub enum PollError {
+    #[error(transparent)]
+    Rest(#[from] RestError),
+
+    #[error("YouTube request failed: {0}")]
+    YouTube(#[from] reqwest::Error),
+
+    #[error("A poll needs at least one answer")]
+    NoAnswers,
+
+    #[error("{0} answers, at most {MAX_ANSWERS} allowed")]
+    TooManyAnswers(usize),
+
+    #[error("Question is {0} characters long, the limit is {MAX_QUESTION}")]
+    QuestionTooLong(usize),
+
+    #[error("Answer {index} is {length} characters long, the limit is {MAX_ANSWER}")]
+    AnswerTooLong { index: usize, length: usize },
+
+    #[error("Poll duration must be 1 to {MAX_DURATION_HOURS} hours, not {0}")]
+    InvalidDuration(u32),
+
+    #[error("Message {0} has no poll")]
+    NotAPoll(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PollBuilder {
+    question: String,
+    answers: Vec<PollMediaCreateRequest>,
+    duration_hours: Option<u32>,
+    allow_multiselect: bool,
+}
+
+impl PollBuilder {
+    pub fn new(question: impl Into<String>) -> Self {
+        PollBuilder {
+            question: question.into(),
+            answers: Vec::new(),
+            duration_hours: None,
+            allow_multiselect: false,
+        }
+    }
+
+    pub fn answer(mut self, text: impl Into<String>) -> Self {
+        self.answers.push(PollMediaCreateRequest {
+            text: Some(Some(text.into())),
+            emoji: None,
+        });
+        self
+    }
+
+    /// An answer shown with a unicode emoji, or a custom one if `emoji` is an emoji id.
+    pub fn answer_with_emoji(mut self, text: impl Into<String>, emoji: &str) -> Self {
+        let emoji = if emoji.bytes().all(|b| b.is_ascii_digit()) {
+            PollEmojiCreateRequest { id: Some(emoji.into()), ..Default::default() }
+        } else {
+            PollEmojiCreateRequest { name: Some(Some(emoji.to_string())), ..Default::default() }
+        };
+        self.answers.push(PollMediaCreateRequest {
+            text: Some(Some(text.into())),
+            emoji: Some(Some(Box::new(emoji))),
+        });
+        self
+    }
+
+    /// Defaults to 24 hours.
+    pub fn duration_hours(mut self, hours: u32) -> Self {
+        self.duration_hours = Some(hours);
+        self
+    }
+
+    pub fn allow_multiselect(mut self, allow: bool) -> Self {
+        self.allow_multiselect = allow;
+        self
+    }
+
+    /// Mirrors a YouTube chat poll, answers in the same order.
+    pub fn from_youtube(poll: &PollMetadata) -> Self {
+        poll.options
+            .iter()
+            .fold(PollBuilder::new(&poll.questionText), |builder, option| builder.answer(&option.optionText))
+    }
+
+    pub fn validate(&self) -> Result<(), PollError> {
+        let question = self.question.chars().count();
+        if question > MAX_QUESTION {
+            return Err(PollError::QuestionTooLong(question))
+        }
+        if self.answers.is_empty() {
+            return Err(PollError::NoAnswers)
+        }
+        if self.answers.len() > MAX_ANSWERS {
+            return Err(PollError::TooManyAnswers(self.answers.len()))
+        }
+        for (index, answer) in self.answers.iter().enumerate() {
+            let length = answer.text.clone().flatten().map_or(0, |text| text.chars().count());
+            if length > MAX_ANSWER {
+                return Err(PollError::AnswerTooLong { index, length })
+            }
+        }
+        if let Some(hours) = self.duration_hours
+            && !(1..=MAX_DURATION_HOURS).contains(&hours)
+        {
+            return Err(PollError::InvalidDuration(hours))
+        }
+        Ok(())
+    }
+
+    pub fn build(self) -> Result<PollCreateRequest, PollError> {
+        self.validate()?;
+        Ok(PollCreateRequest {
+            question: Box::new(PollMedia {
+                text: Some(Some(self.question)),
+                emoji: None,
+            }),
+            answers: self.answers.into_iter().map(PollAnswerCreateRequest::new).collect(),
+            allow_multiselect: Some(Some(self.allow_multiselect)),
+            layout_type: Some(LAYOUT_DEFAULT),
+            duration: self.duration_hours.map(|hours| Some(hours as i32)),
+        })
+    }
+
+    /// A message carrying only this poll.
+    pub fn into_message(self) -> Result<MessageCreateRequest, PollError> {
+        Ok(MessageCreateRequest {
+            poll: Some(Some(Box::new(self.build()?))),
+            ..Default::default()
+        })
+    }
+}
+
+impl RestClient {
+    pub async fn create_poll(&self, channel_id: &str, poll: PollBuilder) -> Result<MessageResponse, PollError> {
+        Ok(self.create_message(channel_id, poll.into_message()?, Vec::new()).await?)
+    }
+
+    /// Ends a poll the bot created before its expiry.
+    pub async fn end_poll(&self, channel_id: &str, message_id: &str) -> Result<MessageResponse, RestError> {
+        let request = Request::new(Method::POST, format!("/channels/{channel_id}/polls/{message_id}/expire"));
+        self.send(request).await
+    }
+
+    /// Users who voted for an answer, at most `limit` (up to 100) after user id `after`.
+    pub async fn get_answer_voters(
+        &self,
+        channel_id: &str,
+        message_id: &str,
+        answer_id: i32,
+        after: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<Vec<UserResponse>, RestError> {
+        let mut request = Request::new(Method::GET, format!("/channels/{channel_id}/polls/{message_id}/answers/{answer_id}"));
+        if let Some(after) = after {
+            request = request.query("after", after);
+        }
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        let response: PollAnswerDetailsResponse = self.send(request).await?;
+        Ok(response.users)
+    }
+}
+
+/// Waits until a poll has expired and Discord has finalized its results.
+pub async fn await_poll_end(rest: &RestClient, channel_id: &str, message_id: &str) -> Result<PollResponse, PollError> {
+    loop {
+        let message = rest.get_message(channel_id, message_id).await?;
+        let poll = message.poll.ok_or_else(|| PollError::NotAPoll(message_id.to_string()))?;
+        if poll.results.is_finalized {
+            return Ok(*poll)
+        }
+
+        let until_expiry = DateTime::parse_from_rfc3339(&poll.expiry)
+            .ok()
+            .and_then(|expiry| (expiry.with_timezone(&Utc) - Utc::now()).to_std().ok());
+        tokio::time::sleep(until_expiry.unwrap_or(FINALIZE_RETRY).max(FINALIZE_RETRY)).await;
+    }
+}
+
+/// Vote counts of one poll, seeded from its message and kept current from
+/// `MESSAGE_POLL_VOTE_ADD` and `MESSAGE_POLL_VOTE_REMOVE`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PollTally {
+    pub channel_id: String,
+    pub message_id: String,
+    pub question: String,
+    /// Answer ids and texts in display order.
+    answers: Vec<(i32, String)>,
+    counts: HashMap<i32, u32>,
+}
+
+impl PollTally {
+    pub fn from_message(message: &MessageResponse) -> Option<Self> {
+        let poll = message.poll.as_ref()?;
+        Some(PollTally {
+            channel_id: message.channel_id.to_string(),
+            message_id: message.id.to_string(),
+            question: poll.question.text.clone().unwrap_or_default(),
+            answers: poll.answers
+                .iter()
+                .map(|answer| (answer.answer_id, answer.poll_media.text.clone().unwrap_or_default()))
+                .collect(),
+            counts: poll.results.answer_counts
+                .iter()
+                .map(|entry| (entry.id, entry.count.max(0) as u32))
+                .collect(),
+        })
+    }
+
+    pub fn count(&self, answer_id: i32) -> u32 {
+        self.counts.get(&answer_id).copied().unwrap_or(0)
+    }
+
+    pub fn total(&self) -> u32 {
+        self.counts.values().sum()
+    }
+
+    /// Answer texts with their vote counts, in display order.
+    pub fn results(&self) -> Vec<(&str, u32)> {
+        self.answers
+            .iter()
+            .map(|(id, text)| (text.as_str(), self.count(*id)))
+            .collect()
+    }
+
+    fn apply(&mut self, vote: &PollVote, added: bool) {
+        let count = self.counts.entry(vote.answer_id).or_default();
+        *count = if added { *count + 1 } else { count.saturating_sub(1) };
+    }
+}
+
+/// Tallies of the polls being followed, by message id.
+#[derive(Debug, Default)]
+pub struct PollTallies {
+    polls: HashMap<String, PollTally>,
+}
+
+impl PollTallies {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts following the poll on `message`. Returns `false` if it has none.
+    pub fn track(&mut self, message: &MessageResponse) -> bool {
+        match PollTally::from_message(message) {
+            Some(tally) => {
+                self.polls.insert(tally.message_id.clone(), tally);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn untrack(&mut self, message_id: &str) -> Option<PollTally> {
+        self.polls.remove(message_id)
+    }
+
+    pub fn get(&self, message_id: &str) -> Option<&PollTally> {
+        self.polls.get(message_id)
+    }
+
+    /// Applies a vote event, returning the tally it changed.
+    pub fn update(&mut self, event: &DispatchEvent) -> Option<&PollTally> {
+        let (vote, added) = match event {
+            DispatchEvent::MessagePollVoteAdd(vote) => (vote, true),
+            DispatchEvent::MessagePollVoteRemove(vote) => (vote, false),
+            _ => return None,
+        };
+        let tally = self.polls.get_mut(&vote.message_id)?;
+        tally.apply(vote, added);
+        Some(tally)
+    }
+}
+
+/// Votes for one answer of a poll running on both Discord and YouTube.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CombinedAnswer {
+    pub text: String,
+    pub discord: u32,
+    pub youtube: u32,
+}
+
+impl CombinedAnswer {
+    pub fn total(&self) -> u32 {
+        self.discord + self.youtube
+    }
+}
+
+/// Adds YouTube tallies to a Discord poll mirrored with `PollBuilder::from_youtube`,
+/// matching answers by position.
+pub fn combine(discord: &PollTally, youtube: &PollMetadata) -> Vec<CombinedAnswer> {
+    discord
+        .results()
+        .into_iter()
+        .enumerate()
+        .map(|(index, (text, votes))| CombinedAnswer {
+            text: text.to_string(),
+            discord: votes,
+            youtube: youtube.options
+                .get(index)
+                .and_then(|option| option.tally.parse().ok())
+                .unwrap_or(0),
+        })
+        .collect()
+}
+
+fn summary(question: &str, answers: &[CombinedAnswer]) -> String {
+    let mut text = format!("**{question}**");
+    for answer in answers {
+        text.push_str(&format!(
+            "\n{}: {} ({} on Discord, {} on YouTube)",
+            answer.text, answer.total(), answer.discord, answer.youtube,
+        ));
+    }
+    text
+}
+
+/// A YouTube poll and the Discord poll mirroring it.
+#[derive(Debug, Clone)]
+struct Mirrored {
+    youtube_id: String,
+    message_id: String,
+    youtube: PollMetadata,
+}
+
+/// Mirrors the polls of a YouTube live chat into a Discord channel, and posts the
+/// combined results when the YouTube poll closes.
+#[derive(Debug)]
+pub struct YouTubePollBridge {
+    rest: RestClient,
+    channel_id: String,
+    duration_hours: u32,
+    mirrored: Option<Mirrored>,
+}
+
+impl YouTubePollBridge {
+    pub fn new(rest: RestClient, channel_id: impl Into<String>) -> Self {
+        YouTubePollBridge {
+            rest,
+            channel_id: channel_id.into(),
+            duration_hours: 1,
+            mirrored: None,
+        }
+    }
+
+    /// How long the Discord poll runs if the YouTube poll is never seen closing.
+    pub fn duration_hours(mut self, hours: u32) -> Self {
+        self.duration_hours = hours;
+        self
+    }
+
+    /// Takes the live chat's `activePollItem`. Returns the combined results when a
+    /// mirrored poll finished.
+    pub async fn sync(&mut self, active_poll: Option<&LiveChatMessage>) -> Result<Option<Vec<CombinedAnswer>>, PollError> {
+        let active = active_poll.and_then(|message| {
+            let details = message.snippet.pollDetails.as_ref()?;
+            Some((message.id.as_str(), &details.metadata))
+        });
+
+        if let (Some(mirrored), Some((youtube_id, youtube))) = (&mut self.mirrored, active)
+            && mirrored.youtube_id == youtube_id
+        {
+            // Keep the latest tally, the poll disappears from the chat once closed.
+            mirrored.youtube = youtube.clone();
+            if youtube.status == "active" {
+                return Ok(None)
+            }
+        }
+
+        let results = match self.mirrored.take() {
+            Some(mirrored) => Some(self.finish(mirrored).await?),
+            None => None,
+        };
+
+        if let Some((youtube_id, youtube)) = active
+            && youtube.status == "active"
+        {
+            let poll = PollBuilder::from_youtube(youtube).duration_hours(self.duration_hours);
+            let message = self.rest.create_poll(&self.channel_id, poll).await?;
+            self.mirrored = Some(Mirrored {
+                youtube_id: youtube_id.to_string(),
+                message_id: message.id.into(),
+                youtube: youtube.clone(),
+            });
+        }
+
+        Ok(results)
+    }
+
+    async fn finish(&self, mirrored: Mirrored) -> Result<Vec<CombinedAnswer>, PollError> {
+        let message = match self.rest.end_poll(&self.channel_id, &mirrored.message_id).await {
+            Ok(message) => message,
+            Err(e) if e.is(JsonErrorCode::PollExpired) => self.rest.get_message(&self.channel_id, &mirrored.message_id).await?,
+            Err(e) => return Err(e.into()),
+        };
+        let tally = PollTally::from_message(&message).ok_or(PollError::NotAPoll(mirrored.message_id))?;
+
+        let results = combine(&tally, &mirrored.youtube);
+        let announcement = MessageCreateRequest {
+            content: Some(Some(summary(&tally.question, &results))),
+            ..Default::default()
+        };
+        self.rest.create_message(&self.channel_id, announcement, Vec::new()).await?;
+        Ok(results)
+    }
+
+    /// Follows a live chat until it goes offline or is gone.
+    pub async fn run(mut self, youtube_access_token: String, live_chat_id: String) {
+        let http = reqwest::Client::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let Some(response) = youtube::poll_live_chat_messages(&http, &youtube_access_token, &live_chat_id, page_token.as_deref()).await else {
+                break
+            };
+
+            if let Err(e) = self.sync(response.activePollItem.as_ref()).await {
+                eprintln!("Failed to bridge YouTube poll: {}", e);
+            }
+            if response.offlineAt.is_some() {
+                break
+            }
+
+            page_token = response.nextPageToken;
+            tokio::time::sleep(Duration::from_millis(response.pollingIntervalMillis.unwrap_or(5000))).await;
+        }
+
+        if let Some(mirrored) = self.mirrored.take()
+            && let Err(e) = self.finish(mirrored).await
+        {
+            eprintln!("Failed to close bridged poll: {}", e);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use oozebot_protocol::events::receive::Dispatch;
+    use serde_json::json;
+
+    use crate::youtube::livechatmessages::PollOption;
+
+    use super::*;
+
+    #[test]
+    fn builder_checks_limits() {
+        let poll = PollBuilder::new("Next game?")
+            .answer("Celeste")
+            .answer_with_emoji("Hollow Knight", "🪲")
+            .answer_with_emoji("Custom", "123456")
+            .duration_hours(2)
+            .build()
+            .unwrap();
+        assert_eq!(poll.answers.len(), 3);
+        assert_eq!(poll.duration, Some(Some(2)));
+        let emoji = poll.answers[2].poll_media.emoji.clone().flatten().unwrap();
+        assert_eq!(emoji.id.as_deref(), Some("123456"));
+
+        assert!(matches!(PollBuilder::new("Empty").build(), Err(PollError::NoAnswers)));
+        assert!(matches!(
+            PollBuilder::new("Long").answer("a".repeat(MAX_ANSWER + 1)).build(),
+            Err(PollError::AnswerTooLong { index: 0, .. })
+        ));
+        assert!(matches!(
+            PollBuilder::new("Forever").answer("yes").duration_hours(MAX_DURATION_HOURS + 1).build(),
+            Err(PollError::InvalidDuration(_))
+        ));
+    }
+
+    #[test]
+    fn tally_follows_votes_and_combines_with_youtube() {
+        let poll = serde_json::from_value(json!({
+            "question": { "text": "Next game?" },
+            "answers": [
+                { "answer_id": 1, "poll_media": { "text": "Celeste" } },
+                { "answer_id": 2, "poll_media": { "text": "Hollow Knight" } },
+            ],
+            "expiry": "2025-01-01T00:00:00Z",
+            "allow_multiselect": false,
+            "layout_type": 1,
+            "results": { "answer_counts": [{ "id": 1, "count": 2, "me_voted": false }], "is_finalized": false },
+        }))
+        .unwrap();
+        let message = MessageResponse {
+            id: "50".into(),
+            channel_id: "5".into(),
+            poll: Some(Box::new(poll)),
+            ..Default::default()
+        };
+
+        let vote = |t: &str, answer_id: i32| {
+            let d = json!({ "user_id": "9", "channel_id": "5", "message_id": "50", "guild_id": "1", "answer_id": answer_id });
+            DispatchEvent::try_from(Dispatch { op: 0, d, s: None, t: Some(t.to_string()) }).unwrap()
+        };
+        let mut tallies = PollTallies::new();
+        assert!(tallies.track(&message));
+        tallies.update(&vote("MESSAGE_POLL_VOTE_ADD", 2));
+        tallies.update(&vote("MESSAGE_POLL_VOTE_ADD", 1));
+        tallies.update(&vote("MESSAGE_POLL_VOTE_REMOVE", 1));
+
+        let tally = tallies.get("50").unwrap();
+        assert_eq!(tally.results(), vec![("Celeste", 2), ("Hollow Knight", 1)]);
+
+        let youtube = PollMetadata {
+            options: vec![
+                PollOption { optionText: "Celeste".to_string(), tally: "4".to_string() },
+                PollOption { optionText: "Hollow Knight".to_string(), tally: "7".to_string() },
+            ],
+            questionText: "Next game?".to_string(),
+            status: "closed".to_string(),
+        };
+        let combined = combine(tally, &youtube);
+        assert_eq!(combined[1], CombinedAnswer { text: "Hollow Knight".to_string(), discord: 1, youtube: 7 });
+        assert_eq!(combined[0].total(), 6);
+    }
+}
@@ -0,0 +1,1696 @@
+//! Every Discord endpoint, sent through `RestClient` so calls share its rate limit
+//! buckets. Generated by `cargo run --bin codegen`, do not edit.
+#![allow(clippy::too_many_arguments)]
+
+use openapi::models;
+use reqwest::Method;
+
+use crate::rest::{Request, RestClient, RestError};
+
+
+pub struct Api<'a> {
+    rest: &'a RestClient,
+}
+
+impl RestClient {
+    /// Typed calls for endpoints without a hand written helper.
+    pub fn api(&self) -> Api<'_> {
+        Api { rest: self }
+    }
+}
+
+impl Api<'_> {
+    /// `GET /applications/@me`
+    pub async fn get_my_application(&self) -> Result<models::PrivateApplicationResponse, RestError> {
+        let request = Request::new(Method::GET, "/applications/@me");
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /applications/@me`
+    pub async fn update_my_application(&self, body: &models::ApplicationFormPartial) -> Result<models::PrivateApplicationResponse, RestError> {
+        let request = Request::new(Method::PATCH, "/applications/@me").json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /applications/{application_id}`
+    pub async fn get_application(&self, application_id: &str) -> Result<models::PrivateApplicationResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/applications/{application_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /applications/{application_id}`
+    pub async fn update_application(&self, application_id: &str, body: &models::ApplicationFormPartial) -> Result<models::PrivateApplicationResponse, RestError> {
+        let request = Request::new(Method::PATCH, format!("/applications/{application_id}")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /applications/{application_id}/activity-instances/{instance_id}`
+    pub async fn applications_get_activity_instance(&self, application_id: &str, instance_id: &str) -> Result<models::EmbeddedActivityInstance, RestError> {
+        let request = Request::new(Method::GET, format!("/applications/{application_id}/activity-instances/{instance_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /applications/{application_id}/commands`
+    pub async fn list_application_commands(&self, application_id: &str, with_localizations: Option<bool>) -> Result<Vec<models::ApplicationCommandResponse>, RestError> {
+        let mut request = Request::new(Method::GET, format!("/applications/{application_id}/commands"));
+        if let Some(with_localizations) = with_localizations {
+            request = request.query("with_localizations", with_localizations);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `PUT /applications/{application_id}/commands`
+    pub async fn bulk_set_application_commands(&self, application_id: &str, body: &[models::ApplicationCommandUpdateRequest]) -> Result<Vec<models::ApplicationCommandResponse>, RestError> {
+        let request = Request::new(Method::PUT, format!("/applications/{application_id}/commands")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `POST /applications/{application_id}/commands`
+    pub async fn create_application_command(&self, application_id: &str, body: &models::ApplicationCommandCreateRequest) -> Result<models::ApplicationCommandResponse, RestError> {
+        let request = Request::new(Method::POST, format!("/applications/{application_id}/commands")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /applications/{application_id}/commands/{command_id}`
+    pub async fn get_application_command(&self, application_id: &str, command_id: &str) -> Result<models::ApplicationCommandResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/applications/{application_id}/commands/{command_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /applications/{application_id}/commands/{command_id}`
+    pub async fn delete_application_command(&self, application_id: &str, command_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/applications/{application_id}/commands/{command_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /applications/{application_id}/commands/{command_id}`
+    pub async fn update_application_command(&self, application_id: &str, command_id: &str, body: &models::ApplicationCommandPatchRequestPartial) -> Result<models::ApplicationCommandResponse, RestError> {
+        let request = Request::new(Method::PATCH, format!("/applications/{application_id}/commands/{command_id}")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /applications/{application_id}/emojis`
+    pub async fn list_application_emojis(&self, application_id: &str) -> Result<models::ListApplicationEmojisResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/applications/{application_id}/emojis"));
+        self.rest.send(request).await
+    }
+
+    /// `POST /applications/{application_id}/emojis`
+    pub async fn create_application_emoji(&self, application_id: &str, body: &models::CreateApplicationEmojiRequest) -> Result<models::EmojiResponse, RestError> {
+        let request = Request::new(Method::POST, format!("/applications/{application_id}/emojis")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /applications/{application_id}/emojis/{emoji_id}`
+    pub async fn get_application_emoji(&self, application_id: &str, emoji_id: &str) -> Result<models::EmojiResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/applications/{application_id}/emojis/{emoji_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /applications/{application_id}/emojis/{emoji_id}`
+    pub async fn delete_application_emoji(&self, application_id: &str, emoji_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/applications/{application_id}/emojis/{emoji_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /applications/{application_id}/emojis/{emoji_id}`
+    pub async fn update_application_emoji(&self, application_id: &str, emoji_id: &str, body: &models::UpdateApplicationEmojiRequest) -> Result<models::EmojiResponse, RestError> {
+        let request = Request::new(Method::PATCH, format!("/applications/{application_id}/emojis/{emoji_id}")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /applications/{application_id}/entitlements`
+    pub async fn get_entitlements(&self, application_id: &str, user_id: Option<&str>, sku_ids: Option<&str>, guild_id: Option<&str>, before: Option<&str>, after: Option<&str>, limit: Option<i64>, exclude_ended: Option<bool>, exclude_deleted: Option<bool>, only_active: Option<bool>) -> Result<Vec<Option<models::EntitlementResponse>>, RestError> {
+        let mut request = Request::new(Method::GET, format!("/applications/{application_id}/entitlements"));
+        if let Some(user_id) = user_id {
+            request = request.query("user_id", user_id);
+        }
+        if let Some(sku_ids) = sku_ids {
+            request = request.query("sku_ids", sku_ids);
+        }
+        if let Some(guild_id) = guild_id {
+            request = request.query("guild_id", guild_id);
+        }
+        if let Some(before) = before {
+            request = request.query("before", before);
+        }
+        if let Some(after) = after {
+            request = request.query("after", after);
+        }
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        if let Some(exclude_ended) = exclude_ended {
+            request = request.query("exclude_ended", exclude_ended);
+        }
+        if let Some(exclude_deleted) = exclude_deleted {
+            request = request.query("exclude_deleted", exclude_deleted);
+        }
+        if let Some(only_active) = only_active {
+            request = request.query("only_active", only_active);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `POST /applications/{application_id}/entitlements`
+    pub async fn create_entitlement(&self, application_id: &str, body: &models::CreateEntitlementRequestData) -> Result<models::EntitlementResponse, RestError> {
+        let request = Request::new(Method::POST, format!("/applications/{application_id}/entitlements")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /applications/{application_id}/entitlements/{entitlement_id}`
+    pub async fn get_entitlement(&self, application_id: &str, entitlement_id: &str) -> Result<models::EntitlementResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/applications/{application_id}/entitlements/{entitlement_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /applications/{application_id}/entitlements/{entitlement_id}`
+    pub async fn delete_entitlement(&self, application_id: &str, entitlement_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/applications/{application_id}/entitlements/{entitlement_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `POST /applications/{application_id}/entitlements/{entitlement_id}/consume`
+    pub async fn consume_entitlement(&self, application_id: &str, entitlement_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::POST, format!("/applications/{application_id}/entitlements/{entitlement_id}/consume"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /applications/{application_id}/guilds/{guild_id}/commands`
+    pub async fn list_guild_application_commands(&self, application_id: &str, guild_id: &str, with_localizations: Option<bool>) -> Result<Vec<models::ApplicationCommandResponse>, RestError> {
+        let mut request = Request::new(Method::GET, format!("/applications/{application_id}/guilds/{guild_id}/commands"));
+        if let Some(with_localizations) = with_localizations {
+            request = request.query("with_localizations", with_localizations);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `PUT /applications/{application_id}/guilds/{guild_id}/commands`
+    pub async fn bulk_set_guild_application_commands(&self, application_id: &str, guild_id: &str, body: &[models::ApplicationCommandUpdateRequest]) -> Result<Vec<models::ApplicationCommandResponse>, RestError> {
+        let request = Request::new(Method::PUT, format!("/applications/{application_id}/guilds/{guild_id}/commands")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `POST /applications/{application_id}/guilds/{guild_id}/commands`
+    pub async fn create_guild_application_command(&self, application_id: &str, guild_id: &str, body: &models::ApplicationCommandCreateRequest) -> Result<models::ApplicationCommandResponse, RestError> {
+        let request = Request::new(Method::POST, format!("/applications/{application_id}/guilds/{guild_id}/commands")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /applications/{application_id}/guilds/{guild_id}/commands/permissions`
+    pub async fn list_guild_application_command_permissions(&self, application_id: &str, guild_id: &str) -> Result<Vec<models::CommandPermissionsResponse>, RestError> {
+        let request = Request::new(Method::GET, format!("/applications/{application_id}/guilds/{guild_id}/commands/permissions"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /applications/{application_id}/guilds/{guild_id}/commands/{command_id}`
+    pub async fn get_guild_application_command(&self, application_id: &str, guild_id: &str, command_id: &str) -> Result<models::ApplicationCommandResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/applications/{application_id}/guilds/{guild_id}/commands/{command_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /applications/{application_id}/guilds/{guild_id}/commands/{command_id}`
+    pub async fn delete_guild_application_command(&self, application_id: &str, guild_id: &str, command_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/applications/{application_id}/guilds/{guild_id}/commands/{command_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /applications/{application_id}/guilds/{guild_id}/commands/{command_id}`
+    pub async fn update_guild_application_command(&self, application_id: &str, guild_id: &str, command_id: &str, body: &models::ApplicationCommandPatchRequestPartial) -> Result<models::ApplicationCommandResponse, RestError> {
+        let request = Request::new(Method::PATCH, format!("/applications/{application_id}/guilds/{guild_id}/commands/{command_id}")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /applications/{application_id}/guilds/{guild_id}/commands/{command_id}/permissions`
+    pub async fn get_guild_application_command_permissions(&self, application_id: &str, guild_id: &str, command_id: &str) -> Result<models::CommandPermissionsResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/applications/{application_id}/guilds/{guild_id}/commands/{command_id}/permissions"));
+        self.rest.send(request).await
+    }
+
+    /// `PUT /applications/{application_id}/guilds/{guild_id}/commands/{command_id}/permissions`
+    pub async fn set_guild_application_command_permissions(&self, application_id: &str, guild_id: &str, command_id: &str, body: &models::SetGuildApplicationCommandPermissionsRequest) -> Result<models::CommandPermissionsResponse, RestError> {
+        let request = Request::new(Method::PUT, format!("/applications/{application_id}/guilds/{guild_id}/commands/{command_id}/permissions")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /applications/{application_id}/role-connections/metadata`
+    pub async fn get_application_role_connections_metadata(&self, application_id: &str) -> Result<Vec<models::ApplicationRoleConnectionsMetadataItemRequest>, RestError> {
+        let request = Request::new(Method::GET, format!("/applications/{application_id}/role-connections/metadata"));
+        self.rest.send(request).await
+    }
+
+    /// `PUT /applications/{application_id}/role-connections/metadata`
+    pub async fn update_application_role_connections_metadata(&self, application_id: &str, body: &[models::ApplicationRoleConnectionsMetadataItemRequest]) -> Result<Vec<models::ApplicationRoleConnectionsMetadataItemRequest>, RestError> {
+        let request = Request::new(Method::PUT, format!("/applications/{application_id}/role-connections/metadata")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /channels/{channel_id}`
+    pub async fn get_channel(&self, channel_id: &str) -> Result<models::ChannelResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/channels/{channel_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /channels/{channel_id}`
+    pub async fn delete_channel(&self, channel_id: &str) -> Result<models::ChannelResponse, RestError> {
+        let request = Request::new(Method::DELETE, format!("/channels/{channel_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /channels/{channel_id}`
+    pub async fn update_channel(&self, channel_id: &str, body: &models::UpdateChannelRequest) -> Result<models::ChannelResponse, RestError> {
+        let request = Request::new(Method::PATCH, format!("/channels/{channel_id}")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `POST /channels/{channel_id}/followers`
+    pub async fn follow_channel(&self, channel_id: &str, body: &models::FollowChannelRequest) -> Result<models::ChannelFollowerResponse, RestError> {
+        let request = Request::new(Method::POST, format!("/channels/{channel_id}/followers")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /channels/{channel_id}/invites`
+    pub async fn list_channel_invites(&self, channel_id: &str) -> Result<Vec<models::InviteResponse>, RestError> {
+        let request = Request::new(Method::GET, format!("/channels/{channel_id}/invites"));
+        self.rest.send(request).await
+    }
+
+    /// `POST /channels/{channel_id}/invites`
+    pub async fn create_channel_invite(&self, channel_id: &str, body: &models::CreateChannelInviteRequest) -> Result<models::InviteResponse, RestError> {
+        let request = Request::new(Method::POST, format!("/channels/{channel_id}/invites")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /channels/{channel_id}/messages`
+    pub async fn list_messages(&self, channel_id: &str, around: Option<&str>, before: Option<&str>, after: Option<&str>, limit: Option<i64>) -> Result<Vec<models::MessageResponse>, RestError> {
+        let mut request = Request::new(Method::GET, format!("/channels/{channel_id}/messages"));
+        if let Some(around) = around {
+            request = request.query("around", around);
+        }
+        if let Some(before) = before {
+            request = request.query("before", before);
+        }
+        if let Some(after) = after {
+            request = request.query("after", after);
+        }
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `POST /channels/{channel_id}/messages`
+    pub async fn create_message(&self, channel_id: &str, body: &models::MessageCreateRequest) -> Result<models::MessageResponse, RestError> {
+        let request = Request::new(Method::POST, format!("/channels/{channel_id}/messages")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `POST /channels/{channel_id}/messages/bulk-delete`
+    pub async fn bulk_delete_messages(&self, channel_id: &str, body: &models::BulkDeleteMessagesRequest) -> Result<(), RestError> {
+        let request = Request::new(Method::POST, format!("/channels/{channel_id}/messages/bulk-delete")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /channels/{channel_id}/messages/pins`
+    pub async fn list_pins(&self, channel_id: &str, before: Option<&str>, limit: Option<i64>) -> Result<models::PinnedMessagesResponse, RestError> {
+        let mut request = Request::new(Method::GET, format!("/channels/{channel_id}/messages/pins"));
+        if let Some(before) = before {
+            request = request.query("before", before);
+        }
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `PUT /channels/{channel_id}/messages/pins/{message_id}`
+    pub async fn create_pin(&self, channel_id: &str, message_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::PUT, format!("/channels/{channel_id}/messages/pins/{message_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /channels/{channel_id}/messages/pins/{message_id}`
+    pub async fn delete_pin(&self, channel_id: &str, message_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/channels/{channel_id}/messages/pins/{message_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /channels/{channel_id}/messages/{message_id}`
+    pub async fn get_message(&self, channel_id: &str, message_id: &str) -> Result<models::MessageResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/channels/{channel_id}/messages/{message_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /channels/{channel_id}/messages/{message_id}`
+    pub async fn delete_message(&self, channel_id: &str, message_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/channels/{channel_id}/messages/{message_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /channels/{channel_id}/messages/{message_id}`
+    pub async fn update_message(&self, channel_id: &str, message_id: &str, body: &models::MessageEditRequestPartial) -> Result<models::MessageResponse, RestError> {
+        let request = Request::new(Method::PATCH, format!("/channels/{channel_id}/messages/{message_id}")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `POST /channels/{channel_id}/messages/{message_id}/crosspost`
+    pub async fn crosspost_message(&self, channel_id: &str, message_id: &str) -> Result<models::MessageResponse, RestError> {
+        let request = Request::new(Method::POST, format!("/channels/{channel_id}/messages/{message_id}/crosspost"));
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /channels/{channel_id}/messages/{message_id}/reactions`
+    pub async fn delete_all_message_reactions(&self, channel_id: &str, message_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/channels/{channel_id}/messages/{message_id}/reactions"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /channels/{channel_id}/messages/{message_id}/reactions/{emoji_name}`
+    pub async fn list_message_reactions_by_emoji(&self, channel_id: &str, message_id: &str, emoji_name: &str, after: Option<&str>, limit: Option<i64>, r#type: Option<&str>) -> Result<Vec<models::UserResponse>, RestError> {
+        let mut request = Request::new(Method::GET, format!("/channels/{channel_id}/messages/{message_id}/reactions/{emoji_name}"));
+        if let Some(after) = after {
+            request = request.query("after", after);
+        }
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        if let Some(r#type) = r#type {
+            request = request.query("type", r#type);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /channels/{channel_id}/messages/{message_id}/reactions/{emoji_name}`
+    pub async fn delete_all_message_reactions_by_emoji(&self, channel_id: &str, message_id: &str, emoji_name: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/channels/{channel_id}/messages/{message_id}/reactions/{emoji_name}"));
+        self.rest.send(request).await
+    }
+
+    /// `PUT /channels/{channel_id}/messages/{message_id}/reactions/{emoji_name}/@me`
+    pub async fn add_my_message_reaction(&self, channel_id: &str, message_id: &str, emoji_name: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::PUT, format!("/channels/{channel_id}/messages/{message_id}/reactions/{emoji_name}/@me"));
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /channels/{channel_id}/messages/{message_id}/reactions/{emoji_name}/@me`
+    pub async fn delete_my_message_reaction(&self, channel_id: &str, message_id: &str, emoji_name: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/channels/{channel_id}/messages/{message_id}/reactions/{emoji_name}/@me"));
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /channels/{channel_id}/messages/{message_id}/reactions/{emoji_name}/{user_id}`
+    pub async fn delete_user_message_reaction(&self, channel_id: &str, message_id: &str, emoji_name: &str, user_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/channels/{channel_id}/messages/{message_id}/reactions/{emoji_name}/{user_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `POST /channels/{channel_id}/messages/{message_id}/threads`
+    pub async fn create_thread_from_message(&self, channel_id: &str, message_id: &str, body: &models::CreateTextThreadWithMessageRequest) -> Result<models::ThreadResponse, RestError> {
+        let request = Request::new(Method::POST, format!("/channels/{channel_id}/messages/{message_id}/threads")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `PUT /channels/{channel_id}/permissions/{overwrite_id}`
+    pub async fn set_channel_permission_overwrite(&self, channel_id: &str, overwrite_id: &str, body: &models::SetChannelPermissionOverwriteRequest) -> Result<(), RestError> {
+        let request = Request::new(Method::PUT, format!("/channels/{channel_id}/permissions/{overwrite_id}")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /channels/{channel_id}/permissions/{overwrite_id}`
+    pub async fn delete_channel_permission_overwrite(&self, channel_id: &str, overwrite_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/channels/{channel_id}/permissions/{overwrite_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /channels/{channel_id}/pins`
+    pub async fn deprecated_list_pins(&self, channel_id: &str) -> Result<Vec<models::MessageResponse>, RestError> {
+        let request = Request::new(Method::GET, format!("/channels/{channel_id}/pins"));
+        self.rest.send(request).await
+    }
+
+    /// `PUT /channels/{channel_id}/pins/{message_id}`
+    pub async fn deprecated_create_pin(&self, channel_id: &str, message_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::PUT, format!("/channels/{channel_id}/pins/{message_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /channels/{channel_id}/pins/{message_id}`
+    pub async fn deprecated_delete_pin(&self, channel_id: &str, message_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/channels/{channel_id}/pins/{message_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /channels/{channel_id}/polls/{message_id}/answers/{answer_id}`
+    pub async fn get_answer_voters(&self, channel_id: &str, message_id: &str, answer_id: &str, after: Option<&str>, limit: Option<i64>) -> Result<models::PollAnswerDetailsResponse, RestError> {
+        let mut request = Request::new(Method::GET, format!("/channels/{channel_id}/polls/{message_id}/answers/{answer_id}"));
+        if let Some(after) = after {
+            request = request.query("after", after);
+        }
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `POST /channels/{channel_id}/polls/{message_id}/expire`
+    pub async fn poll_expire(&self, channel_id: &str, message_id: &str) -> Result<models::MessageResponse, RestError> {
+        let request = Request::new(Method::POST, format!("/channels/{channel_id}/polls/{message_id}/expire"));
+        self.rest.send(request).await
+    }
+
+    /// `PUT /channels/{channel_id}/recipients/{user_id}`
+    pub async fn add_group_dm_user(&self, channel_id: &str, user_id: &str, body: &models::AddGroupDmUserRequest) -> Result<models::DmChannelResponse, RestError> {
+        let request = Request::new(Method::PUT, format!("/channels/{channel_id}/recipients/{user_id}")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /channels/{channel_id}/recipients/{user_id}`
+    pub async fn delete_group_dm_user(&self, channel_id: &str, user_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/channels/{channel_id}/recipients/{user_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `POST /channels/{channel_id}/send-soundboard-sound`
+    pub async fn send_soundboard_sound(&self, channel_id: &str, body: &models::SoundboardSoundSendRequest) -> Result<(), RestError> {
+        let request = Request::new(Method::POST, format!("/channels/{channel_id}/send-soundboard-sound")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /channels/{channel_id}/thread-members`
+    pub async fn list_thread_members(&self, channel_id: &str, with_member: Option<bool>, limit: Option<i64>, after: Option<&str>) -> Result<Vec<models::ThreadMemberResponse>, RestError> {
+        let mut request = Request::new(Method::GET, format!("/channels/{channel_id}/thread-members"));
+        if let Some(with_member) = with_member {
+            request = request.query("with_member", with_member);
+        }
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        if let Some(after) = after {
+            request = request.query("after", after);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `PUT /channels/{channel_id}/thread-members/@me`
+    pub async fn join_thread(&self, channel_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::PUT, format!("/channels/{channel_id}/thread-members/@me"));
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /channels/{channel_id}/thread-members/@me`
+    pub async fn leave_thread(&self, channel_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/channels/{channel_id}/thread-members/@me"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /channels/{channel_id}/thread-members/{user_id}`
+    pub async fn get_thread_member(&self, channel_id: &str, user_id: &str, with_member: Option<bool>) -> Result<models::ThreadMemberResponse, RestError> {
+        let mut request = Request::new(Method::GET, format!("/channels/{channel_id}/thread-members/{user_id}"));
+        if let Some(with_member) = with_member {
+            request = request.query("with_member", with_member);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `PUT /channels/{channel_id}/thread-members/{user_id}`
+    pub async fn add_thread_member(&self, channel_id: &str, user_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::PUT, format!("/channels/{channel_id}/thread-members/{user_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /channels/{channel_id}/thread-members/{user_id}`
+    pub async fn delete_thread_member(&self, channel_id: &str, user_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/channels/{channel_id}/thread-members/{user_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `POST /channels/{channel_id}/threads`
+    pub async fn create_thread(&self, channel_id: &str, body: &models::CreateThreadRequest) -> Result<models::CreatedThreadResponse, RestError> {
+        let request = Request::new(Method::POST, format!("/channels/{channel_id}/threads")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /channels/{channel_id}/threads/archived/private`
+    pub async fn list_private_archived_threads(&self, channel_id: &str, before: Option<&str>, limit: Option<i64>) -> Result<models::ThreadsResponse, RestError> {
+        let mut request = Request::new(Method::GET, format!("/channels/{channel_id}/threads/archived/private"));
+        if let Some(before) = before {
+            request = request.query("before", before);
+        }
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `GET /channels/{channel_id}/threads/archived/public`
+    pub async fn list_public_archived_threads(&self, channel_id: &str, before: Option<&str>, limit: Option<i64>) -> Result<models::ThreadsResponse, RestError> {
+        let mut request = Request::new(Method::GET, format!("/channels/{channel_id}/threads/archived/public"));
+        if let Some(before) = before {
+            request = request.query("before", before);
+        }
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `GET /channels/{channel_id}/threads/search`
+    pub async fn thread_search(&self, channel_id: &str, name: Option<&str>, slop: Option<i64>, min_id: Option<&str>, max_id: Option<&str>, tag: Option<&str>, tag_setting: Option<&str>, archived: Option<bool>, sort_by: Option<&str>, sort_order: Option<&str>, limit: Option<i64>, offset: Option<i64>) -> Result<models::ThreadSearchResponse, RestError> {
+        let mut request = Request::new(Method::GET, format!("/channels/{channel_id}/threads/search"));
+        if let Some(name) = name {
+            request = request.query("name", name);
+        }
+        if let Some(slop) = slop {
+            request = request.query("slop", slop);
+        }
+        if let Some(min_id) = min_id {
+            request = request.query("min_id", min_id);
+        }
+        if let Some(max_id) = max_id {
+            request = request.query("max_id", max_id);
+        }
+        if let Some(tag) = tag {
+            request = request.query("tag", tag);
+        }
+        if let Some(tag_setting) = tag_setting {
+            request = request.query("tag_setting", tag_setting);
+        }
+        if let Some(archived) = archived {
+            request = request.query("archived", archived);
+        }
+        if let Some(sort_by) = sort_by {
+            request = request.query("sort_by", sort_by);
+        }
+        if let Some(sort_order) = sort_order {
+            request = request.query("sort_order", sort_order);
+        }
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        if let Some(offset) = offset {
+            request = request.query("offset", offset);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `POST /channels/{channel_id}/typing`
+    pub async fn trigger_typing_indicator(&self, channel_id: &str) -> Result<serde_json::Value, RestError> {
+        let request = Request::new(Method::POST, format!("/channels/{channel_id}/typing"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /channels/{channel_id}/users/@me/threads/archived/private`
+    pub async fn list_my_private_archived_threads(&self, channel_id: &str, before: Option<&str>, limit: Option<i64>) -> Result<models::ThreadsResponse, RestError> {
+        let mut request = Request::new(Method::GET, format!("/channels/{channel_id}/users/@me/threads/archived/private"));
+        if let Some(before) = before {
+            request = request.query("before", before);
+        }
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `GET /channels/{channel_id}/webhooks`
+    pub async fn list_channel_webhooks(&self, channel_id: &str) -> Result<Vec<models::WebhookResponse>, RestError> {
+        let request = Request::new(Method::GET, format!("/channels/{channel_id}/webhooks"));
+        self.rest.send(request).await
+    }
+
+    /// `POST /channels/{channel_id}/webhooks`
+    pub async fn create_webhook(&self, channel_id: &str, body: &models::CreateWebhookRequest) -> Result<models::GuildIncomingWebhookResponse, RestError> {
+        let request = Request::new(Method::POST, format!("/channels/{channel_id}/webhooks")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /gateway`
+    pub async fn get_gateway(&self) -> Result<models::GatewayResponse, RestError> {
+        let request = Request::new(Method::GET, "/gateway");
+        self.rest.send(request).await
+    }
+
+    /// `GET /gateway/bot`
+    pub async fn get_bot_gateway(&self) -> Result<models::GatewayBotResponse, RestError> {
+        let request = Request::new(Method::GET, "/gateway/bot");
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/templates/{code}`
+    pub async fn get_guild_template(&self, code: &str) -> Result<models::GuildTemplateResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/templates/{code}"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}`
+    pub async fn get_guild(&self, guild_id: &str, with_counts: Option<bool>) -> Result<models::GuildWithCountsResponse, RestError> {
+        let mut request = Request::new(Method::GET, format!("/guilds/{guild_id}"));
+        if let Some(with_counts) = with_counts {
+            request = request.query("with_counts", with_counts);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /guilds/{guild_id}`
+    pub async fn update_guild(&self, guild_id: &str, body: &models::GuildPatchRequestPartial) -> Result<models::GuildResponse, RestError> {
+        let request = Request::new(Method::PATCH, format!("/guilds/{guild_id}")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/audit-logs`
+    pub async fn list_guild_audit_log_entries(&self, guild_id: &str, user_id: Option<&str>, target_id: Option<&str>, action_type: Option<&str>, before: Option<&str>, after: Option<&str>, limit: Option<i64>) -> Result<models::GuildAuditLogResponse, RestError> {
+        let mut request = Request::new(Method::GET, format!("/guilds/{guild_id}/audit-logs"));
+        if let Some(user_id) = user_id {
+            request = request.query("user_id", user_id);
+        }
+        if let Some(target_id) = target_id {
+            request = request.query("target_id", target_id);
+        }
+        if let Some(action_type) = action_type {
+            request = request.query("action_type", action_type);
+        }
+        if let Some(before) = before {
+            request = request.query("before", before);
+        }
+        if let Some(after) = after {
+            request = request.query("after", after);
+        }
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/auto-moderation/rules`
+    pub async fn list_auto_moderation_rules(&self, guild_id: &str) -> Result<Vec<models::AutoModerationRuleResponse>, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/auto-moderation/rules"));
+        self.rest.send(request).await
+    }
+
+    /// `POST /guilds/{guild_id}/auto-moderation/rules`
+    pub async fn create_auto_moderation_rule(&self, guild_id: &str, body: &models::CreateAutoModerationRuleRequest) -> Result<models::AutoModerationRuleResponse, RestError> {
+        let request = Request::new(Method::POST, format!("/guilds/{guild_id}/auto-moderation/rules")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/auto-moderation/rules/{rule_id}`
+    pub async fn get_auto_moderation_rule(&self, guild_id: &str, rule_id: &str) -> Result<models::AutoModerationRuleResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/auto-moderation/rules/{rule_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /guilds/{guild_id}/auto-moderation/rules/{rule_id}`
+    pub async fn delete_auto_moderation_rule(&self, guild_id: &str, rule_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/guilds/{guild_id}/auto-moderation/rules/{rule_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /guilds/{guild_id}/auto-moderation/rules/{rule_id}`
+    pub async fn update_auto_moderation_rule(&self, guild_id: &str, rule_id: &str, body: &models::UpdateAutoModerationRuleRequest) -> Result<models::AutoModerationRuleResponse, RestError> {
+        let request = Request::new(Method::PATCH, format!("/guilds/{guild_id}/auto-moderation/rules/{rule_id}")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/bans`
+    pub async fn list_guild_bans(&self, guild_id: &str, limit: Option<i64>, before: Option<&str>, after: Option<&str>) -> Result<Vec<models::GuildBanResponse>, RestError> {
+        let mut request = Request::new(Method::GET, format!("/guilds/{guild_id}/bans"));
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        if let Some(before) = before {
+            request = request.query("before", before);
+        }
+        if let Some(after) = after {
+            request = request.query("after", after);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/bans/{user_id}`
+    pub async fn get_guild_ban(&self, guild_id: &str, user_id: &str) -> Result<models::GuildBanResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/bans/{user_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `PUT /guilds/{guild_id}/bans/{user_id}`
+    pub async fn ban_user_from_guild(&self, guild_id: &str, user_id: &str, body: &models::BanUserFromGuildRequest) -> Result<(), RestError> {
+        let request = Request::new(Method::PUT, format!("/guilds/{guild_id}/bans/{user_id}")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /guilds/{guild_id}/bans/{user_id}`
+    pub async fn unban_user_from_guild(&self, guild_id: &str, user_id: &str, body: &serde_json::Value) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/guilds/{guild_id}/bans/{user_id}")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `POST /guilds/{guild_id}/bulk-ban`
+    pub async fn bulk_ban_users_from_guild(&self, guild_id: &str, body: &models::BulkBanUsersRequest) -> Result<models::BulkBanUsersResponse, RestError> {
+        let request = Request::new(Method::POST, format!("/guilds/{guild_id}/bulk-ban")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/channels`
+    pub async fn list_guild_channels(&self, guild_id: &str) -> Result<Vec<models::ChannelResponse>, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/channels"));
+        self.rest.send(request).await
+    }
+
+    /// `POST /guilds/{guild_id}/channels`
+    pub async fn create_guild_channel(&self, guild_id: &str, body: &models::CreateGuildChannelRequest) -> Result<models::GuildChannelResponse, RestError> {
+        let request = Request::new(Method::POST, format!("/guilds/{guild_id}/channels")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /guilds/{guild_id}/channels`
+    pub async fn bulk_update_guild_channels(&self, guild_id: &str, body: &serde_json::Value) -> Result<(), RestError> {
+        let request = Request::new(Method::PATCH, format!("/guilds/{guild_id}/channels")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/emojis`
+    pub async fn list_guild_emojis(&self, guild_id: &str) -> Result<Vec<models::EmojiResponse>, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/emojis"));
+        self.rest.send(request).await
+    }
+
+    /// `POST /guilds/{guild_id}/emojis`
+    pub async fn create_guild_emoji(&self, guild_id: &str, body: &models::CreateGuildEmojiRequest) -> Result<models::EmojiResponse, RestError> {
+        let request = Request::new(Method::POST, format!("/guilds/{guild_id}/emojis")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/emojis/{emoji_id}`
+    pub async fn get_guild_emoji(&self, guild_id: &str, emoji_id: &str) -> Result<models::EmojiResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/emojis/{emoji_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /guilds/{guild_id}/emojis/{emoji_id}`
+    pub async fn delete_guild_emoji(&self, guild_id: &str, emoji_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/guilds/{guild_id}/emojis/{emoji_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /guilds/{guild_id}/emojis/{emoji_id}`
+    pub async fn update_guild_emoji(&self, guild_id: &str, emoji_id: &str, body: &models::UpdateGuildEmojiRequest) -> Result<models::EmojiResponse, RestError> {
+        let request = Request::new(Method::PATCH, format!("/guilds/{guild_id}/emojis/{emoji_id}")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/integrations`
+    pub async fn list_guild_integrations(&self, guild_id: &str) -> Result<Vec<models::IntegrationResponse>, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/integrations"));
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /guilds/{guild_id}/integrations/{integration_id}`
+    pub async fn delete_guild_integration(&self, guild_id: &str, integration_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/guilds/{guild_id}/integrations/{integration_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/invites`
+    pub async fn list_guild_invites(&self, guild_id: &str) -> Result<Vec<models::InviteResponse>, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/invites"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/members`
+    pub async fn list_guild_members(&self, guild_id: &str, limit: Option<i64>, after: Option<i64>) -> Result<Vec<models::GuildMemberResponse>, RestError> {
+        let mut request = Request::new(Method::GET, format!("/guilds/{guild_id}/members"));
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        if let Some(after) = after {
+            request = request.query("after", after);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /guilds/{guild_id}/members/@me`
+    pub async fn update_my_guild_member(&self, guild_id: &str, body: &models::UpdateMyGuildMemberRequest) -> Result<models::PrivateGuildMemberResponse, RestError> {
+        let request = Request::new(Method::PATCH, format!("/guilds/{guild_id}/members/@me")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/members/search`
+    pub async fn search_guild_members(&self, guild_id: &str, limit: Option<i64>, query: Option<&str>) -> Result<Vec<models::GuildMemberResponse>, RestError> {
+        let mut request = Request::new(Method::GET, format!("/guilds/{guild_id}/members/search"));
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        if let Some(query) = query {
+            request = request.query("query", query);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/members/{user_id}`
+    pub async fn get_guild_member(&self, guild_id: &str, user_id: &str) -> Result<models::GuildMemberResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/members/{user_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `PUT /guilds/{guild_id}/members/{user_id}`
+    pub async fn add_guild_member(&self, guild_id: &str, user_id: &str, body: &models::BotAddGuildMemberRequest) -> Result<models::GuildMemberResponse, RestError> {
+        let request = Request::new(Method::PUT, format!("/guilds/{guild_id}/members/{user_id}")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /guilds/{guild_id}/members/{user_id}`
+    pub async fn delete_guild_member(&self, guild_id: &str, user_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/guilds/{guild_id}/members/{user_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /guilds/{guild_id}/members/{user_id}`
+    pub async fn update_guild_member(&self, guild_id: &str, user_id: &str, body: &models::UpdateGuildMemberRequest) -> Result<models::GuildMemberResponse, RestError> {
+        let request = Request::new(Method::PATCH, format!("/guilds/{guild_id}/members/{user_id}")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `PUT /guilds/{guild_id}/members/{user_id}/roles/{role_id}`
+    pub async fn add_guild_member_role(&self, guild_id: &str, user_id: &str, role_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::PUT, format!("/guilds/{guild_id}/members/{user_id}/roles/{role_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /guilds/{guild_id}/members/{user_id}/roles/{role_id}`
+    pub async fn delete_guild_member_role(&self, guild_id: &str, user_id: &str, role_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/guilds/{guild_id}/members/{user_id}/roles/{role_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/messages/search`
+    pub async fn guild_search(&self, guild_id: &str, sort_by: Option<&str>, sort_order: Option<&str>, content: Option<&str>, slop: Option<i64>, contents: Option<&str>, author_id: Option<&str>, author_type: Option<&str>, mentions: Option<&str>, mention_everyone: Option<bool>, min_id: Option<&str>, max_id: Option<&str>, limit: Option<i64>, offset: Option<i64>, cursor: Option<&str>, has: Option<&str>, link_hostname: Option<&str>, embed_provider: Option<&str>, embed_type: Option<&str>, attachment_extension: Option<&str>, attachment_filename: Option<&str>, pinned: Option<bool>, command_id: Option<&str>, command_name: Option<&str>, include_nsfw: Option<bool>, channel_id: Option<&str>) -> Result<serde_json::Value, RestError> {
+        let mut request = Request::new(Method::GET, format!("/guilds/{guild_id}/messages/search"));
+        if let Some(sort_by) = sort_by {
+            request = request.query("sort_by", sort_by);
+        }
+        if let Some(sort_order) = sort_order {
+            request = request.query("sort_order", sort_order);
+        }
+        if let Some(content) = content {
+            request = request.query("content", content);
+        }
+        if let Some(slop) = slop {
+            request = request.query("slop", slop);
+        }
+        if let Some(contents) = contents {
+            request = request.query("contents", contents);
+        }
+        if let Some(author_id) = author_id {
+            request = request.query("author_id", author_id);
+        }
+        if let Some(author_type) = author_type {
+            request = request.query("author_type", author_type);
+        }
+        if let Some(mentions) = mentions {
+            request = request.query("mentions", mentions);
+        }
+        if let Some(mention_everyone) = mention_everyone {
+            request = request.query("mention_everyone", mention_everyone);
+        }
+        if let Some(min_id) = min_id {
+            request = request.query("min_id", min_id);
+        }
+        if let Some(max_id) = max_id {
+            request = request.query("max_id", max_id);
+        }
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        if let Some(offset) = offset {
+            request = request.query("offset", offset);
+        }
+        if let Some(cursor) = cursor {
+            request = request.query("cursor", cursor);
+        }
+        if let Some(has) = has {
+            request = request.query("has", has);
+        }
+        if let Some(link_hostname) = link_hostname {
+            request = request.query("link_hostname", link_hostname);
+        }
+        if let Some(embed_provider) = embed_provider {
+            request = request.query("embed_provider", embed_provider);
+        }
+        if let Some(embed_type) = embed_type {
+            request = request.query("embed_type", embed_type);
+        }
+        if let Some(attachment_extension) = attachment_extension {
+            request = request.query("attachment_extension", attachment_extension);
+        }
+        if let Some(attachment_filename) = attachment_filename {
+            request = request.query("attachment_filename", attachment_filename);
+        }
+        if let Some(pinned) = pinned {
+            request = request.query("pinned", pinned);
+        }
+        if let Some(command_id) = command_id {
+            request = request.query("command_id", command_id);
+        }
+        if let Some(command_name) = command_name {
+            request = request.query("command_name", command_name);
+        }
+        if let Some(include_nsfw) = include_nsfw {
+            request = request.query("include_nsfw", include_nsfw);
+        }
+        if let Some(channel_id) = channel_id {
+            request = request.query("channel_id", channel_id);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/new-member-welcome`
+    pub async fn get_guild_new_member_welcome(&self, guild_id: &str) -> Result<models::GuildHomeSettingsResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/new-member-welcome"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/onboarding`
+    pub async fn get_guilds_onboarding(&self, guild_id: &str) -> Result<models::UserGuildOnboardingResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/onboarding"));
+        self.rest.send(request).await
+    }
+
+    /// `PUT /guilds/{guild_id}/onboarding`
+    pub async fn put_guilds_onboarding(&self, guild_id: &str, body: &models::UpdateGuildOnboardingRequest) -> Result<models::GuildOnboardingResponse, RestError> {
+        let request = Request::new(Method::PUT, format!("/guilds/{guild_id}/onboarding")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/preview`
+    pub async fn get_guild_preview(&self, guild_id: &str) -> Result<models::GuildPreviewResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/preview"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/prune`
+    pub async fn preview_prune_guild(&self, guild_id: &str, days: Option<i64>, include_roles: Option<&str>) -> Result<models::GuildPruneResponse, RestError> {
+        let mut request = Request::new(Method::GET, format!("/guilds/{guild_id}/prune"));
+        if let Some(days) = days {
+            request = request.query("days", days);
+        }
+        if let Some(include_roles) = include_roles {
+            request = request.query("include_roles", include_roles);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `POST /guilds/{guild_id}/prune`
+    pub async fn prune_guild(&self, guild_id: &str, body: &models::PruneGuildRequest) -> Result<models::GuildPruneResponse, RestError> {
+        let request = Request::new(Method::POST, format!("/guilds/{guild_id}/prune")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/regions`
+    pub async fn list_guild_voice_regions(&self, guild_id: &str) -> Result<Vec<models::VoiceRegionResponse>, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/regions"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/roles`
+    pub async fn list_guild_roles(&self, guild_id: &str) -> Result<Vec<models::GuildRoleResponse>, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/roles"));
+        self.rest.send(request).await
+    }
+
+    /// `POST /guilds/{guild_id}/roles`
+    pub async fn create_guild_role(&self, guild_id: &str, body: &models::CreateRoleRequest) -> Result<models::GuildRoleResponse, RestError> {
+        let request = Request::new(Method::POST, format!("/guilds/{guild_id}/roles")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /guilds/{guild_id}/roles`
+    pub async fn bulk_update_guild_roles(&self, guild_id: &str, body: &[models::UpdateRolePositionsRequest]) -> Result<Vec<models::GuildRoleResponse>, RestError> {
+        let request = Request::new(Method::PATCH, format!("/guilds/{guild_id}/roles")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/roles/member-counts`
+    pub async fn guild_role_member_counts(&self, guild_id: &str) -> Result<std::collections::HashMap<String, i64>, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/roles/member-counts"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/roles/{role_id}`
+    pub async fn get_guild_role(&self, guild_id: &str, role_id: &str) -> Result<models::GuildRoleResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/roles/{role_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /guilds/{guild_id}/roles/{role_id}`
+    pub async fn delete_guild_role(&self, guild_id: &str, role_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/guilds/{guild_id}/roles/{role_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /guilds/{guild_id}/roles/{role_id}`
+    pub async fn update_guild_role(&self, guild_id: &str, role_id: &str, body: &models::UpdateRoleRequestPartial) -> Result<models::GuildRoleResponse, RestError> {
+        let request = Request::new(Method::PATCH, format!("/guilds/{guild_id}/roles/{role_id}")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/scheduled-events`
+    pub async fn list_guild_scheduled_events(&self, guild_id: &str, with_user_count: Option<bool>) -> Result<Vec<models::GuildScheduledEventResponse>, RestError> {
+        let mut request = Request::new(Method::GET, format!("/guilds/{guild_id}/scheduled-events"));
+        if let Some(with_user_count) = with_user_count {
+            request = request.query("with_user_count", with_user_count);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `POST /guilds/{guild_id}/scheduled-events`
+    pub async fn create_guild_scheduled_event(&self, guild_id: &str, body: &models::CreateGuildScheduledEventRequest) -> Result<models::GuildScheduledEventResponse, RestError> {
+        let request = Request::new(Method::POST, format!("/guilds/{guild_id}/scheduled-events")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/scheduled-events/{guild_scheduled_event_id}`
+    pub async fn get_guild_scheduled_event(&self, guild_id: &str, guild_scheduled_event_id: &str, with_user_count: Option<bool>) -> Result<models::GuildScheduledEventResponse, RestError> {
+        let mut request = Request::new(Method::GET, format!("/guilds/{guild_id}/scheduled-events/{guild_scheduled_event_id}"));
+        if let Some(with_user_count) = with_user_count {
+            request = request.query("with_user_count", with_user_count);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /guilds/{guild_id}/scheduled-events/{guild_scheduled_event_id}`
+    pub async fn delete_guild_scheduled_event(&self, guild_id: &str, guild_scheduled_event_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/guilds/{guild_id}/scheduled-events/{guild_scheduled_event_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /guilds/{guild_id}/scheduled-events/{guild_scheduled_event_id}`
+    pub async fn update_guild_scheduled_event(&self, guild_id: &str, guild_scheduled_event_id: &str, body: &models::UpdateGuildScheduledEventRequest) -> Result<models::GuildScheduledEventResponse, RestError> {
+        let request = Request::new(Method::PATCH, format!("/guilds/{guild_id}/scheduled-events/{guild_scheduled_event_id}")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/scheduled-events/{guild_scheduled_event_id}/users`
+    pub async fn list_guild_scheduled_event_users(&self, guild_id: &str, guild_scheduled_event_id: &str, with_member: Option<bool>, limit: Option<i64>, before: Option<&str>, after: Option<&str>) -> Result<Vec<models::ScheduledEventUserResponse>, RestError> {
+        let mut request = Request::new(Method::GET, format!("/guilds/{guild_id}/scheduled-events/{guild_scheduled_event_id}/users"));
+        if let Some(with_member) = with_member {
+            request = request.query("with_member", with_member);
+        }
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        if let Some(before) = before {
+            request = request.query("before", before);
+        }
+        if let Some(after) = after {
+            request = request.query("after", after);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/soundboard-sounds`
+    pub async fn list_guild_soundboard_sounds(&self, guild_id: &str) -> Result<models::ListGuildSoundboardSoundsResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/soundboard-sounds"));
+        self.rest.send(request).await
+    }
+
+    /// `POST /guilds/{guild_id}/soundboard-sounds`
+    pub async fn create_guild_soundboard_sound(&self, guild_id: &str, body: &models::SoundboardCreateRequest) -> Result<models::SoundboardSoundResponse, RestError> {
+        let request = Request::new(Method::POST, format!("/guilds/{guild_id}/soundboard-sounds")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/soundboard-sounds/{sound_id}`
+    pub async fn get_guild_soundboard_sound(&self, guild_id: &str, sound_id: &str) -> Result<models::SoundboardSoundResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/soundboard-sounds/{sound_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /guilds/{guild_id}/soundboard-sounds/{sound_id}`
+    pub async fn delete_guild_soundboard_sound(&self, guild_id: &str, sound_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/guilds/{guild_id}/soundboard-sounds/{sound_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /guilds/{guild_id}/soundboard-sounds/{sound_id}`
+    pub async fn update_guild_soundboard_sound(&self, guild_id: &str, sound_id: &str, body: &models::SoundboardPatchRequestPartial) -> Result<models::SoundboardSoundResponse, RestError> {
+        let request = Request::new(Method::PATCH, format!("/guilds/{guild_id}/soundboard-sounds/{sound_id}")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/stickers`
+    pub async fn list_guild_stickers(&self, guild_id: &str) -> Result<Vec<models::GuildStickerResponse>, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/stickers"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/stickers/{sticker_id}`
+    pub async fn get_guild_sticker(&self, guild_id: &str, sticker_id: &str) -> Result<models::GuildStickerResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/stickers/{sticker_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /guilds/{guild_id}/stickers/{sticker_id}`
+    pub async fn delete_guild_sticker(&self, guild_id: &str, sticker_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/guilds/{guild_id}/stickers/{sticker_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /guilds/{guild_id}/stickers/{sticker_id}`
+    pub async fn update_guild_sticker(&self, guild_id: &str, sticker_id: &str, body: &models::UpdateGuildStickerRequest) -> Result<models::GuildStickerResponse, RestError> {
+        let request = Request::new(Method::PATCH, format!("/guilds/{guild_id}/stickers/{sticker_id}")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/templates`
+    pub async fn list_guild_templates(&self, guild_id: &str) -> Result<Vec<models::GuildTemplateResponse>, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/templates"));
+        self.rest.send(request).await
+    }
+
+    /// `POST /guilds/{guild_id}/templates`
+    pub async fn create_guild_template(&self, guild_id: &str, body: &models::CreateGuildTemplateRequest) -> Result<models::GuildTemplateResponse, RestError> {
+        let request = Request::new(Method::POST, format!("/guilds/{guild_id}/templates")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `PUT /guilds/{guild_id}/templates/{code}`
+    pub async fn sync_guild_template(&self, guild_id: &str, code: &str) -> Result<models::GuildTemplateResponse, RestError> {
+        let request = Request::new(Method::PUT, format!("/guilds/{guild_id}/templates/{code}"));
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /guilds/{guild_id}/templates/{code}`
+    pub async fn delete_guild_template(&self, guild_id: &str, code: &str) -> Result<models::GuildTemplateResponse, RestError> {
+        let request = Request::new(Method::DELETE, format!("/guilds/{guild_id}/templates/{code}"));
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /guilds/{guild_id}/templates/{code}`
+    pub async fn update_guild_template(&self, guild_id: &str, code: &str, body: &models::UpdateGuildTemplateRequest) -> Result<models::GuildTemplateResponse, RestError> {
+        let request = Request::new(Method::PATCH, format!("/guilds/{guild_id}/templates/{code}")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/threads/active`
+    pub async fn get_active_guild_threads(&self, guild_id: &str) -> Result<models::ThreadsResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/threads/active"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/vanity-url`
+    pub async fn get_guild_vanity_url(&self, guild_id: &str) -> Result<models::VanityUrlResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/vanity-url"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/voice-states/@me`
+    pub async fn get_self_voice_state(&self, guild_id: &str) -> Result<models::VoiceStateResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/voice-states/@me"));
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /guilds/{guild_id}/voice-states/@me`
+    pub async fn update_self_voice_state(&self, guild_id: &str, body: &models::UpdateSelfVoiceStateRequestPartial) -> Result<(), RestError> {
+        let request = Request::new(Method::PATCH, format!("/guilds/{guild_id}/voice-states/@me")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/voice-states/{user_id}`
+    pub async fn get_voice_state(&self, guild_id: &str, user_id: &str) -> Result<models::VoiceStateResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/voice-states/{user_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /guilds/{guild_id}/voice-states/{user_id}`
+    pub async fn update_voice_state(&self, guild_id: &str, user_id: &str, body: &models::UpdateVoiceStateRequestPartial) -> Result<(), RestError> {
+        let request = Request::new(Method::PATCH, format!("/guilds/{guild_id}/voice-states/{user_id}")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/webhooks`
+    pub async fn get_guild_webhooks(&self, guild_id: &str) -> Result<Vec<models::WebhookResponse>, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/webhooks"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/welcome-screen`
+    pub async fn get_guild_welcome_screen(&self, guild_id: &str) -> Result<models::GuildWelcomeScreenResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/welcome-screen"));
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /guilds/{guild_id}/welcome-screen`
+    pub async fn update_guild_welcome_screen(&self, guild_id: &str, body: &models::WelcomeScreenPatchRequestPartial) -> Result<models::GuildWelcomeScreenResponse, RestError> {
+        let request = Request::new(Method::PATCH, format!("/guilds/{guild_id}/welcome-screen")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/widget`
+    pub async fn get_guild_widget_settings(&self, guild_id: &str) -> Result<models::WidgetSettingsResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/widget"));
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /guilds/{guild_id}/widget`
+    pub async fn update_guild_widget_settings(&self, guild_id: &str, body: &models::UpdateGuildWidgetSettingsRequest) -> Result<models::WidgetSettingsResponse, RestError> {
+        let request = Request::new(Method::PATCH, format!("/guilds/{guild_id}/widget")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/widget.json`
+    pub async fn get_guild_widget(&self, guild_id: &str) -> Result<models::WidgetResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/widget.json"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /guilds/{guild_id}/widget.png`
+    pub async fn get_guild_widget_png(&self, guild_id: &str, style: Option<&str>) -> Result<(), RestError> {
+        let mut request = Request::new(Method::GET, format!("/guilds/{guild_id}/widget.png"));
+        if let Some(style) = style {
+            request = request.query("style", style);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `POST /interactions/{interaction_id}/{interaction_token}/callback`
+    pub async fn create_interaction_response(&self, interaction_id: &str, interaction_token: &str, body: &models::CreateInteractionResponseRequest, with_response: Option<bool>) -> Result<models::InteractionCallbackResponse, RestError> {
+        let mut request = Request::new(Method::POST, format!("/interactions/{interaction_id}/{interaction_token}/callback")).json(body)?;
+        if let Some(with_response) = with_response {
+            request = request.query("with_response", with_response);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `GET /invites/{code}`
+    pub async fn invite_resolve(&self, code: &str, with_counts: Option<bool>, guild_scheduled_event_id: Option<&str>) -> Result<models::InviteResponse, RestError> {
+        let mut request = Request::new(Method::GET, format!("/invites/{code}"));
+        if let Some(with_counts) = with_counts {
+            request = request.query("with_counts", with_counts);
+        }
+        if let Some(guild_scheduled_event_id) = guild_scheduled_event_id {
+            request = request.query("guild_scheduled_event_id", guild_scheduled_event_id);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /invites/{code}`
+    pub async fn invite_revoke(&self, code: &str) -> Result<models::InviteResponse, RestError> {
+        let request = Request::new(Method::DELETE, format!("/invites/{code}"));
+        self.rest.send(request).await
+    }
+
+    /// `PUT /lobbies`
+    pub async fn create_or_join_lobby(&self, body: &models::CreateOrJoinLobbyRequest) -> Result<models::LobbyResponse, RestError> {
+        let request = Request::new(Method::PUT, "/lobbies").json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `POST /lobbies`
+    pub async fn create_lobby(&self, body: &models::CreateLobbyRequest) -> Result<models::LobbyResponse, RestError> {
+        let request = Request::new(Method::POST, "/lobbies").json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /lobbies/{lobby_id}`
+    pub async fn get_lobby(&self, lobby_id: &str) -> Result<models::LobbyResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/lobbies/{lobby_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /lobbies/{lobby_id}`
+    pub async fn edit_lobby(&self, lobby_id: &str, body: &serde_json::Value) -> Result<models::LobbyResponse, RestError> {
+        let request = Request::new(Method::PATCH, format!("/lobbies/{lobby_id}")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /lobbies/{lobby_id}/channel-linking`
+    pub async fn edit_lobby_channel_link(&self, lobby_id: &str, body: &models::EditLobbyChannelLinkRequest) -> Result<models::LobbyResponse, RestError> {
+        let request = Request::new(Method::PATCH, format!("/lobbies/{lobby_id}/channel-linking")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /lobbies/{lobby_id}/members/@me`
+    pub async fn leave_lobby(&self, lobby_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/lobbies/{lobby_id}/members/@me"));
+        self.rest.send(request).await
+    }
+
+    /// `POST /lobbies/{lobby_id}/members/@me/invites`
+    pub async fn create_linked_lobby_guild_invite_for_self(&self, lobby_id: &str) -> Result<models::LobbyGuildInviteResponse, RestError> {
+        let request = Request::new(Method::POST, format!("/lobbies/{lobby_id}/members/@me/invites"));
+        self.rest.send(request).await
+    }
+
+    /// `POST /lobbies/{lobby_id}/members/bulk`
+    pub async fn bulk_update_lobby_members(&self, lobby_id: &str, body: &[models::BulkLobbyMemberRequest]) -> Result<Vec<models::LobbyMemberResponse>, RestError> {
+        let request = Request::new(Method::POST, format!("/lobbies/{lobby_id}/members/bulk")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `PUT /lobbies/{lobby_id}/members/{user_id}`
+    pub async fn add_lobby_member(&self, lobby_id: &str, user_id: &str, body: &models::AddLobbyMemberRequest) -> Result<models::LobbyMemberResponse, RestError> {
+        let request = Request::new(Method::PUT, format!("/lobbies/{lobby_id}/members/{user_id}")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /lobbies/{lobby_id}/members/{user_id}`
+    pub async fn delete_lobby_member(&self, lobby_id: &str, user_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/lobbies/{lobby_id}/members/{user_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `POST /lobbies/{lobby_id}/members/{user_id}/invites`
+    pub async fn create_linked_lobby_guild_invite_for_user(&self, lobby_id: &str, user_id: &str) -> Result<models::LobbyGuildInviteResponse, RestError> {
+        let request = Request::new(Method::POST, format!("/lobbies/{lobby_id}/members/{user_id}/invites"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /lobbies/{lobby_id}/messages`
+    pub async fn get_lobby_messages(&self, lobby_id: &str, limit: Option<i64>) -> Result<Vec<models::LobbyMessageResponse>, RestError> {
+        let mut request = Request::new(Method::GET, format!("/lobbies/{lobby_id}/messages"));
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `POST /lobbies/{lobby_id}/messages`
+    pub async fn create_lobby_message(&self, lobby_id: &str, body: &models::SdkMessageRequest) -> Result<models::LobbyMessageResponse, RestError> {
+        let request = Request::new(Method::POST, format!("/lobbies/{lobby_id}/messages")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /oauth2/@me`
+    pub async fn get_my_oauth2_authorization(&self) -> Result<models::OAuth2GetAuthorizationResponse, RestError> {
+        let request = Request::new(Method::GET, "/oauth2/@me");
+        self.rest.send(request).await
+    }
+
+    /// `GET /oauth2/applications/@me`
+    pub async fn get_my_oauth2_application(&self) -> Result<models::PrivateApplicationResponse, RestError> {
+        let request = Request::new(Method::GET, "/oauth2/applications/@me");
+        self.rest.send(request).await
+    }
+
+    /// `GET /oauth2/keys`
+    pub async fn get_public_keys(&self) -> Result<models::OAuth2GetKeys, RestError> {
+        let request = Request::new(Method::GET, "/oauth2/keys");
+        self.rest.send(request).await
+    }
+
+    /// `GET /oauth2/userinfo`
+    pub async fn get_openid_connect_userinfo(&self) -> Result<models::OAuth2GetOpenIdConnectUserInfoResponse, RestError> {
+        let request = Request::new(Method::GET, "/oauth2/userinfo");
+        self.rest.send(request).await
+    }
+
+    /// `POST /partner-sdk/provisional-accounts/unmerge`
+    pub async fn partner_sdk_unmerge_provisional_account(&self, body: &models::PartnerSdkUnmergeProvisionalAccountRequest) -> Result<(), RestError> {
+        let request = Request::new(Method::POST, "/partner-sdk/provisional-accounts/unmerge").json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `POST /partner-sdk/provisional-accounts/unmerge/bot`
+    pub async fn bot_partner_sdk_unmerge_provisional_account(&self, body: &models::BotPartnerSdkUnmergeProvisionalAccountRequest) -> Result<(), RestError> {
+        let request = Request::new(Method::POST, "/partner-sdk/provisional-accounts/unmerge/bot").json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `POST /partner-sdk/token`
+    pub async fn partner_sdk_token(&self, body: &serde_json::Value) -> Result<models::ProvisionalTokenResponse, RestError> {
+        let request = Request::new(Method::POST, "/partner-sdk/token").json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `POST /partner-sdk/token/bot`
+    pub async fn bot_partner_sdk_token(&self, body: &models::BotPartnerSdkTokenRequest) -> Result<models::ProvisionalTokenResponse, RestError> {
+        let request = Request::new(Method::POST, "/partner-sdk/token/bot").json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /soundboard-default-sounds`
+    pub async fn get_soundboard_default_sounds(&self) -> Result<Vec<models::SoundboardSoundResponse>, RestError> {
+        let request = Request::new(Method::GET, "/soundboard-default-sounds");
+        self.rest.send(request).await
+    }
+
+    /// `POST /stage-instances`
+    pub async fn create_stage_instance(&self, body: &models::CreateStageInstanceRequest) -> Result<models::StageInstanceResponse, RestError> {
+        let request = Request::new(Method::POST, "/stage-instances").json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /stage-instances/{channel_id}`
+    pub async fn get_stage_instance(&self, channel_id: &str) -> Result<models::StageInstanceResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/stage-instances/{channel_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /stage-instances/{channel_id}`
+    pub async fn delete_stage_instance(&self, channel_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/stage-instances/{channel_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /stage-instances/{channel_id}`
+    pub async fn update_stage_instance(&self, channel_id: &str, body: &models::UpdateStageInstanceRequest) -> Result<models::StageInstanceResponse, RestError> {
+        let request = Request::new(Method::PATCH, format!("/stage-instances/{channel_id}")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /sticker-packs`
+    pub async fn list_sticker_packs(&self) -> Result<models::StickerPackCollectionResponse, RestError> {
+        let request = Request::new(Method::GET, "/sticker-packs");
+        self.rest.send(request).await
+    }
+
+    /// `GET /sticker-packs/{pack_id}`
+    pub async fn get_sticker_pack(&self, pack_id: &str) -> Result<models::StickerPackResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/sticker-packs/{pack_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /stickers/{sticker_id}`
+    pub async fn get_sticker(&self, sticker_id: &str) -> Result<models::StickerResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/stickers/{sticker_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /users/@me`
+    pub async fn get_my_user(&self) -> Result<models::UserPiiResponse, RestError> {
+        let request = Request::new(Method::GET, "/users/@me");
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /users/@me`
+    pub async fn update_my_user(&self, body: &models::BotAccountPatchRequest) -> Result<models::UserPiiResponse, RestError> {
+        let request = Request::new(Method::PATCH, "/users/@me").json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /users/@me/applications/{application_id}/role-connection`
+    pub async fn get_application_user_role_connection(&self, application_id: &str) -> Result<models::ApplicationUserRoleConnectionResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/users/@me/applications/{application_id}/role-connection"));
+        self.rest.send(request).await
+    }
+
+    /// `PUT /users/@me/applications/{application_id}/role-connection`
+    pub async fn update_application_user_role_connection(&self, application_id: &str, body: &models::UpdateApplicationUserRoleConnectionRequest) -> Result<models::ApplicationUserRoleConnectionResponse, RestError> {
+        let request = Request::new(Method::PUT, format!("/users/@me/applications/{application_id}/role-connection")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /users/@me/applications/{application_id}/role-connection`
+    pub async fn delete_application_user_role_connection(&self, application_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/users/@me/applications/{application_id}/role-connection"));
+        self.rest.send(request).await
+    }
+
+    /// `POST /users/@me/channels`
+    pub async fn create_dm(&self, body: &models::CreatePrivateChannelRequest) -> Result<models::DmChannelResponse, RestError> {
+        let request = Request::new(Method::POST, "/users/@me/channels").json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /users/@me/connections`
+    pub async fn list_my_connections(&self) -> Result<Vec<models::ConnectedAccountResponse>, RestError> {
+        let request = Request::new(Method::GET, "/users/@me/connections");
+        self.rest.send(request).await
+    }
+
+    /// `GET /users/@me/guilds`
+    pub async fn list_my_guilds(&self, before: Option<&str>, after: Option<&str>, limit: Option<i64>, with_counts: Option<bool>) -> Result<Vec<models::MyGuildResponse>, RestError> {
+        let mut request = Request::new(Method::GET, "/users/@me/guilds");
+        if let Some(before) = before {
+            request = request.query("before", before);
+        }
+        if let Some(after) = after {
+            request = request.query("after", after);
+        }
+        if let Some(limit) = limit {
+            request = request.query("limit", limit);
+        }
+        if let Some(with_counts) = with_counts {
+            request = request.query("with_counts", with_counts);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /users/@me/guilds/{guild_id}`
+    pub async fn leave_guild(&self, guild_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/users/@me/guilds/{guild_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /users/@me/guilds/{guild_id}/member`
+    pub async fn get_my_guild_member(&self, guild_id: &str) -> Result<models::PrivateGuildMemberResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/users/@me/guilds/{guild_id}/member"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /users/{user_id}`
+    pub async fn get_user(&self, user_id: &str) -> Result<models::UserResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/users/{user_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `GET /voice/regions`
+    pub async fn list_voice_regions(&self) -> Result<Vec<models::VoiceRegionResponse>, RestError> {
+        let request = Request::new(Method::GET, "/voice/regions");
+        self.rest.send(request).await
+    }
+
+    /// `GET /webhooks/{webhook_id}`
+    pub async fn get_webhook(&self, webhook_id: &str) -> Result<models::WebhookResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/webhooks/{webhook_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /webhooks/{webhook_id}`
+    pub async fn delete_webhook(&self, webhook_id: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/webhooks/{webhook_id}"));
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /webhooks/{webhook_id}`
+    pub async fn update_webhook(&self, webhook_id: &str, body: &models::UpdateWebhookRequest) -> Result<models::WebhookResponse, RestError> {
+        let request = Request::new(Method::PATCH, format!("/webhooks/{webhook_id}")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `GET /webhooks/{webhook_id}/{webhook_token}`
+    pub async fn get_webhook_by_token(&self, webhook_id: &str, webhook_token: &str) -> Result<models::WebhookResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/webhooks/{webhook_id}/{webhook_token}"));
+        self.rest.send(request).await
+    }
+
+    /// `POST /webhooks/{webhook_id}/{webhook_token}`
+    pub async fn execute_webhook(&self, webhook_id: &str, webhook_token: &str, body: &models::ExecuteWebhookRequest, wait: Option<bool>, thread_id: Option<&str>, with_components: Option<bool>) -> Result<models::MessageResponse, RestError> {
+        let mut request = Request::new(Method::POST, format!("/webhooks/{webhook_id}/{webhook_token}")).json(body)?;
+        if let Some(wait) = wait {
+            request = request.query("wait", wait);
+        }
+        if let Some(thread_id) = thread_id {
+            request = request.query("thread_id", thread_id);
+        }
+        if let Some(with_components) = with_components {
+            request = request.query("with_components", with_components);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /webhooks/{webhook_id}/{webhook_token}`
+    pub async fn delete_webhook_by_token(&self, webhook_id: &str, webhook_token: &str) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/webhooks/{webhook_id}/{webhook_token}"));
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /webhooks/{webhook_id}/{webhook_token}`
+    pub async fn update_webhook_by_token(&self, webhook_id: &str, webhook_token: &str, body: &models::UpdateWebhookByTokenRequest) -> Result<models::WebhookResponse, RestError> {
+        let request = Request::new(Method::PATCH, format!("/webhooks/{webhook_id}/{webhook_token}")).json(body)?;
+        self.rest.send(request).await
+    }
+
+    /// `POST /webhooks/{webhook_id}/{webhook_token}/github`
+    pub async fn execute_github_compatible_webhook(&self, webhook_id: &str, webhook_token: &str, body: &models::GithubWebhook, wait: Option<bool>, thread_id: Option<&str>) -> Result<(), RestError> {
+        let mut request = Request::new(Method::POST, format!("/webhooks/{webhook_id}/{webhook_token}/github")).json(body)?;
+        if let Some(wait) = wait {
+            request = request.query("wait", wait);
+        }
+        if let Some(thread_id) = thread_id {
+            request = request.query("thread_id", thread_id);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `GET /webhooks/{webhook_id}/{webhook_token}/messages/@original`
+    pub async fn get_original_webhook_message(&self, webhook_id: &str, webhook_token: &str, thread_id: Option<&str>) -> Result<models::MessageResponse, RestError> {
+        let mut request = Request::new(Method::GET, format!("/webhooks/{webhook_id}/{webhook_token}/messages/@original"));
+        if let Some(thread_id) = thread_id {
+            request = request.query("thread_id", thread_id);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /webhooks/{webhook_id}/{webhook_token}/messages/@original`
+    pub async fn delete_original_webhook_message(&self, webhook_id: &str, webhook_token: &str, thread_id: Option<&str>) -> Result<(), RestError> {
+        let mut request = Request::new(Method::DELETE, format!("/webhooks/{webhook_id}/{webhook_token}/messages/@original"));
+        if let Some(thread_id) = thread_id {
+            request = request.query("thread_id", thread_id);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /webhooks/{webhook_id}/{webhook_token}/messages/@original`
+    pub async fn update_original_webhook_message(&self, webhook_id: &str, webhook_token: &str, body: &models::IncomingWebhookUpdateRequestPartial, thread_id: Option<&str>, with_components: Option<bool>) -> Result<models::MessageResponse, RestError> {
+        let mut request = Request::new(Method::PATCH, format!("/webhooks/{webhook_id}/{webhook_token}/messages/@original")).json(body)?;
+        if let Some(thread_id) = thread_id {
+            request = request.query("thread_id", thread_id);
+        }
+        if let Some(with_components) = with_components {
+            request = request.query("with_components", with_components);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `GET /webhooks/{webhook_id}/{webhook_token}/messages/{message_id}`
+    pub async fn get_webhook_message(&self, webhook_id: &str, webhook_token: &str, message_id: &str, thread_id: Option<&str>) -> Result<models::MessageResponse, RestError> {
+        let mut request = Request::new(Method::GET, format!("/webhooks/{webhook_id}/{webhook_token}/messages/{message_id}"));
+        if let Some(thread_id) = thread_id {
+            request = request.query("thread_id", thread_id);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `DELETE /webhooks/{webhook_id}/{webhook_token}/messages/{message_id}`
+    pub async fn delete_webhook_message(&self, webhook_id: &str, webhook_token: &str, message_id: &str, thread_id: Option<&str>) -> Result<(), RestError> {
+        let mut request = Request::new(Method::DELETE, format!("/webhooks/{webhook_id}/{webhook_token}/messages/{message_id}"));
+        if let Some(thread_id) = thread_id {
+            request = request.query("thread_id", thread_id);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `PATCH /webhooks/{webhook_id}/{webhook_token}/messages/{message_id}`
+    pub async fn update_webhook_message(&self, webhook_id: &str, webhook_token: &str, message_id: &str, body: &models::IncomingWebhookUpdateRequestPartial, thread_id: Option<&str>, with_components: Option<bool>) -> Result<models::MessageResponse, RestError> {
+        let mut request = Request::new(Method::PATCH, format!("/webhooks/{webhook_id}/{webhook_token}/messages/{message_id}")).json(body)?;
+        if let Some(thread_id) = thread_id {
+            request = request.query("thread_id", thread_id);
+        }
+        if let Some(with_components) = with_components {
+            request = request.query("with_components", with_components);
+        }
+        self.rest.send(request).await
+    }
+
+    /// `POST /webhooks/{webhook_id}/{webhook_token}/slack`
+    pub async fn execute_slack_compatible_webhook(&self, webhook_id: &str, webhook_token: &str, body: &models::SlackWebhook, wait: Option<bool>, thread_id: Option<&str>) -> Result<String, RestError> {
+        let mut request = Request::new(Method::POST, format!("/webhooks/{webhook_id}/{webhook_token}/slack")).json(body)?;
+        if let Some(wait) = wait {
+            request = request.query("wait", wait);
+        }
+        if let Some(thread_id) = thread_id {
+            request = request.query("thread_id", thread_id);
+        }
+        self.rest.send(request).await
+    }
+}
@@ -0,0 +1,249 @@
+use std::{fmt::Debug, path::Path, pin::Pin};
+
+use base64::Engine;
+use openapi::models::{EmojiResponse, GuildStickerResponse, MessageAttachmentRequest, MessageCreateRequest, MessageResponse};
+use reqwest::{multipart::Part, Method};
+use serde::Serialize;
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio_util::io::ReaderStream;
+
+use crate::rest::{Request, RestClient, RestError};
+
+
+enum AttachmentSource {
+    Bytes(Vec<u8>),
+    Reader(Option<Pin<Box<dyn AsyncRead + Send + Sync>>>),
+}
+
+impl Debug for AttachmentSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bytes(bytes) => write!(f, "Bytes({} bytes)", bytes.len()),
+            Self::Reader(reader) => write!(f, "Reader(consumed: {})", reader.is_none()),
+        }
+    }
+}
+
+/// A file uploaded alongside a request.
+///
+/// Embeds can show an uploaded image by pointing at `Attachment::url`.
+#[derive(Debug)]
+pub struct Attachment {
+    pub filename: String,
+    pub description: Option<String>,
+    source: AttachmentSource,
+}
+
+impl Attachment {
+    pub fn from_bytes(filename: impl Into<String>, bytes: impl Into<Vec<u8>>) -> Self {
+        Attachment {
+            filename: filename.into(),
+            description: None,
+            source: AttachmentSource::Bytes(bytes.into()),
+        }
+    }
+
+    /// Streams the file from `reader` instead of buffering it.
+    pub fn from_reader<R>(filename: impl Into<String>, reader: R) -> Self
+    where
+        R: AsyncRead + Send + Sync + 'static,
+    {
+        Attachment {
+            filename: filename.into(),
+            description: None,
+            source: AttachmentSource::Reader(Some(Box::pin(reader))),
+        }
+    }
+
+    /// Streams a file from disk, named after the last path component.
+    pub async fn from_path(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let path = path.as_ref();
+        let filename = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "file".to_string());
+        let file = tokio::fs::File::open(path).await?;
+
+        Ok(Attachment::from_reader(filename, file))
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// `attachment://` url for referencing this file from an embed.
+    pub fn url(&self) -> String {
+        format!("attachment://{}", self.filename)
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        mime_type(&self.filename)
+    }
+
+    pub(crate) fn take_part(&mut self) -> Result<Part, RestError> {
+        let part = match &mut self.source {
+            AttachmentSource::Bytes(bytes) => Part::bytes(bytes.clone()),
+            AttachmentSource::Reader(reader) => {
+                let reader = reader.take().ok_or(RestError::BodyConsumed)?;
+                Part::stream(reqwest::Body::wrap_stream(ReaderStream::new(reader)))
+            }
+        };
+
+        Ok(part.file_name(self.filename.clone()).mime_str(self.mime_type())?)
+    }
+
+    async fn into_bytes(self) -> std::io::Result<Vec<u8>> {
+        match self.source {
+            AttachmentSource::Bytes(bytes) => Ok(bytes),
+            AttachmentSource::Reader(Some(mut reader)) => {
+                let mut bytes = Vec::new();
+                reader.read_to_end(&mut bytes).await?;
+                Ok(bytes)
+            }
+            AttachmentSource::Reader(None) => Err(std::io::Error::other("attachment was already streamed")),
+        }
+    }
+
+    /// `data:` uri as expected by image fields such as emoji, avatars and icons.
+    pub async fn to_data_uri(self) -> std::io::Result<String> {
+        let mime = self.mime_type();
+        let bytes = self.into_bytes().await?;
+        Ok(format!("data:{};base64,{}", mime, base64::engine::general_purpose::STANDARD.encode(bytes)))
+    }
+}
+
+fn mime_type(filename: &str) -> &'static str {
+    let extension = filename
+        .rsplit_once('.')
+        .map(|(_, extension)| extension.to_ascii_lowercase())
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "png" | "apng" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "json" => "application/json",
+        "txt" => "text/plain",
+        "mp3" => "audio/mpeg",
+        "ogg" => "audio/ogg",
+        "wav" => "audio/wav",
+        "mp4" => "video/mp4",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Fills in a message's `attachments` so each uploaded `files[n]` part is described,
+/// keeping any entries the caller already set.
+pub(crate) fn describe_attachments(attachments: &mut Option<Option<Vec<MessageAttachmentRequest>>>, files: &[Attachment]) {
+    let described = attachments.get_or_insert_with(|| Some(Vec::new())).get_or_insert_with(Vec::new);
+
+    for (index, file) in files.iter().enumerate() {
+        let id = index.to_string();
+        if described.iter().any(|attachment| attachment.id == id) {
+            continue
+        }
+        let mut attachment = MessageAttachmentRequest::new(id.into());
+        attachment.filename = Some(Some(file.filename.clone()));
+        attachment.description = file.description.clone().map(Some);
+        described.push(attachment);
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct CreateGuildEmoji<'a> {
+    name: &'a str,
+    image: String,
+    roles: &'a [String],
+}
+
+impl RestClient {
+    /// Posts a message, uploading `files` as `files[0]`, `files[1]`, ...
+    pub async fn create_message(&self, channel_id: &str, mut message: MessageCreateRequest, files: Vec<Attachment>) -> Result<MessageResponse, RestError> {
+        let request = Request::new(Method::POST, format!("/channels/{channel_id}/messages"));
+
+        if files.is_empty() {
+            return self.send(request.json(&message)?).await
+        }
+
+        describe_attachments(&mut message.attachments, &files);
+        let mut request = request.payload_json(&message)?;
+        for (index, file) in files.into_iter().enumerate() {
+            request = request.file(&format!("files[{index}]"), file);
+        }
+
+        self.send(request).await
+    }
+
+    pub async fn get_message(&self, channel_id: &str, message_id: &str) -> Result<MessageResponse, RestError> {
+        let request = Request::new(Method::GET, format!("/channels/{channel_id}/messages/{message_id}"));
+        self.send(request).await
+    }
+
+    /// Uploads a PNG, APNG, GIF or Lottie JSON sticker.
+    pub async fn create_guild_sticker(&self, guild_id: &str, name: &str, tags: &str, description: Option<&str>, file: Attachment, reason: Option<&str>) -> Result<GuildStickerResponse, RestError> {
+        let mut request = Request::new(Method::POST, format!("/guilds/{guild_id}/stickers"))
+            .field("name", name)
+            .field("tags", tags)
+            .reason(reason);
+        if let Some(description) = description {
+            request = request.field("description", description);
+        }
+
+        self.send(request.file("file", file)).await
+    }
+
+    pub async fn create_guild_emoji(&self, guild_id: &str, name: &str, image: Attachment, roles: &[String], reason: Option<&str>) -> Result<EmojiResponse, RestError> {
+        let body = CreateGuildEmoji {
+            name,
+            image: image.to_data_uri().await?,
+            roles,
+        };
+        let request = Request::new(Method::POST, format!("/guilds/{guild_id}/emojis"))
+            .json(&body)?
+            .reason(reason);
+
+        self.send(request).await
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use openapi::models::{MessageAttachmentRequest, MessageCreateRequest};
+
+    use super::{describe_attachments, Attachment};
+
+    #[test]
+    fn attachments_are_described_in_payload() {
+        let mut existing = MessageAttachmentRequest::new("1".into());
+        existing.description = Some(Some("kept".to_string()));
+
+        let mut message = MessageCreateRequest {
+            attachments: Some(Some(vec![existing])),
+            ..Default::default()
+        };
+        let files = vec![
+            Attachment::from_bytes("tts.wav", vec![0u8; 4]).description("recording"),
+            Attachment::from_bytes("screenshot.png", vec![0u8; 4]),
+        ];
+
+        describe_attachments(&mut message.attachments, &files);
+
+        let attachments = message.attachments.flatten().unwrap();
+        assert_eq!(attachments.len(), 2);
+        assert_eq!(attachments[0].description, Some(Some("kept".to_string())));
+        assert_eq!(attachments[1].id, "0");
+        assert_eq!(attachments[1].filename, Some(Some("tts.wav".to_string())));
+        assert_eq!(attachments[1].description, Some(Some("recording".to_string())));
+        assert_eq!(files[1].url(), "attachment://screenshot.png");
+    }
+
+    #[tokio::test]
+    async fn data_uri_from_reader() {
+        let image = Attachment::from_reader("blob.PNG", &b"png"[..]);
+        assert_eq!(image.mime_type(), "image/png");
+        assert_eq!(image.to_data_uri().await.unwrap(), "data:image/png;base64,cG5n");
+    }
+}
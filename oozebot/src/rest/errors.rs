@@ -0,0 +1,388 @@
+use std::fmt;
+
+use reqwest::StatusCode;
+use serde::Deserialize;
+use serde_json::Value;
+use thiserror::Error;
+
+
+macro_rules! json_error_codes {
+    ($($name:ident = $code:literal,)*) => {
+        /// Discord's JSON error codes, as listed in the API reference.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum JsonErrorCode {
+            $($name,)*
+            /// A code this list does not know yet.
+            Other(u32),
+        }
+
+        impl JsonErrorCode {
+            pub fn from_code(code: u32) -> Self {
+                match code {
+                    $($code => JsonErrorCode::$name,)*
+                    code => JsonErrorCode::Other(code),
+                }
+            }
+
+            pub fn code(self) -> u32 {
+                match self {
+                    $(JsonErrorCode::$name => $code,)*
+                    JsonErrorCode::Other(code) => code,
+                }
+            }
+        }
+    };
+}
+
+json_error_codes! {
+    General = 0,
+    UnknownAccount = 10001,
+    UnknownApplication = 10002,
+    UnknownChannel = 10003,
+    UnknownGuild = 10004,
+    UnknownIntegration = 10005,
+    UnknownInvite = 10006,
+    UnknownMember = 10007,
+    UnknownMessage = 10008,
+    UnknownPermissionOverwrite = 10009,
+    UnknownProvider = 10010,
+    UnknownRole = 10011,
+    UnknownToken = 10012,
+    UnknownUser = 10013,
+    UnknownEmoji = 10014,
+    UnknownWebhook = 10015,
+    UnknownWebhookService = 10016,
+    UnknownSession = 10020,
+    UnknownAsset = 10021,
+    UnknownBan = 10026,
+    UnknownSku = 10027,
+    UnknownStoreListing = 10028,
+    UnknownEntitlement = 10029,
+    UnknownBuild = 10030,
+    UnknownLobby = 10031,
+    UnknownBranch = 10032,
+    UnknownStoreDirectoryLayout = 10033,
+    UnknownRedistributable = 10036,
+    UnknownGiftCode = 10038,
+    UnknownStream = 10049,
+    UnknownPremiumServerSubscribeCooldown = 10050,
+    UnknownGuildTemplate = 10057,
+    UnknownDiscoverableServerCategory = 10059,
+    UnknownSticker = 10060,
+    UnknownStickerPack = 10061,
+    UnknownInteraction = 10062,
+    UnknownApplicationCommand = 10063,
+    UnknownVoiceState = 10065,
+    UnknownApplicationCommandPermissions = 10066,
+    UnknownStageInstance = 10067,
+    UnknownGuildMemberVerificationForm = 10068,
+    UnknownGuildWelcomeScreen = 10069,
+    UnknownGuildScheduledEvent = 10070,
+    UnknownGuildScheduledEventUser = 10071,
+    UnknownTag = 10087,
+    UnknownSound = 10097,
+    BotsCannotUseEndpoint = 20001,
+    OnlyBotsCanUseEndpoint = 20002,
+    ExplicitContentCannotBeSent = 20009,
+    NotAuthorizedForApplication = 20012,
+    SlowmodeRateLimit = 20016,
+    OnlyOwnerCanPerformAction = 20018,
+    AnnouncementEditRateLimit = 20022,
+    UnderMinimumAge = 20024,
+    ChannelWriteRateLimit = 20028,
+    ServerWriteRateLimit = 20029,
+    DisallowedWords = 20031,
+    GuildPremiumTierTooLow = 20035,
+    MaxGuilds = 30001,
+    MaxFriends = 30002,
+    MaxPins = 30003,
+    MaxRecipients = 30004,
+    MaxRoles = 30005,
+    MaxWebhooks = 30007,
+    MaxEmojis = 30008,
+    MaxReactions = 30010,
+    MaxGroupDms = 30011,
+    MaxChannels = 30013,
+    MaxAttachments = 30015,
+    MaxInvites = 30016,
+    MaxAnimatedEmojis = 30018,
+    MaxServerMembers = 30019,
+    MaxServerCategories = 30030,
+    GuildAlreadyHasTemplate = 30031,
+    MaxApplicationCommands = 30032,
+    MaxThreadParticipants = 30033,
+    MaxDailyApplicationCommandCreates = 30034,
+    MaxBansForNonMembers = 30035,
+    MaxBanFetches = 30037,
+    MaxUncompletedScheduledEvents = 30038,
+    MaxStickers = 30039,
+    MaxPruneRequests = 30040,
+    MaxWidgetSettingsUpdates = 30042,
+    MaxSoundboardSounds = 30045,
+    MaxEditsToOldMessages = 30046,
+    MaxPinnedThreads = 30047,
+    MaxForumTags = 30048,
+    BitrateTooHigh = 30052,
+    MaxPremiumEmojis = 30056,
+    MaxGuildWebhooks = 30058,
+    Unauthorized = 40001,
+    VerifyAccount = 40002,
+    OpeningDirectMessagesTooFast = 40003,
+    SendMessagesTemporarilyDisabled = 40004,
+    RequestEntityTooLarge = 40005,
+    FeatureTemporarilyDisabled = 40006,
+    UserBannedFromGuild = 40007,
+    ConnectionRevoked = 40012,
+    OnlyConsumableSkus = 40018,
+    OnlySandboxEntitlements = 40019,
+    TargetUserNotInVoice = 40032,
+    AlreadyCrossposted = 40033,
+    ApplicationCommandNameExists = 40041,
+    InteractionFailedToSend = 40043,
+    CannotSendInForumChannel = 40058,
+    InteractionAlreadyAcknowledged = 40060,
+    TagNamesMustBeUnique = 40061,
+    ServiceResourceRateLimited = 40062,
+    NoTagsForNonModerators = 40066,
+    TagRequired = 40067,
+    EntitlementAlreadyGranted = 40074,
+    MaxFollowUpMessages = 40094,
+    CloudflareBlocked = 40333,
+    MissingAccess = 50001,
+    InvalidAccountType = 50002,
+    CannotExecuteOnDm = 50003,
+    GuildWidgetDisabled = 50004,
+    CannotEditOtherUsersMessage = 50005,
+    CannotSendEmptyMessage = 50006,
+    CannotSendMessagesToUser = 50007,
+    CannotSendMessagesInNonTextChannel = 50008,
+    ChannelVerificationTooHigh = 50009,
+    OAuth2ApplicationHasNoBot = 50010,
+    OAuth2ApplicationLimit = 50011,
+    InvalidOAuth2State = 50012,
+    MissingPermissions = 50013,
+    InvalidAuthenticationToken = 50014,
+    NoteTooLong = 50015,
+    BulkDeleteCountOutOfRange = 50016,
+    InvalidMfaLevel = 50017,
+    MessagePinnedInOtherChannel = 50019,
+    InvalidInviteCode = 50020,
+    CannotExecuteOnSystemMessage = 50021,
+    CannotExecuteOnChannelType = 50024,
+    InvalidOAuth2AccessToken = 50025,
+    MissingOAuth2Scope = 50026,
+    InvalidWebhookToken = 50027,
+    InvalidRole = 50028,
+    InvalidRecipients = 50033,
+    MessageTooOldToBulkDelete = 50034,
+    InvalidFormBody = 50035,
+    InviteAcceptedWithoutBot = 50036,
+    InvalidActivityAction = 50039,
+    InvalidApiVersion = 50041,
+    FileTooLarge = 50045,
+    InvalidFileUploaded = 50046,
+    CannotSelfRedeemGift = 50054,
+    InvalidGuild = 50055,
+    InvalidSku = 50057,
+    InvalidRequestOrigin = 50067,
+    InvalidMessageType = 50068,
+    PaymentSourceRequired = 50070,
+    CannotModifySystemWebhook = 50073,
+    CannotDeleteRequiredCommunityChannel = 50074,
+    CannotEditMessageStickers = 50080,
+    InvalidStickerSent = 50081,
+    ThreadArchived = 50083,
+    InvalidThreadNotificationSettings = 50084,
+    BeforeEarlierThanThreadCreation = 50085,
+    CommunityChannelsMustBeText = 50086,
+    EntityTypeMismatch = 50091,
+    ServerNotAvailableInLocation = 50095,
+    MonetizationRequired = 50097,
+    NotEnoughBoosts = 50101,
+    InvalidJson = 50109,
+    InvalidFileProvided = 50110,
+    InvalidFileType = 50123,
+    FileDurationTooLong = 50124,
+    OwnerCannotBePendingMember = 50131,
+    CannotTransferOwnershipToBot = 50132,
+    FailedToResizeAsset = 50138,
+    CannotMixSubscriptionRoles = 50144,
+    CannotConvertPremiumEmoji = 50145,
+    UploadedFileNotFound = 50146,
+    InvalidEmoji = 50151,
+    VoiceMessagesNoAdditionalContent = 50159,
+    VoiceMessagesSingleAudioAttachment = 50160,
+    VoiceMessagesRequireMetadata = 50161,
+    VoiceMessagesCannotBeEdited = 50162,
+    CannotDeleteGuildSubscriptionIntegration = 50163,
+    CannotSendVoiceMessagesInChannel = 50173,
+    UserAccountMustBeVerified = 50178,
+    NoPermissionToSendSticker = 50600,
+    TwoFactorRequired = 60003,
+    NoUsersWithDiscordTag = 80004,
+    ReactionBlocked = 90001,
+    CannotUseBurstReactions = 90002,
+    ApplicationNotYetAvailable = 110001,
+    ApiResourceOverloaded = 130000,
+    StageAlreadyOpen = 150006,
+    CannotReplyWithoutReadHistory = 160002,
+    ThreadAlreadyCreated = 160004,
+    ThreadLocked = 160005,
+    MaxActiveThreads = 160006,
+    MaxActiveAnnouncementThreads = 160007,
+    InvalidLottieJson = 170001,
+    LottieRasterizedImages = 170002,
+    StickerMaxFramerateExceeded = 170003,
+    StickerFrameCountExceeded = 170004,
+    LottieDimensionsTooLarge = 170005,
+    StickerFramerateOutOfRange = 170006,
+    StickerAnimationTooLong = 170007,
+    CannotUpdateFinishedEvent = 180000,
+    FailedToCreateStageForEvent = 180002,
+    AutoModerationBlocked = 200000,
+    AutoModerationTitleBlocked = 200001,
+    ForumWebhookNeedsThread = 220001,
+    ForumWebhookThreadNameOrId = 220002,
+    WebhookThreadsOnlyInForum = 220003,
+    WebhookServicesNotInForum = 220004,
+    HarmfulLinks = 240000,
+    CannotEnableOnboarding = 340002,
+    OnboardingRequirementsNotMet = 340004,
+    OnboardingUpdateRequirementsNotMet = 350000,
+    FailedToBanUsers = 500000,
+    PollVotingBlocked = 520000,
+    PollExpired = 520001,
+    InvalidChannelTypeForPoll = 520002,
+    CannotEditPollMessage = 520003,
+    CannotUseEmojiInPoll = 520004,
+    CannotExpireNonPoll = 520006,
+}
+
+impl fmt::Display for JsonErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonErrorCode::Other(code) => write!(f, "{code}"),
+            known => write!(f, "{known:?} ({})", known.code()),
+        }
+    }
+}
+
+/// One validation failure of a request body field.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct FieldError {
+    /// Where in the body the field is, like `embeds[0].fields[2].name`.
+    #[serde(skip)]
+    pub path: String,
+    pub code: String,
+    pub message: String,
+}
+
+/// An error body Discord sent with a failed request.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("Discord responded with {status}: {message} [{code}]")]
+pub struct DiscordApiError {
+    pub status: StatusCode,
+    pub code: JsonErrorCode,
+    pub message: String,
+    /// Field errors of an `InvalidFormBody` response, ordered by field name with
+    /// list indices in numeric order.
+    pub errors: Vec<FieldError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorBody {
+    code: u32,
+    message: String,
+    #[serde(default)]
+    errors: Value,
+}
+
+/// Collects `_errors` lists from Discord's nested error object, which mirrors the
+/// shape of the request body.
+fn flatten(errors: &Value, path: &str, flat: &mut Vec<FieldError>) {
+    let Some(object) = errors.as_object() else { return };
+    // Object keys come back sorted as strings, which puts "10" before "2".
+    let mut entries: Vec<_> = object.iter().collect();
+    entries.sort_by(|(a, _), (b, _)| match (a.parse::<u64>(), b.parse::<u64>()) {
+        (Ok(a), Ok(b)) => a.cmp(&b),
+        _ => a.cmp(b),
+    });
+    for (key, value) in entries {
+        if key == "_errors" {
+            let Some(list) = value.as_array() else { continue };
+            for error in list {
+                if let Ok(mut error) = serde_json::from_value::<FieldError>(error.clone()) {
+                    error.path = path.to_string();
+                    flat.push(error);
+                }
+            }
+        } else if key.bytes().all(|b| b.is_ascii_digit()) {
+            flatten(value, &format!("{path}[{key}]"), flat);
+        } else if path.is_empty() {
+            flatten(value, key, flat);
+        } else {
+            flatten(value, &format!("{path}.{key}"), flat);
+        }
+    }
+}
+
+impl DiscordApiError {
+    /// Decodes an error response, or `None` if the body is not Discord's error JSON.
+    pub fn parse(status: StatusCode, body: &str) -> Option<Self> {
+        let body: ErrorBody = serde_json::from_str(body).ok()?;
+        let mut errors = Vec::new();
+        flatten(&body.errors, "", &mut errors);
+        Some(DiscordApiError {
+            status,
+            code: JsonErrorCode::from_code(body.code),
+            message: body.message,
+            errors,
+        })
+    }
+
+    pub fn field(&self, path: &str) -> Option<&FieldError> {
+        self.errors.iter().find(|error| error.path == path)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_nested_field_errors() {
+        let body = r#"{
+            "code": 50035,
+            "message": "Invalid Form Body",
+            "errors": {
+                "embeds": {
+                    "0": {
+                        "title": { "_errors": [{ "code": "BASE_TYPE_MAX_LENGTH", "message": "Must be 256 or fewer in length." }] },
+                        "fields": {
+                            "2": { "name": { "_errors": [{ "code": "BASE_TYPE_REQUIRED", "message": "This field is required" }] } },
+                            "10": { "value": { "_errors": [{ "code": "BASE_TYPE_REQUIRED", "message": "This field is required" }] } }
+                        }
+                    }
+                },
+                "content": { "_errors": [{ "code": "BASE_TYPE_MAX_LENGTH", "message": "Must be 2000 or fewer in length." }] }
+            }
+        }"#;
+        let error = DiscordApiError::parse(StatusCode::BAD_REQUEST, body).unwrap();
+
+        assert_eq!(error.code, JsonErrorCode::InvalidFormBody);
+        let paths: Vec<_> = error.errors.iter().map(|error| error.path.as_str()).collect();
+        assert_eq!(paths, vec!["content", "embeds[0].fields[2].name", "embeds[0].fields[10].value", "embeds[0].title"]);
+        assert_eq!(error.field("embeds[0].fields[2].name").unwrap().code, "BASE_TYPE_REQUIRED");
+        assert!(error.field("content").is_some());
+        assert!(DiscordApiError::parse(StatusCode::BAD_GATEWAY, "<html>").is_none());
+    }
+
+    #[test]
+    fn error_codes_round_trip() {
+        assert_eq!(JsonErrorCode::from_code(10003), JsonErrorCode::UnknownChannel);
+        assert_eq!(JsonErrorCode::MissingPermissions.code(), 50013);
+        assert_eq!(JsonErrorCode::from_code(999_999), JsonErrorCode::Other(999_999));
+        assert_eq!(JsonErrorCode::UnknownMember.to_string(), "UnknownMember (10007)");
+    }
+}
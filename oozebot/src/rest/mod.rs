@@ -0,0 +1,336 @@
+use std::sync::Arc;
+
+use openapi::apis::configuration::{ApiKey, Configuration};
+use reqwest::{header::HeaderMap, Method, StatusCode};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use thiserror::Error;
+use tokio::time::Duration;
+
+use crate::rest::{
+    attachments::Attachment,
+    errors::{DiscordApiError, JsonErrorCode},
+    ratelimit::{route_key, RateLimiter},
+};
+
+pub mod api;
+pub mod attachments;
+pub mod errors;
+pub mod pagination;
+pub mod ratelimit;
+
+
+const DISCORD_API_URL: &str = "https://discord.com/api/v10";
+const MAX_RETRIES: usize = 5;
+
+#[derive(Debug, Error)]
+pub enum RestError {
+    #[error("HTTP error: {0}")]
+    Http(#[from] reqwest::Error),
+
+    #[error("Could not decode response: {0}")]
+    Decode(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    Api(#[from] DiscordApiError),
+
+    /// An error response without Discord's error JSON, like a gateway timeout page.
+    #[error("Discord responded with {status}: {body}")]
+    Response { status: StatusCode, body: String },
+
+    #[error("Still rate limited after {0} retries")]
+    RateLimited(usize),
+
+    #[error("Request body was streamed already and cannot be retried")]
+    BodyConsumed,
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+impl RestError {
+    fn from_response(status: StatusCode, body: String) -> Self {
+        match DiscordApiError::parse(status, &body) {
+            Some(error) => RestError::Api(error),
+            None => RestError::Response { status, body },
+        }
+    }
+
+    /// Discord's JSON error code, if the request failed with one.
+    pub fn code(&self) -> Option<JsonErrorCode> {
+        match self {
+            RestError::Api(error) => Some(error.code),
+            _ => None,
+        }
+    }
+
+    pub fn is(&self, code: JsonErrorCode) -> bool {
+        self.code() == Some(code)
+    }
+}
+
+impl<T> From<openapi::apis::Error<T>> for RestError {
+    fn from(value: openapi::apis::Error<T>) -> Self {
+        match value {
+            openapi::apis::Error::Reqwest(e) => RestError::Http(e),
+            openapi::apis::Error::Serde(e) => RestError::Decode(e),
+            openapi::apis::Error::Io(e) => RestError::Io(e),
+            openapi::apis::Error::ResponseError(content) => RestError::from_response(content.status, content.content),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct RateLimitedBody {
+    retry_after: f64,
+    #[serde(default)]
+    global: bool,
+}
+
+impl RateLimitedBody {
+    /// Reads a 429. Limits applied before Discord, e.g. by Cloudflare, answer with
+    /// HTML, so the headers are used when the body is not JSON.
+    fn parse(body: &str, headers: &HeaderMap) -> Self {
+        if let Ok(limited) = serde_json::from_str(body) {
+            return limited
+        }
+        let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+        RateLimitedBody {
+            retry_after: header("retry-after")
+                .or_else(|| header("x-ratelimit-reset-after"))
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(1.0),
+            global: header("x-ratelimit-global").is_some_and(|value| value == "true"),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Body {
+    Json(serde_json::Value),
+    Multipart {
+        fields: Vec<(String, String)>,
+        files: Vec<(String, Attachment)>,
+    },
+}
+
+/// A single REST call. Paths are relative to the API base url, e.g. `/guilds/{id}`.
+#[derive(Debug)]
+pub struct Request {
+    method: Method,
+    path: String,
+    query: Vec<(String, String)>,
+    body: Option<Body>,
+    reason: Option<String>,
+    bearer: Option<String>,
+}
+
+impl Request {
+    pub fn new(method: Method, path: impl Into<String>) -> Self {
+        Request {
+            method,
+            path: path.into(),
+            query: Vec::new(),
+            body: None,
+            reason: None,
+            bearer: None,
+        }
+    }
+
+    pub fn json<B: Serialize + ?Sized>(mut self, body: &B) -> Result<Self, RestError> {
+        self.body = Some(Body::Json(serde_json::to_value(body)?));
+        Ok(self)
+    }
+
+    pub fn query(mut self, key: &str, value: impl ToString) -> Self {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Adds a text field, switching the body to `multipart/form-data`.
+    pub fn field(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.multipart().0.push((name.to_string(), value.into()));
+        self
+    }
+
+    /// Sends `body` as the `payload_json` field of a multipart request.
+    pub fn payload_json<B: Serialize + ?Sized>(self, body: &B) -> Result<Self, RestError> {
+        let payload = serde_json::to_string(body)?;
+        Ok(self.field("payload_json", payload))
+    }
+
+    /// Adds a file part, switching the body to `multipart/form-data`.
+    pub fn file(mut self, name: &str, attachment: Attachment) -> Self {
+        self.multipart().1.push((name.to_string(), attachment));
+        self
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn multipart(&mut self) -> (&mut Vec<(String, String)>, &mut Vec<(String, Attachment)>) {
+        if !matches!(self.body, Some(Body::Multipart { .. })) {
+            self.body = Some(Body::Multipart { fields: Vec::new(), files: Vec::new() });
+        }
+        match &mut self.body {
+            Some(Body::Multipart { fields, files }) => (fields, files),
+            _ => unreachable!("Body was just set to multipart"),
+        }
+    }
+
+    /// Authenticates as a user with an OAuth2 access token instead of the bot token.
+    pub fn bearer(mut self, access_token: &str) -> Self {
+        self.bearer = Some(access_token.to_string());
+        self
+    }
+
+    /// Sets the `X-Audit-Log-Reason` header shown in the guild's audit log.
+    pub fn reason(mut self, reason: Option<&str>) -> Self {
+        self.reason = reason.map(str::to_string);
+        self
+    }
+}
+
+/// Bot-authenticated client for Discord's HTTP API. Clones share rate limit buckets.
+#[derive(Debug, Clone)]
+pub struct RestClient {
+    http: reqwest::Client,
+    base_url: String,
+    token: String,
+    ratelimiter: Arc<RateLimiter>,
+}
+
+impl RestClient {
+    pub fn new(token: impl Into<String>) -> Self {
+        RestClient {
+            http: reqwest::Client::new(),
+            base_url: DISCORD_API_URL.to_string(),
+            token: token.into(),
+            ratelimiter: Arc::new(RateLimiter::new()),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// A client sharing this one's rate limit buckets that sends no `Authorization`
+    /// header, for routes authenticated by a token in the path such as webhooks.
+    pub fn without_token(&self) -> Self {
+        RestClient {
+            token: String::new(),
+            ..self.clone()
+        }
+    }
+
+    pub fn ratelimiter(&self) -> &Arc<RateLimiter> {
+        &self.ratelimiter
+    }
+
+    /// Configuration for calling the generated `openapi` functions directly.
+    /// These calls bypass the shared rate limit buckets.
+    pub fn configuration(&self) -> Configuration {
+        Configuration {
+            base_path: self.base_url.clone(),
+            client: self.http.clone(),
+            api_key: Some(ApiKey {
+                prefix: Some("Bot".to_string()),
+                key: self.token.clone(),
+            }),
+            ..Configuration::default()
+        }
+    }
+
+    /// Sends `request`, waiting on and updating its rate limit bucket.
+    /// Empty responses decode as `()`.
+    /// Streamed attachments cannot be resent, so a rate limited upload of one fails
+    /// with `RestError::BodyConsumed`.
+    pub async fn send<T: DeserializeOwned>(&self, mut request: Request) -> Result<T, RestError> {
+        let route = route_key(&request.method, &request.path);
+
+        for _ in 0..MAX_RETRIES {
+            self.ratelimiter.acquire(&route).await;
+
+            let mut builder = self.http
+                .request(request.method.clone(), format!("{}{}", self.base_url, request.path))
+                .query(&request.query);
+            if let Some(access_token) = &request.bearer {
+                builder = builder.bearer_auth(access_token);
+            } else if !self.token.is_empty() {
+                builder = builder.header(reqwest::header::AUTHORIZATION, format!("Bot {}", self.token));
+            }
+            match &mut request.body {
+                Some(Body::Json(body)) => builder = builder.json(body),
+                Some(Body::Multipart { fields, files }) => {
+                    let mut form = reqwest::multipart::Form::new();
+                    for (name, value) in fields.iter() {
+                        let mut part = reqwest::multipart::Part::text(value.clone());
+                        if name == "payload_json" {
+                            part = part.mime_str("application/json")?;
+                        }
+                        form = form.part(name.clone(), part);
+                    }
+                    for (name, attachment) in files.iter_mut() {
+                        form = form.part(name.clone(), attachment.take_part()?);
+                    }
+                    builder = builder.multipart(form);
+                }
+                None => {}
+            }
+            if let Some(reason) = &request.reason {
+                builder = builder.header("X-Audit-Log-Reason", encode_reason(reason));
+            }
+
+            let response = builder.send().await?;
+            self.ratelimiter.update(&route, response.headers());
+
+            let status = response.status();
+            let headers = response.headers().clone();
+            let body = response.text().await?;
+
+            if status == StatusCode::TOO_MANY_REQUESTS {
+                let limited = RateLimitedBody::parse(&body, &headers);
+                self.ratelimiter.limited(&route, Duration::from_secs_f64(limited.retry_after), limited.global);
+                continue
+            }
+
+            if !status.is_success() {
+                return Err(RestError::from_response(status, body))
+            }
+
+            let body = if body.is_empty() { "null" } else { body.as_str() };
+            return Ok(serde_json::from_str(body)?)
+        }
+
+        Err(RestError::RateLimited(MAX_RETRIES))
+    }
+}
+
+/// Audit log reasons are sent as a header and must be percent-encoded.
+fn encode_reason(reason: &str) -> String {
+    reason
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::{HeaderMap, HeaderValue};
+
+    use super::RateLimitedBody;
+
+    #[test]
+    fn rate_limit_falls_back_to_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("retry-after", HeaderValue::from_static("3"));
+
+        let json = RateLimitedBody::parse(r#"{"retry_after": 0.5, "global": true}"#, &headers);
+        assert_eq!(json, RateLimitedBody { retry_after: 0.5, global: true });
+
+        let html = RateLimitedBody::parse("<html>Too Many Requests</html>", &headers);
+        assert_eq!(html, RateLimitedBody { retry_after: 3.0, global: false });
+    }
+}
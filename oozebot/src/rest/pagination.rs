@@ -0,0 +1,271 @@
+use std::{cmp::Ordering, collections::VecDeque};
+
+use futures::{future::BoxFuture, stream, Stream};
+use openapi::models::{AuditLogEntryResponse, GuildBanResponse, GuildMemberResponse, MessageResponse};
+
+use crate::rest::{RestClient, RestError};
+
+
+/// Where a listing starts and which way it walks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cursor {
+    /// Items older than the id, newest first. `None` starts at the newest item.
+    Before(Option<String>),
+    /// Items newer than the id, oldest first. `"0"` starts at the oldest item.
+    After(String),
+}
+
+impl Cursor {
+    pub fn oldest() -> Self {
+        Cursor::After("0".to_string())
+    }
+
+    pub fn newest() -> Self {
+        Cursor::Before(None)
+    }
+
+    /// Value of the `before` query parameter.
+    pub fn before(&self) -> Option<&str> {
+        match self {
+            Cursor::Before(id) => id.as_deref(),
+            Cursor::After(_) => None,
+        }
+    }
+
+    /// Value of the `after` query parameter.
+    pub fn after(&self) -> Option<&str> {
+        match self {
+            Cursor::After(id) => Some(id),
+            Cursor::Before(_) => None,
+        }
+    }
+}
+
+/// Snowflakes compared as numbers without parsing them.
+fn compare_ids(a: &str, b: &str) -> Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+type FetchPage<'a, T> = Box<dyn FnMut(Cursor, u32) -> BoxFuture<'a, Result<Vec<T>, RestError>> + Send + 'a>;
+
+/// Walks a cursor based list endpoint page by page. Each page is one request through
+/// the `RestClient`, so paging waits on the endpoint's rate limit bucket like any call.
+pub struct Paginator<'a, T> {
+    fetch: FetchPage<'a, T>,
+    id: fn(&T) -> &str,
+    cursor: Cursor,
+    page_size: u32,
+    max: Option<usize>,
+}
+
+impl<'a, T: Send + 'a> Paginator<'a, T> {
+    /// `fetch` requests up to `page_size` items past the cursor, `id` reads the
+    /// snowflake the endpoint pages by.
+    pub fn new<F>(cursor: Cursor, page_size: u32, id: fn(&T) -> &str, fetch: F) -> Self
+    where
+        F: FnMut(Cursor, u32) -> BoxFuture<'a, Result<Vec<T>, RestError>> + Send + 'a,
+    {
+        Paginator {
+            fetch: Box::new(fetch),
+            id,
+            cursor,
+            page_size,
+            max: None,
+        }
+    }
+
+    /// Stops after `max` items.
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    fn next_page_size(&self, yielded: usize) -> u32 {
+        match self.max {
+            Some(max) => self.page_size.min((max - yielded) as u32),
+            None => self.page_size,
+        }
+    }
+
+    /// Sorts a page into walking order and moves the cursor past it. Returns whether
+    /// the listing may have more items.
+    fn advance(&mut self, page: &mut [T], requested: u32) -> bool {
+        let id = self.id;
+        match &self.cursor {
+            Cursor::Before(_) => page.sort_by(|a, b| compare_ids(id(b), id(a))),
+            Cursor::After(_) => page.sort_by(|a, b| compare_ids(id(a), id(b))),
+        }
+        let Some(last) = page.last() else { return false };
+        self.cursor = match self.cursor {
+            Cursor::Before(_) => Cursor::Before(Some(id(last).to_string())),
+            Cursor::After(_) => Cursor::After(id(last).to_string()),
+        };
+        page.len() as u32 >= requested
+    }
+
+    /// Items one at a time. A failed request ends the stream after yielding its error.
+    pub fn into_stream(self) -> impl Stream<Item = Result<T, RestError>> + 'a {
+        struct State<'a, T> {
+            paginator: Paginator<'a, T>,
+            buffer: VecDeque<T>,
+            yielded: usize,
+            more: bool,
+        }
+
+        let state = State { paginator: self, buffer: VecDeque::new(), yielded: 0, more: true };
+        stream::unfold(state, |mut state| async move {
+            if state.paginator.max.is_some_and(|max| state.yielded >= max) {
+                return None
+            }
+            if state.buffer.is_empty() && state.more {
+                let requested = state.paginator.next_page_size(state.yielded);
+                let cursor = state.paginator.cursor.clone();
+                match (state.paginator.fetch)(cursor, requested).await {
+                    Ok(mut page) => {
+                        state.more = state.paginator.advance(&mut page, requested);
+                        state.buffer.extend(page);
+                    }
+                    Err(e) => {
+                        state.more = false;
+                        return Some((Err(e), state))
+                    }
+                }
+            }
+
+            let item = state.buffer.pop_front()?;
+            state.yielded += 1;
+            Some((Ok(item), state))
+        })
+    }
+}
+
+impl RestClient {
+    /// Messages of a channel, 100 per request.
+    pub fn paginate_messages<'a>(&'a self, channel_id: &str, cursor: Cursor) -> Paginator<'a, MessageResponse> {
+        let channel_id = channel_id.to_string();
+        Paginator::new(cursor, 100, |message: &MessageResponse| &message.id, move |cursor, limit| {
+            let channel_id = channel_id.clone();
+            Box::pin(async move {
+                self.api().list_messages(&channel_id, None, cursor.before(), cursor.after(), Some(limit.into())).await
+            })
+        })
+    }
+
+    /// Members of a guild by user id, oldest first, 1000 per request. Discord only
+    /// pages members forward, so `after` is a user id to start past.
+    pub fn paginate_members<'a>(&'a self, guild_id: &str, after: Option<&str>) -> Paginator<'a, GuildMemberResponse> {
+        let guild_id = guild_id.to_string();
+        let cursor = Cursor::After(after.unwrap_or("0").to_string());
+        Paginator::new(cursor, 1000, |member: &GuildMemberResponse| &member.user.id, move |cursor, limit| {
+            let guild_id = guild_id.clone();
+            Box::pin(async move {
+                let after = cursor.after().and_then(|id| id.parse().ok());
+                self.api().list_guild_members(&guild_id, Some(limit.into()), after).await
+            })
+        })
+    }
+
+    /// Bans of a guild by user id, 1000 per request.
+    pub fn paginate_bans<'a>(&'a self, guild_id: &str, cursor: Cursor) -> Paginator<'a, GuildBanResponse> {
+        let guild_id = guild_id.to_string();
+        Paginator::new(cursor, 1000, |ban: &GuildBanResponse| &ban.user.id, move |cursor, limit| {
+            let guild_id = guild_id.clone();
+            Box::pin(async move {
+                self.api().list_guild_bans(&guild_id, Some(limit.into()), cursor.before(), cursor.after()).await
+            })
+        })
+    }
+
+    /// Audit log entries of a guild, 100 per request, optionally only those by
+    /// `user_id` or of `action_type`.
+    pub fn paginate_audit_log<'a>(
+        &'a self,
+        guild_id: &str,
+        cursor: Cursor,
+        user_id: Option<&str>,
+        action_type: Option<i32>,
+    ) -> Paginator<'a, AuditLogEntryResponse> {
+        let guild_id = guild_id.to_string();
+        let user_id = user_id.map(str::to_string);
+        let action_type = action_type.map(|action| action.to_string());
+        Paginator::new(cursor, 100, |entry: &AuditLogEntryResponse| &entry.id, move |cursor, limit| {
+            let (guild_id, user_id, action_type) = (guild_id.clone(), user_id.clone(), action_type.clone());
+            Box::pin(async move {
+                let log = self
+                    .api()
+                    .list_guild_audit_log_entries(
+                        &guild_id,
+                        user_id.as_deref(),
+                        None,
+                        action_type.as_deref(),
+                        cursor.before(),
+                        cursor.after(),
+                        Some(limit.into()),
+                    )
+                    .await?;
+                Ok(log.audit_log_entries)
+            })
+        })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use futures::StreamExt;
+
+    use super::*;
+
+    /// Ids 1..=250, served the way Discord does: newest first whatever the direction.
+    fn fake(requests: Arc<Mutex<Vec<(Cursor, u32)>>>) -> impl FnMut(Cursor, u32) -> BoxFuture<'static, Result<Vec<String>, RestError>> + Send {
+        move |cursor, limit| {
+            requests.lock().unwrap().push((cursor.clone(), limit));
+            let ids = (1..=250u64).rev();
+            let mut page: Vec<String> = match &cursor {
+                Cursor::Before(before) => {
+                    let before = before.as_deref().map_or(u64::MAX, |id| id.parse().unwrap());
+                    ids.filter(|&id| id < before).take(limit as usize).map(|id| id.to_string()).collect()
+                }
+                Cursor::After(after) => {
+                    let after: u64 = after.parse().unwrap();
+                    let mut page: Vec<String> = ids.rev().filter(|&id| id > after).take(limit as usize).map(|id| id.to_string()).collect();
+                    page.reverse();
+                    page
+                }
+            };
+            page.truncate(limit as usize);
+            Box::pin(async move { Ok(page) })
+        }
+    }
+
+    #[tokio::test]
+    async fn walks_pages_in_both_directions() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let newest: Vec<String> = Paginator::new(Cursor::newest(), 100, String::as_str, fake(requests.clone()))
+            .into_stream()
+            .map(Result::unwrap)
+            .collect()
+            .await;
+        assert_eq!(newest.len(), 250);
+        assert_eq!((newest[0].as_str(), newest[249].as_str()), ("250", "1"));
+        assert_eq!(requests.lock().unwrap()[2], (Cursor::Before(Some("51".to_string())), 100));
+
+        requests.lock().unwrap().clear();
+        let oldest: Vec<String> = Paginator::new(Cursor::oldest(), 100, String::as_str, fake(requests.clone()))
+            .max(120)
+            .into_stream()
+            .map(Result::unwrap)
+            .collect()
+            .await;
+        assert_eq!(oldest.len(), 120);
+        assert_eq!((oldest[0].as_str(), oldest[119].as_str()), ("1", "120"));
+        assert_eq!(*requests.lock().unwrap(), vec![(Cursor::oldest(), 100), (Cursor::After("100".to_string()), 20)]);
+    }
+}
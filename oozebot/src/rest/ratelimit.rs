@@ -0,0 +1,206 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use reqwest::{header::HeaderMap, Method};
+use tokio::time::{sleep_until, Duration, Instant};
+
+
+/// Path segments whose following id is a "major parameter" and therefore part of the bucket.
+const MAJOR_PARAMETERS: [&str; 3] = ["channels", "guilds", "webhooks"];
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    remaining: u64,
+    reset_at: Instant,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    bucket_ids: HashMap<String, String>,
+    buckets: HashMap<String, Bucket>,
+    global_reset: Option<Instant>,
+}
+
+/// Shared bucket bookkeeping for every request made through a `RestClient`.
+///
+/// Routes start out unlimited and learn their bucket from the `X-RateLimit-*`
+/// headers of the first response.
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    state: Mutex<State>,
+}
+
+/// Groups a request path into the route Discord rate limits on: ids are replaced
+/// unless they directly follow a major parameter.
+pub fn route_key(method: &Method, path: &str) -> String {
+    let path = path.split('?').next().unwrap_or_default();
+    let mut previous = "";
+    let segments: Vec<&str> = path
+        .split('/')
+        .map(|segment| {
+            let is_id = !segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit());
+            let keep = !is_id || MAJOR_PARAMETERS.contains(&previous);
+            previous = segment;
+            if keep { segment } else { ":id" }
+        })
+        .collect();
+
+    format!("{} {}", method, segments.join("/"))
+}
+
+/// The bucket of `route`: Discord shares a hash between routes, but limits each
+/// major parameter separately.
+fn bucket_key(hash: &str, route: &str) -> String {
+    let major = route
+        .split('/')
+        .find(|segment| !segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit()))
+        .unwrap_or_default();
+    format!("{hash}:{major}")
+}
+
+impl RateLimiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Waits until a request on `route` may be sent and reserves a slot for it.
+    pub async fn acquire(&self, route: &str) {
+        loop {
+            let wait_until = {
+                let mut state = self.state.lock().expect("Rate limiter lock poisoned");
+                let now = Instant::now();
+
+                match state.global_reset {
+                    Some(reset) if reset > now => Some(reset),
+                    _ => {
+                        state.global_reset = None;
+                        let bucket_id = state.bucket_ids.get(route).cloned();
+                        match bucket_id.and_then(|id| state.buckets.get_mut(&id)) {
+                            Some(bucket) if bucket.reset_at <= now => {
+                                // The window passed; let this request through and
+                                // wait for its headers to refresh the bucket.
+                                bucket.remaining = 0;
+                                bucket.reset_at = now + Duration::from_secs(1);
+                                None
+                            }
+                            Some(bucket) if bucket.remaining == 0 => Some(bucket.reset_at),
+                            Some(bucket) => {
+                                bucket.remaining -= 1;
+                                None
+                            }
+                            None => None,
+                        }
+                    }
+                }
+            };
+
+            match wait_until {
+                Some(when) => sleep_until(when).await,
+                None => return,
+            }
+        }
+    }
+
+    /// Records the rate limit headers returned for a request on `route`.
+    pub fn update(&self, route: &str, headers: &HeaderMap) {
+        let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+
+        let Some(bucket_id) = header("x-ratelimit-bucket").map(|hash| bucket_key(hash, route)) else {
+            return
+        };
+        let remaining = header("x-ratelimit-remaining").and_then(|v| v.parse::<u64>().ok());
+        let reset_after = header("x-ratelimit-reset-after").and_then(|v| v.parse::<f64>().ok());
+
+        let mut state = self.state.lock().expect("Rate limiter lock poisoned");
+        state.bucket_ids.insert(route.to_string(), bucket_id.clone());
+
+        if let (Some(remaining), Some(reset_after)) = (remaining, reset_after) {
+            state.buckets.insert(bucket_id, Bucket {
+                remaining,
+                reset_at: Instant::now() + Duration::from_secs_f64(reset_after),
+            });
+        }
+    }
+
+    /// Blocks `route` (or every route if `global`) for `retry_after` after a 429.
+    pub fn limited(&self, route: &str, retry_after: Duration, global: bool) {
+        let mut state = self.state.lock().expect("Rate limiter lock poisoned");
+        let reset_at = Instant::now() + retry_after;
+
+        if global {
+            state.global_reset = Some(reset_at);
+            return
+        }
+
+        let bucket_id = state.bucket_ids
+            .entry(route.to_string())
+            .or_insert_with(|| route.to_string())
+            .clone();
+        state.buckets.insert(bucket_id, Bucket { remaining: 0, reset_at });
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use reqwest::{header::{HeaderMap, HeaderValue}, Method};
+    use tokio::time::{Duration, Instant};
+
+    use super::{route_key, RateLimiter};
+
+    #[test]
+    fn route_key_keeps_major_parameters() {
+        assert_eq!(
+            route_key(&Method::DELETE, "/channels/123/messages/456"),
+            "DELETE /channels/123/messages/:id",
+        );
+        assert_eq!(
+            route_key(&Method::PUT, "/guilds/1/bans/2?reason=x"),
+            "PUT /guilds/1/bans/:id",
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn acquire_waits_for_exhausted_bucket() {
+        let limiter = RateLimiter::new();
+        let route = route_key(&Method::POST, "/channels/1/messages");
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-bucket", HeaderValue::from_static("abc"));
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static("1"));
+        headers.insert("x-ratelimit-reset-after", HeaderValue::from_static("2.5"));
+        limiter.update(&route, &headers);
+
+        let start = Instant::now();
+        limiter.acquire(&route).await;
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        limiter.acquire(&route).await;
+        assert_eq!(start.elapsed(), Duration::from_millis(2500));
+
+        limiter.limited(&route, Duration::from_secs(1), true);
+        limiter.acquire(&route_key(&Method::GET, "/guilds/1")).await;
+        assert_eq!(start.elapsed(), Duration::from_millis(3500));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn major_parameters_split_a_shared_bucket() {
+        let limiter = RateLimiter::new();
+        let first = route_key(&Method::POST, "/channels/1/messages");
+        let second = route_key(&Method::POST, "/channels/2/messages");
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-bucket", HeaderValue::from_static("abc"));
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static("0"));
+        headers.insert("x-ratelimit-reset-after", HeaderValue::from_static("5"));
+        limiter.update(&first, &headers);
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static("1"));
+        limiter.update(&second, &headers);
+
+        let start = Instant::now();
+        limiter.acquire(&second).await;
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        limiter.acquire(&first).await;
+        assert_eq!(start.elapsed(), Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,480 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use openapi::models::{
+    EntityMetadataExternal, ExternalScheduledEventCreateRequest, ExternalScheduledEventPatchRequestPartial,
+    ExternalScheduledEventResponse,
+};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use thiserror::Error;
+
+use crate::{
+    rest::{Request, RestClient, RestError},
+    youtube::{self, livestream::LiveBroadcast},
+};
+
+
+pub const STATUS_SCHEDULED: i32 = 1;
+pub const STATUS_ACTIVE: i32 = 2;
+pub const STATUS_COMPLETED: i32 = 3;
+pub const STATUS_CANCELED: i32 = 4;
+
+const ENTITY_TYPE_EXTERNAL: i32 = 3;
+const PRIVACY_LEVEL_GUILD_ONLY: i32 = 2;
+/// External events need an end time; YouTube rarely sets one.
+const DEFAULT_STREAM_LENGTH: Duration = Duration::hours(2);
+/// Discord rejects events that start in the past.
+const MIN_LEAD_TIME: Duration = Duration::minutes(1);
+
+#[derive(Debug, Error)]
+pub enum SyncError {
+    #[error(transparent)]
+    Rest(#[from] RestError),
+
+    #[error("YouTube request failed: {0}")]
+    YouTube(#[from] reqwest::Error),
+
+    #[error("Could not persist sync state: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Could not (de)serialize sync state: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// A Discord event mirroring one broadcast, as last written to Discord.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncedEvent {
+    pub event_id: String,
+    pub status: i32,
+    pub name: String,
+    pub scheduled_start_time: String,
+    pub scheduled_end_time: String,
+}
+
+/// Broadcast id to synced event, saved as JSON after every change so a restart
+/// picks up where it left off.
+#[derive(Debug, Default)]
+pub struct EventStore {
+    path: Option<PathBuf>,
+    events: HashMap<String, SyncedEvent>,
+}
+
+impl EventStore {
+    pub fn in_memory() -> Self {
+        Self::default()
+    }
+
+    /// Loads the store at `path`, starting empty if the file does not exist yet.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, SyncError> {
+        let path = path.into();
+        let events = match std::fs::read_to_string(&path) {
+            Ok(json) => serde_json::from_str(&json)?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => HashMap::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(EventStore { path: Some(path), events })
+    }
+
+    pub fn get(&self, broadcast_id: &str) -> Option<&SyncedEvent> {
+        self.events.get(broadcast_id)
+    }
+
+    fn insert(&mut self, broadcast_id: String, event: SyncedEvent) -> Result<(), SyncError> {
+        self.events.insert(broadcast_id, event);
+        self.save()
+    }
+
+    /// Writes to a temporary file first so a crash never leaves a truncated store.
+    fn save(&self) -> Result<(), SyncError> {
+        let Some(path) = &self.path else {
+            return Ok(())
+        };
+        let temporary = path.with_extension("tmp");
+        std::fs::write(&temporary, serde_json::to_string_pretty(&self.events)?)?;
+        std::fs::rename(&temporary, path)?;
+        Ok(())
+    }
+}
+
+/// Where a broadcast is in its life, as far as the Discord event is concerned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    Upcoming,
+    Live,
+    Ended,
+}
+
+fn phase(broadcast: &LiveBroadcast) -> Phase {
+    match broadcast.status.lifeCycleStatus.as_str() {
+        "liveStarting" | "live" => Phase::Live,
+        "complete" | "revoked" => Phase::Ended,
+        _ => Phase::Upcoming,
+    }
+}
+
+fn format_time(time: DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn parse_time(time: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(time).ok().map(|time| time.with_timezone(&Utc))
+}
+
+fn watch_url(broadcast_id: &str) -> String {
+    format!("https://www.youtube.com/watch?v={broadcast_id}")
+}
+
+/// Start and end time for the event, moved into the future when the broadcast's
+/// start has already passed.
+fn event_times(broadcast: &LiveBroadcast, now: DateTime<Utc>) -> (String, String) {
+    let snippet = &broadcast.snippet;
+    let start = snippet.scheduledStartTime
+        .as_deref()
+        .and_then(parse_time)
+        .unwrap_or(now)
+        .max(now + MIN_LEAD_TIME);
+    let end = snippet.scheduledEndTime
+        .as_deref()
+        .and_then(parse_time)
+        .filter(|end| *end > start)
+        .unwrap_or(start + DEFAULT_STREAM_LENGTH);
+
+    (format_time(start), format_time(end))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Action {
+    Create { broadcast_id: String, name: String, start: String, end: String, description: String, go_live: bool },
+    /// Times are `None` when only the title changed.
+    Reschedule { broadcast_id: String, name: String, times: Option<(String, String)> },
+    Start { broadcast_id: String },
+    Complete { broadcast_id: String },
+    Cancel { broadcast_id: String },
+}
+
+/// Works out the Discord calls that bring the store in line with `broadcasts`.
+/// Events whose broadcast is missing from the list are treated as ended.
+fn plan(store: &EventStore, broadcasts: &[LiveBroadcast], now: DateTime<Utc>) -> Vec<Action> {
+    let mut actions = Vec::new();
+
+    for broadcast in broadcasts {
+        let broadcast_id = broadcast.id.clone();
+        let name = broadcast.snippet.title.chars().take(100).collect::<String>();
+        let phase = phase(broadcast);
+
+        match (store.get(&broadcast.id), phase) {
+            (None, Phase::Ended) => {}
+            (None, phase) => {
+                let (start, end) = event_times(broadcast, now);
+                let description = broadcast.snippet.description.chars().take(1000).collect();
+                actions.push(Action::Create { broadcast_id, name, start, end, description, go_live: phase == Phase::Live });
+            }
+            (Some(event), _) if event.status == STATUS_COMPLETED || event.status == STATUS_CANCELED => {}
+            (Some(event), Phase::Upcoming) => {
+                let scheduled = broadcast.snippet.scheduledStartTime.as_deref().and_then(parse_time);
+                let stored = parse_time(&event.scheduled_start_time);
+                // Only follow the broadcast while its start is in the future, since
+                // `event_times` pushes past starts forward on every poll.
+                let moved = scheduled.is_some_and(|start| start > now + MIN_LEAD_TIME && Some(start) != stored);
+                if moved || event.name != name {
+                    let times = moved.then(|| event_times(broadcast, now));
+                    actions.push(Action::Reschedule { broadcast_id, name, times });
+                }
+            }
+            (Some(event), Phase::Live) => {
+                if event.status == STATUS_SCHEDULED {
+                    actions.push(Action::Start { broadcast_id });
+                }
+            }
+            (Some(event), Phase::Ended) => actions.push(finish(broadcast_id, event)),
+        }
+    }
+
+    for (broadcast_id, event) in &store.events {
+        let open = event.status == STATUS_SCHEDULED || event.status == STATUS_ACTIVE;
+        if open && !broadcasts.iter().any(|broadcast| &broadcast.id == broadcast_id) {
+            actions.push(finish(broadcast_id.clone(), event));
+        }
+    }
+
+    actions
+}
+
+/// Discord only completes active events; scheduled ones that never went live are canceled.
+fn finish(broadcast_id: String, event: &SyncedEvent) -> Action {
+    if event.status == STATUS_ACTIVE {
+        Action::Complete { broadcast_id }
+    } else {
+        Action::Cancel { broadcast_id }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ExistingEvent {
+    id: String,
+    status: i32,
+    name: String,
+    scheduled_start_time: String,
+    scheduled_end_time: Option<String>,
+    entity_metadata: Option<ExistingMetadata>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExistingMetadata {
+    location: Option<String>,
+}
+
+/// Mirrors a YouTube channel's broadcasts as external guild scheduled events.
+#[derive(Debug)]
+pub struct ScheduledEventSync {
+    rest: RestClient,
+    guild_id: String,
+    store: EventStore,
+}
+
+impl ScheduledEventSync {
+    pub fn new(rest: RestClient, guild_id: impl Into<String>, store: EventStore) -> Self {
+        ScheduledEventSync {
+            rest,
+            guild_id: guild_id.into(),
+            store,
+        }
+    }
+
+    pub fn store(&self) -> &EventStore {
+        &self.store
+    }
+
+    /// Adopts open events in the guild that point at a broadcast but are missing from
+    /// the store, e.g. because the store file was lost.
+    pub async fn adopt_existing(&mut self) -> Result<(), SyncError> {
+        let request = Request::new(Method::GET, format!("/guilds/{}/scheduled-events", self.guild_id));
+        let events: Vec<ExistingEvent> = self.rest.send(request).await?;
+
+        for event in events {
+            let Some(location) = event.entity_metadata.and_then(|metadata| metadata.location) else {
+                continue
+            };
+            let Some(broadcast_id) = location.strip_prefix("https://www.youtube.com/watch?v=") else {
+                continue
+            };
+            if self.store.get(broadcast_id).is_none() {
+                self.store.insert(broadcast_id.to_string(), SyncedEvent {
+                    event_id: event.id,
+                    status: event.status,
+                    name: event.name,
+                    scheduled_end_time: event.scheduled_end_time.unwrap_or_else(|| event.scheduled_start_time.clone()),
+                    scheduled_start_time: event.scheduled_start_time,
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn patch(&self, event_id: &str, patch: &ExternalScheduledEventPatchRequestPartial) -> Result<ExternalScheduledEventResponse, RestError> {
+        let request = Request::new(Method::PATCH, format!("/guilds/{}/scheduled-events/{event_id}", self.guild_id)).json(patch)?;
+        self.rest.send(request).await
+    }
+
+    async fn set_status(&mut self, broadcast_id: &str, status: i32) -> Result<(), SyncError> {
+        let Some(mut event) = self.store.get(broadcast_id).cloned() else {
+            return Ok(())
+        };
+        let patch = ExternalScheduledEventPatchRequestPartial {
+            status: Some(Some(status)),
+            ..Default::default()
+        };
+        self.patch(&event.event_id, &patch).await?;
+        event.status = status;
+        self.store.insert(broadcast_id.to_string(), event)
+    }
+
+    async fn apply(&mut self, action: Action) -> Result<(), SyncError> {
+        match action {
+            Action::Create { broadcast_id, name, start, end, description, go_live } => {
+                let create = ExternalScheduledEventCreateRequest {
+                    name: name.clone(),
+                    description: (!description.is_empty()).then_some(Some(description)),
+                    scheduled_start_time: start.clone(),
+                    scheduled_end_time: Some(Some(end.clone())),
+                    privacy_level: Some(json!(PRIVACY_LEVEL_GUILD_ONLY)),
+                    entity_type: Some(ENTITY_TYPE_EXTERNAL),
+                    entity_metadata: Box::new(EntityMetadataExternal { location: watch_url(&broadcast_id) }),
+                    ..Default::default()
+                };
+                let request = Request::new(Method::POST, format!("/guilds/{}/scheduled-events", self.guild_id))
+                    .json(&create)?
+                    .reason(Some("Mirroring YouTube broadcast"));
+                let created: ExternalScheduledEventResponse = self.rest.send(request).await?;
+
+                // Persist before anything else can fail so the event is never created twice.
+                self.store.insert(broadcast_id.clone(), SyncedEvent {
+                    event_id: created.id.into(),
+                    status: STATUS_SCHEDULED,
+                    name,
+                    scheduled_start_time: start,
+                    scheduled_end_time: end,
+                })?;
+                if go_live {
+                    self.set_status(&broadcast_id, STATUS_ACTIVE).await?;
+                }
+            }
+            Action::Reschedule { broadcast_id, name, times } => {
+                let Some(mut event) = self.store.get(&broadcast_id).cloned() else {
+                    return Ok(())
+                };
+                let mut patch = ExternalScheduledEventPatchRequestPartial {
+                    name: Some(name.clone()),
+                    ..Default::default()
+                };
+                if let Some((start, end)) = &times {
+                    patch.scheduled_start_time = Some(start.clone());
+                    patch.scheduled_end_time = Some(Some(end.clone()));
+                }
+                self.patch(&event.event_id, &patch).await?;
+                event.name = name;
+                if let Some((start, end)) = times {
+                    event.scheduled_start_time = start;
+                    event.scheduled_end_time = end;
+                }
+                self.store.insert(broadcast_id, event)?;
+            }
+            Action::Start { broadcast_id } => self.set_status(&broadcast_id, STATUS_ACTIVE).await?,
+            Action::Complete { broadcast_id } => self.set_status(&broadcast_id, STATUS_COMPLETED).await?,
+            Action::Cancel { broadcast_id } => self.set_status(&broadcast_id, STATUS_CANCELED).await?,
+        }
+
+        Ok(())
+    }
+
+    /// Brings the guild's events in line with `broadcasts`, which should hold every
+    /// upcoming and live broadcast of the channel. Safe to call repeatedly.
+    pub async fn sync(&mut self, broadcasts: &[LiveBroadcast]) -> Result<(), SyncError> {
+        for action in plan(&self.store, broadcasts, Utc::now()) {
+            self.apply(action).await?;
+        }
+        Ok(())
+    }
+
+    /// Fetches upcoming and active broadcasts every `interval` and syncs them.
+    pub async fn run(mut self, youtube_access_token: String, interval: tokio::time::Duration) {
+        let http = reqwest::Client::new();
+        if let Err(e) = self.adopt_existing().await {
+            eprintln!("Failed to adopt existing scheduled events: {}", e);
+        }
+
+        loop {
+            let broadcasts = async {
+                let mut broadcasts = youtube::fetch_broadcasts(&http, &youtube_access_token, "upcoming").await?;
+                broadcasts.extend(youtube::fetch_broadcasts(&http, &youtube_access_token, "active").await?);
+                Ok::<_, SyncError>(broadcasts)
+            };
+            let result = match broadcasts.await {
+                Ok(broadcasts) => self.sync(&broadcasts).await,
+                Err(e) => Err(e),
+            };
+            if let Err(e) = result {
+                eprintln!("Failed to sync scheduled events: {}", e);
+            }
+            tokio::time::sleep(interval).await;
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn broadcast(id: &str, status: &str, start: &str) -> LiveBroadcast {
+        serde_json::from_value(json!({
+            "kind": "youtube#liveBroadcast",
+            "etag": "e",
+            "id": id,
+            "snippet": {
+                "publishedAt": "2025-01-01T00:00:00Z",
+                "channelId": "UC1",
+                "title": "Stream",
+                "description": "",
+                "scheduledStartTime": start,
+                "isDefaultBroadcast": false,
+            },
+            "status": {
+                "lifeCycleStatus": status,
+                "privacyStatus": "public",
+                "recordingStatus": "notRecording",
+                "madeForKids": false,
+                "selfDeclaredMadeForKids": false,
+            },
+        }))
+        .unwrap()
+    }
+
+    fn synced(status: i32, start: &str) -> SyncedEvent {
+        SyncedEvent {
+            event_id: "event".to_string(),
+            status,
+            name: "Stream".to_string(),
+            scheduled_start_time: start.to_string(),
+            scheduled_end_time: "2025-01-10T20:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn plan_follows_broadcast_lifecycle() {
+        let now = parse_time("2025-01-05T12:00:00Z").unwrap();
+        let mut store = EventStore::in_memory();
+        store.events.insert("moved".to_string(), synced(STATUS_SCHEDULED, "2025-01-10T18:00:00Z"));
+        store.events.insert("unchanged".to_string(), synced(STATUS_SCHEDULED, "2025-01-10T18:00:00Z"));
+        store.events.insert("started".to_string(), synced(STATUS_SCHEDULED, "2025-01-05T11:00:00Z"));
+        store.events.insert("gone".to_string(), synced(STATUS_ACTIVE, "2025-01-04T11:00:00Z"));
+
+        let broadcasts = vec![
+            broadcast("new", "ready", "2025-01-06T18:00:00Z"),
+            broadcast("moved", "ready", "2025-01-11T18:00:00Z"),
+            broadcast("unchanged", "ready", "2025-01-10T18:00:00Z"),
+            broadcast("started", "live", "2025-01-05T11:00:00Z"),
+        ];
+        let mut actions = plan(&store, &broadcasts, now);
+        actions.sort_by_key(|action| format!("{action:?}"));
+
+        assert_eq!(actions, vec![
+            Action::Complete { broadcast_id: "gone".to_string() },
+            Action::Create {
+                broadcast_id: "new".to_string(),
+                name: "Stream".to_string(),
+                start: "2025-01-06T18:00:00Z".to_string(),
+                end: "2025-01-06T20:00:00Z".to_string(),
+                description: String::new(),
+                go_live: false,
+            },
+            Action::Reschedule {
+                broadcast_id: "moved".to_string(),
+                name: "Stream".to_string(),
+                times: Some(("2025-01-11T18:00:00Z".to_string(), "2025-01-11T20:00:00Z".to_string())),
+            },
+            Action::Start { broadcast_id: "started".to_string() },
+        ]);
+    }
+
+    #[test]
+    fn store_survives_restart() {
+        let path = std::env::temp_dir().join(format!("oozebot-events-{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let mut store = EventStore::open(&path).unwrap();
+        store.insert("abc".to_string(), synced(STATUS_SCHEDULED, "2025-01-10T18:00:00Z")).unwrap();
+
+        let reopened = EventStore::open(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(reopened.get("abc"), Some(&synced(STATUS_SCHEDULED, "2025-01-10T18:00:00Z")));
+
+        // Known, unchanged broadcasts produce no calls, so re-syncing never duplicates.
+        let now = parse_time("2025-01-05T12:00:00Z").unwrap();
+        assert!(plan(&reopened, &[broadcast("abc", "ready", "2025-01-10T18:00:00Z")], now).is_empty());
+    }
+}
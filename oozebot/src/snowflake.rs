@@ -0,0 +1,22 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+
+/// Milliseconds between the unix epoch and the first second of 2015.
+pub const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+
+/// Creation time encoded in a snowflake id, or `None` if `id` is not a snowflake.
+pub fn timestamp(id: &str) -> Option<SystemTime> {
+    let id = id.parse::<u64>().ok()?;
+    Some(UNIX_EPOCH + Duration::from_millis((id >> 22) + DISCORD_EPOCH))
+}
+
+/// The smallest snowflake that could have been created at `time`, usable as a
+/// `before`/`after` cursor.
+pub fn from_timestamp(time: SystemTime) -> String {
+    let millis = time
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+        .saturating_sub(DISCORD_EPOCH);
+    (millis << 22).to_string()
+}
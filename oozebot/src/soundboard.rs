@@ -0,0 +1,261 @@
+use std::{path::Path, process::Stdio};
+
+use oozebot_protocol::events::send::{GatewaySendEvent, RequestSoundboardSounds};
+use openapi::models::{
+    ListGuildSoundboardSoundsResponse, SoundboardCreateRequest, SoundboardPatchRequestPartial, SoundboardSoundResponse,
+    SoundboardSoundSendRequest,
+};
+use reqwest::Method;
+use rusqlite::{Connection, OpenFlags, OptionalExtension};
+use thiserror::Error;
+use tokio::{io::AsyncWriteExt, process::Command};
+
+use crate::{
+    cache::Cache,
+    rest::{attachments::Attachment, Request, RestClient, RestError},
+};
+
+
+/// Longest sound Discord accepts, in seconds.
+pub const MAX_SOUND_SECONDS: f64 = 5.2;
+pub const MAX_SOUND_BYTES: usize = 512 * 1024;
+
+#[derive(Debug, Error)]
+pub enum SoundboardError {
+    #[error(transparent)]
+    Rest(#[from] RestError),
+
+    #[error("TTS database error: {0}")]
+    Database(#[from] rusqlite::Error),
+
+    #[error("No TTS clip with id {0}")]
+    UnknownClip(i64),
+
+    #[error("Could not encode clip with ffmpeg: {0}")]
+    Encode(#[from] std::io::Error),
+
+    #[error("Encoded sound is {0} bytes, the limit is {MAX_SOUND_BYTES}")]
+    TooLarge(usize),
+
+    #[error("No sound named {0:?} in the guild's soundboard")]
+    UnknownSound(String),
+}
+
+/// Gateway command asking for the soundboards of `guild_ids`. Discord answers with
+/// one `SOUNDBOARD_SOUNDS` dispatch per guild, which `Cache::update` stores.
+pub fn request_soundboard_sounds(guild_ids: impl IntoIterator<Item = String>) -> GatewaySendEvent {
+    GatewaySendEvent::RequestSoundboardSounds(RequestSoundboardSounds {
+        guild_ids: guild_ids.into_iter().collect(),
+    })
+}
+
+impl RestClient {
+    pub async fn list_soundboard_sounds(&self, guild_id: &str) -> Result<Vec<SoundboardSoundResponse>, RestError> {
+        let request = Request::new(Method::GET, format!("/guilds/{guild_id}/soundboard-sounds"));
+        let response: ListGuildSoundboardSoundsResponse = self.send(request).await?;
+        Ok(response.items)
+    }
+
+    /// Uploads an MP3 or OGG sound of at most 512 KiB and 5.2 seconds.
+    pub async fn create_soundboard_sound(
+        &self,
+        guild_id: &str,
+        name: &str,
+        sound: Attachment,
+        volume: Option<f64>,
+        emoji_name: Option<&str>,
+        reason: Option<&str>,
+    ) -> Result<SoundboardSoundResponse, RestError> {
+        let body = SoundboardCreateRequest {
+            name: name.to_string(),
+            volume: volume.map(Some),
+            emoji_name: emoji_name.map(|emoji| Some(emoji.to_string())),
+            sound: sound.to_data_uri().await?,
+            ..Default::default()
+        };
+        let request = Request::new(Method::POST, format!("/guilds/{guild_id}/soundboard-sounds"))
+            .json(&body)?
+            .reason(reason);
+        self.send(request).await
+    }
+
+    pub async fn edit_soundboard_sound(&self, guild_id: &str, sound_id: &str, edit: &SoundboardPatchRequestPartial, reason: Option<&str>) -> Result<SoundboardSoundResponse, RestError> {
+        let request = Request::new(Method::PATCH, format!("/guilds/{guild_id}/soundboard-sounds/{sound_id}"))
+            .json(edit)?
+            .reason(reason);
+        self.send(request).await
+    }
+
+    pub async fn delete_soundboard_sound(&self, guild_id: &str, sound_id: &str, reason: Option<&str>) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("/guilds/{guild_id}/soundboard-sounds/{sound_id}"))
+            .reason(reason);
+        self.send(request).await
+    }
+
+    /// Plays a sound in the voice channel the bot is connected to. Sounds from another
+    /// guild need `source_guild_id`.
+    pub async fn send_soundboard_sound(&self, channel_id: &str, sound_id: &str, source_guild_id: Option<&str>) -> Result<(), RestError> {
+        let body = SoundboardSoundSendRequest {
+            sound_id: sound_id.into(),
+            source_guild_id: source_guild_id.map(Into::into),
+        };
+        let request = Request::new(Method::POST, format!("/channels/{channel_id}/send-soundboard-sound")).json(&body)?;
+        self.send(request).await
+    }
+
+    /// Plays a cached sound of `guild_id` by name in `channel_id`.
+    pub async fn play_sound_named(&self, cache: &Cache, guild_id: &str, channel_id: &str, name: &str) -> Result<(), SoundboardError> {
+        let sound = cache
+            .sound_named(guild_id, name)
+            .ok_or_else(|| SoundboardError::UnknownSound(name.to_string()))?;
+        Ok(self.send_soundboard_sound(channel_id, &sound.sound_id, sound.guild_id.as_deref()).await?)
+    }
+}
+
+/// A rendered TTS line as stored by `tts_magic`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TtsClip {
+    pub id: i64,
+    pub audio_wav: Vec<u8>,
+    pub created_at: String,
+}
+
+/// Read-only view of `tts_magic`'s `tts_entries` table.
+#[derive(Debug)]
+pub struct TtsClips {
+    conn: Connection,
+}
+
+impl TtsClips {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, SoundboardError> {
+        let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+        Ok(TtsClips { conn })
+    }
+
+    #[cfg(test)]
+    fn from_connection(conn: Connection) -> Self {
+        TtsClips { conn }
+    }
+
+    pub fn get(&self, id: i64) -> Result<TtsClip, SoundboardError> {
+        self.conn
+            .query_row(
+                "SELECT id, audio, created_at FROM tts_entries WHERE id = ?1",
+                [id],
+                |row| Ok(TtsClip { id: row.get(0)?, audio_wav: row.get(1)?, created_at: row.get(2)? }),
+            )
+            .optional()?
+            .ok_or(SoundboardError::UnknownClip(id))
+    }
+
+    /// Ids and creation times of the newest clips, newest first.
+    pub fn latest(&self, limit: usize) -> Result<Vec<(i64, String)>, SoundboardError> {
+        let mut statement = self.conn.prepare("SELECT id, created_at FROM tts_entries ORDER BY id DESC LIMIT ?1")?;
+        let rows = statement.query_map([limit as i64], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        Ok(rows.collect::<Result<_, _>>()?)
+    }
+}
+
+/// Converts a WAV clip to Opus in OGG, cut to the soundboard's length limit.
+/// Needs `ffmpeg` on the `PATH`.
+pub async fn encode_ogg(wav: &[u8]) -> Result<Vec<u8>, SoundboardError> {
+    let mut ffmpeg = Command::new("ffmpeg")
+        .args(["-hide_banner", "-loglevel", "error", "-f", "wav", "-i", "pipe:0"])
+        .args(["-t", &MAX_SOUND_SECONDS.to_string(), "-c:a", "libopus", "-b:a", "64k", "-f", "ogg", "pipe:1"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let mut stdin = ffmpeg.stdin.take().expect("stdin is piped");
+    let input = wav.to_vec();
+    let writer = tokio::spawn(async move {
+        stdin.write_all(&input).await?;
+        stdin.shutdown().await
+    });
+
+    let output = ffmpeg.wait_with_output().await?;
+    writer.await.map_err(std::io::Error::other)??;
+    if !output.status.success() {
+        return Err(std::io::Error::other(String::from_utf8_lossy(&output.stderr).into_owned()).into())
+    }
+    if output.stdout.len() > MAX_SOUND_BYTES {
+        return Err(SoundboardError::TooLarge(output.stdout.len()))
+    }
+
+    Ok(output.stdout)
+}
+
+/// Uploads TTS clip `clip_id` to a guild's soundboard.
+pub async fn upload_tts_clip(
+    rest: &RestClient,
+    clips: &TtsClips,
+    guild_id: &str,
+    clip_id: i64,
+    name: &str,
+    emoji_name: Option<&str>,
+) -> Result<SoundboardSoundResponse, SoundboardError> {
+    let clip = clips.get(clip_id)?;
+    let sound = Attachment::from_bytes(format!("tts-{clip_id}.ogg"), encode_ogg(&clip.audio_wav).await?);
+
+    let reason = format!("TTS clip {clip_id}");
+    Ok(rest.create_soundboard_sound(guild_id, name, sound, None, emoji_name, Some(&reason)).await?)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use oozebot_protocol::events::receive::Dispatch;
+    use serde_json::json;
+
+    use crate::events::DispatchEvent;
+
+    use super::*;
+
+    #[test]
+    fn tts_clips_read_from_database() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE tts_entries (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                alignment TEXT NOT NULL,
+                audio BLOB NOT NULL,
+                created_at TEXT DEFAULT CURRENT_TIMESTAMP
+            );
+            INSERT INTO tts_entries (alignment, audio, created_at) VALUES ('{}', x'52494646', '2025-01-01');
+            INSERT INTO tts_entries (alignment, audio, created_at) VALUES ('{}', x'00', '2025-01-02');",
+        )
+        .unwrap();
+        let clips = TtsClips::from_connection(conn);
+
+        assert_eq!(clips.get(1).unwrap().audio_wav, b"RIFF");
+        assert_eq!(clips.latest(1).unwrap(), vec![(2, "2025-01-02".to_string())]);
+        assert!(matches!(clips.get(3), Err(SoundboardError::UnknownClip(3))));
+    }
+
+    #[test]
+    fn soundboard_events_update_cache() {
+        let sound = |id: &str, name: &str| json!({
+            "name": name,
+            "sound_id": id,
+            "volume": 1.0,
+            "emoji_id": null,
+            "emoji_name": null,
+            "guild_id": "1",
+            "available": true,
+        });
+        let event = |t: &str, d: serde_json::Value| {
+            DispatchEvent::try_from(Dispatch { op: 0, d, s: None, t: Some(t.to_string()) }).unwrap()
+        };
+        let mut cache = Cache::new();
+        assert!(cache.sounds("1").is_none());
+
+        cache.update(&event("SOUNDBOARD_SOUNDS", json!({ "guild_id": "1", "soundboard_sounds": [sound("10", "Airhorn")] })));
+        cache.update(&event("GUILD_SOUNDBOARD_SOUND_CREATE", sound("11", "Laugh")));
+        cache.update(&event("GUILD_SOUNDBOARD_SOUND_DELETE", json!({ "guild_id": "1", "sound_id": "10" })));
+
+        assert_eq!(cache.sounds("1").unwrap().count(), 1);
+        assert_eq!(cache.sound_named("1", "laugh").unwrap().sound_id, "11");
+        assert!(cache.sound_named("1", "airhorn").is_none());
+    }
+}
@@ -0,0 +1,428 @@
+use std::collections::{HashMap, HashSet};
+
+use futures::{Stream, StreamExt};
+use openapi::models::{
+    BaseCreateMessageCreateRequest, CreateForumThreadRequest, CreateTextThreadWithMessageRequest,
+    CreateTextThreadWithoutMessageRequest, CreatedThreadResponse, GuildChannelResponse, MessageCreateRequest,
+    ThreadResponse, ThreadsResponse, UpdateThreadRequestPartial,
+};
+use reqwest::Method;
+use thiserror::Error;
+use tokio::time::Duration;
+
+use crate::{
+    embeds::{Colour, EmbedBuilder},
+    events::DispatchEvent,
+    rest::{attachments::{describe_attachments, Attachment}, Request, RestClient, RestError},
+    youtube::{self, livestream::LiveBroadcast},
+};
+
+
+pub const ANNOUNCEMENT_THREAD: i32 = 10;
+pub const PUBLIC_THREAD: i32 = 11;
+pub const PRIVATE_THREAD: i32 = 12;
+
+/// Minutes of inactivity after which Discord archives a thread.
+pub const ARCHIVE_AFTER_HOUR: i32 = 60;
+pub const ARCHIVE_AFTER_DAY: i32 = 1440;
+pub const ARCHIVE_AFTER_THREE_DAYS: i32 = 4320;
+pub const ARCHIVE_AFTER_WEEK: i32 = 10080;
+
+const MAX_THREAD_NAME: usize = 100;
+
+#[derive(Debug, Error)]
+pub enum ThreadError {
+    #[error(transparent)]
+    Rest(#[from] RestError),
+
+    #[error("Forum has no tag named {0:?}")]
+    UnknownTag(String),
+}
+
+/// Resolves forum tag names (case-insensitive) to the ids `applied_tags` expects.
+pub fn forum_tag_ids(forum: &GuildChannelResponse, names: &[&str]) -> Result<Vec<String>, ThreadError> {
+    let tags = forum.available_tags.as_deref().unwrap_or_default();
+    names
+        .iter()
+        .map(|name| {
+            tags.iter()
+                .find(|tag| tag.name.eq_ignore_ascii_case(name))
+                .map(|tag| tag.id.to_string())
+                .ok_or_else(|| ThreadError::UnknownTag(name.to_string()))
+        })
+        .collect()
+}
+
+fn thread_name(name: &str) -> String {
+    name.chars().take(MAX_THREAD_NAME).collect()
+}
+
+impl RestClient {
+    /// Starts a thread without a starter message, public unless `kind` says otherwise.
+    pub async fn start_thread(&self, channel_id: &str, mut thread: CreateTextThreadWithoutMessageRequest, reason: Option<&str>) -> Result<CreatedThreadResponse, RestError> {
+        thread.name = thread_name(&thread.name);
+        let request = Request::new(Method::POST, format!("/channels/{channel_id}/threads"))
+            .json(&thread)?
+            .reason(reason);
+        self.send(request).await
+    }
+
+    pub async fn start_thread_from_message(&self, channel_id: &str, message_id: &str, name: &str, auto_archive_duration: Option<i32>) -> Result<ThreadResponse, RestError> {
+        let body = CreateTextThreadWithMessageRequest {
+            name: thread_name(name),
+            auto_archive_duration,
+            ..Default::default()
+        };
+        let request = Request::new(Method::POST, format!("/channels/{channel_id}/messages/{message_id}/threads")).json(&body)?;
+        self.send(request).await
+    }
+
+    /// Opens a forum post with `tag_ids` applied, see `forum_tag_ids`.
+    pub async fn create_forum_post(
+        &self,
+        forum_id: &str,
+        name: &str,
+        tag_ids: Vec<String>,
+        mut message: BaseCreateMessageCreateRequest,
+        files: Vec<Attachment>,
+    ) -> Result<CreatedThreadResponse, RestError> {
+        let request = Request::new(Method::POST, format!("/channels/{forum_id}/threads"));
+        if !files.is_empty() {
+            describe_attachments(&mut message.attachments, &files);
+        }
+        let post = CreateForumThreadRequest {
+            name: thread_name(name),
+            applied_tags: (!tag_ids.is_empty()).then(|| Some(tag_ids.into_iter().map(Into::into).collect())),
+            message: Box::new(message),
+            ..Default::default()
+        };
+
+        if files.is_empty() {
+            return self.send(request.json(&post)?).await
+        }
+        let mut request = request.payload_json(&post)?;
+        for (index, file) in files.into_iter().enumerate() {
+            request = request.file(&format!("files[{index}]"), file);
+        }
+        self.send(request).await
+    }
+
+    pub async fn edit_thread(&self, thread_id: &str, edit: &UpdateThreadRequestPartial, reason: Option<&str>) -> Result<ThreadResponse, RestError> {
+        let request = Request::new(Method::PATCH, format!("/channels/{thread_id}"))
+            .json(edit)?
+            .reason(reason);
+        self.send(request).await
+    }
+
+    /// Archives a thread, optionally locking it so only moderators can unarchive it.
+    pub async fn archive_thread(&self, thread_id: &str, locked: bool, reason: Option<&str>) -> Result<ThreadResponse, RestError> {
+        let edit = UpdateThreadRequestPartial {
+            archived: Some(Some(true)),
+            locked: Some(Some(locked)),
+            ..Default::default()
+        };
+        self.edit_thread(thread_id, &edit, reason).await
+    }
+
+    pub async fn unarchive_thread(&self, thread_id: &str, reason: Option<&str>) -> Result<ThreadResponse, RestError> {
+        let edit = UpdateThreadRequestPartial {
+            archived: Some(Some(false)),
+            ..Default::default()
+        };
+        self.edit_thread(thread_id, &edit, reason).await
+    }
+
+    pub async fn join_thread(&self, thread_id: &str) -> Result<(), RestError> {
+        self.send(Request::new(Method::PUT, format!("/channels/{thread_id}/thread-members/@me"))).await
+    }
+
+    pub async fn leave_thread(&self, thread_id: &str) -> Result<(), RestError> {
+        self.send(Request::new(Method::DELETE, format!("/channels/{thread_id}/thread-members/@me"))).await
+    }
+
+    pub async fn add_thread_member(&self, thread_id: &str, user_id: &str) -> Result<(), RestError> {
+        self.send(Request::new(Method::PUT, format!("/channels/{thread_id}/thread-members/{user_id}"))).await
+    }
+
+    pub async fn remove_thread_member(&self, thread_id: &str, user_id: &str) -> Result<(), RestError> {
+        self.send(Request::new(Method::DELETE, format!("/channels/{thread_id}/thread-members/{user_id}"))).await
+    }
+
+    /// Every active thread in the guild the bot can see, joined or not.
+    pub async fn list_active_threads(&self, guild_id: &str) -> Result<ThreadsResponse, RestError> {
+        self.send(Request::new(Method::GET, format!("/guilds/{guild_id}/threads/active"))).await
+    }
+}
+
+/// Reacts to thread events: joins new threads so the bot receives their messages, and
+/// unarchives threads that should stay open when Discord auto-archives them.
+#[derive(Debug)]
+pub struct ThreadWatcher {
+    rest: RestClient,
+    auto_join: bool,
+    parents: Option<HashSet<String>>,
+    keep_alive: HashSet<String>,
+}
+
+impl ThreadWatcher {
+    pub fn new(rest: RestClient) -> Self {
+        ThreadWatcher {
+            rest,
+            auto_join: true,
+            parents: None,
+            keep_alive: HashSet::new(),
+        }
+    }
+
+    pub fn auto_join(mut self, auto_join: bool) -> Self {
+        self.auto_join = auto_join;
+        self
+    }
+
+    /// Only join threads started in these channels.
+    pub fn only_in(mut self, channel_ids: impl IntoIterator<Item = String>) -> Self {
+        self.parents = Some(channel_ids.into_iter().collect());
+        self
+    }
+
+    pub fn keep_alive(&mut self, thread_id: impl Into<String>) {
+        self.keep_alive.insert(thread_id.into());
+    }
+
+    pub fn release(&mut self, thread_id: &str) {
+        self.keep_alive.remove(thread_id);
+    }
+
+    fn watches(&self, thread: &ThreadResponse) -> bool {
+        match (&self.parents, &thread.parent_id) {
+            (None, _) => true,
+            (Some(parents), Some(parent)) => parents.contains(parent.as_str()),
+            (Some(_), None) => false,
+        }
+    }
+
+    pub async fn handle(&mut self, event: &DispatchEvent) -> Result<(), RestError> {
+        match event {
+            DispatchEvent::ThreadCreate(create) => {
+                let thread = &create.thread;
+                if self.auto_join && create.newly_created && thread.member.is_none() && self.watches(thread) {
+                    self.rest.join_thread(&thread.id).await?;
+                }
+            }
+            DispatchEvent::ThreadUpdate(thread) => {
+                let metadata = &thread.thread_metadata;
+                if metadata.archived && !metadata.locked && self.keep_alive.contains(thread.id.as_str()) {
+                    self.rest.unarchive_thread(&thread.id, Some("Thread is kept open")).await?;
+                }
+            }
+            DispatchEvent::ThreadDelete(delete) => {
+                self.keep_alive.remove(&delete.id);
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    pub async fn follow<S>(mut self, events: S)
+    where
+        S: Stream<Item = DispatchEvent>,
+    {
+        tokio::pin!(events);
+        while let Some(event) = events.next().await {
+            if let Err(e) = self.handle(&event).await {
+                eprintln!("Failed to handle thread event: {}", e);
+            }
+        }
+    }
+}
+
+/// Where `StreamThreads` opens its discussions.
+#[derive(Debug, Clone)]
+enum StreamChannel {
+    Text(String),
+    Forum { channel_id: String, tag_ids: Vec<String> },
+}
+
+/// Opens a discussion thread when a livestream goes live and archives it when the
+/// stream ends.
+#[derive(Debug)]
+pub struct StreamThreads {
+    rest: RestClient,
+    channel: StreamChannel,
+    /// Broadcast id to thread id.
+    open: HashMap<String, String>,
+}
+
+/// Which broadcasts need a thread opened, and which threads should be closed.
+fn plan<'a>(open: &HashMap<String, String>, live: &'a [LiveBroadcast]) -> (Vec<&'a LiveBroadcast>, Vec<String>) {
+    let to_open = live
+        .iter()
+        .filter(|broadcast| broadcast.status.lifeCycleStatus == "live" && !open.contains_key(&broadcast.id))
+        .collect();
+    let to_close = open
+        .keys()
+        .filter(|id| !live.iter().any(|broadcast| &broadcast.id == *id && broadcast.status.lifeCycleStatus == "live"))
+        .cloned()
+        .collect();
+
+    (to_open, to_close)
+}
+
+fn announcement(broadcast: &LiveBroadcast) -> EmbedBuilder {
+    let snippet = &broadcast.snippet;
+    let mut embed = EmbedBuilder::new()
+        .title(snippet.title.chars().take(crate::embeds::MAX_TITLE).collect::<String>())
+        .url(format!("https://www.youtube.com/watch?v={}", broadcast.id))
+        .description(snippet.description.chars().take(crate::embeds::MAX_DESCRIPTION).collect::<String>())
+        .colour(Colour::RED);
+    if let Some(thumbnail) = snippet.thumbnails.high.as_ref().or(snippet.thumbnails.default_thumb.as_ref()) {
+        embed = embed.image(thumbnail.url.clone());
+    }
+    embed
+}
+
+impl StreamThreads {
+    /// Posts an announcement in a text channel and starts the thread from it.
+    pub fn in_channel(rest: RestClient, channel_id: impl Into<String>) -> Self {
+        StreamThreads {
+            rest,
+            channel: StreamChannel::Text(channel_id.into()),
+            open: HashMap::new(),
+        }
+    }
+
+    /// Opens each stream as a forum post with `tag_ids` applied.
+    pub fn in_forum(rest: RestClient, forum_id: impl Into<String>, tag_ids: Vec<String>) -> Self {
+        StreamThreads {
+            rest,
+            channel: StreamChannel::Forum { channel_id: forum_id.into(), tag_ids },
+            open: HashMap::new(),
+        }
+    }
+
+    pub fn thread_for(&self, broadcast_id: &str) -> Option<&str> {
+        self.open.get(broadcast_id).map(String::as_str)
+    }
+
+    pub async fn open(&mut self, broadcast: &LiveBroadcast) -> Result<String, RestError> {
+        let name = &broadcast.snippet.title;
+        let embed = announcement(broadcast).build().unwrap_or_default();
+
+        let thread_id: String = match &self.channel {
+            StreamChannel::Text(channel_id) => {
+                let message = MessageCreateRequest {
+                    embeds: Some(Some(vec![embed])),
+                    ..Default::default()
+                };
+                let message = self.rest.create_message(channel_id, message, Vec::new()).await?;
+                self.rest.start_thread_from_message(channel_id, &message.id, name, Some(ARCHIVE_AFTER_DAY)).await?.id.into()
+            }
+            StreamChannel::Forum { channel_id, tag_ids } => {
+                let message = BaseCreateMessageCreateRequest {
+                    embeds: Some(Some(vec![embed])),
+                    ..Default::default()
+                };
+                self.rest.create_forum_post(channel_id, name, tag_ids.clone(), message, Vec::new()).await?.id.into()
+            }
+        };
+
+        self.open.insert(broadcast.id.clone(), thread_id.clone());
+        Ok(thread_id)
+    }
+
+    pub async fn close(&mut self, broadcast_id: &str) -> Result<(), RestError> {
+        if let Some(thread_id) = self.open.remove(broadcast_id) {
+            self.rest.archive_thread(&thread_id, false, Some("Stream ended")).await?;
+        }
+        Ok(())
+    }
+
+    /// Opens threads for newly live broadcasts and closes those no longer live.
+    pub async fn sync(&mut self, broadcasts: &[LiveBroadcast]) -> Result<(), RestError> {
+        let (to_open, to_close) = plan(&self.open, broadcasts);
+        for broadcast in to_open {
+            self.open(broadcast).await?;
+        }
+        for broadcast_id in to_close {
+            self.close(&broadcast_id).await?;
+        }
+        Ok(())
+    }
+
+    /// Polls the channel's active broadcasts every `interval` and syncs threads with them.
+    pub async fn run(mut self, youtube_access_token: String, interval: Duration) {
+        let http = reqwest::Client::new();
+        loop {
+            match youtube::fetch_broadcasts(&http, &youtube_access_token, "active").await {
+                Ok(broadcasts) => {
+                    if let Err(e) = self.sync(&broadcasts).await {
+                        eprintln!("Failed to sync stream threads: {}", e);
+                    }
+                }
+                Err(e) => eprintln!("Failed to fetch broadcasts: {}", e),
+            }
+            tokio::time::sleep(interval).await;
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use openapi::models::ForumTagResponse;
+    use serde_json::json;
+
+    use super::*;
+
+    fn broadcast(id: &str, status: &str) -> LiveBroadcast {
+        serde_json::from_value(json!({
+            "kind": "youtube#liveBroadcast",
+            "etag": "e",
+            "id": id,
+            "snippet": {
+                "publishedAt": "2025-01-01T00:00:00Z",
+                "channelId": "UC1",
+                "title": "Stream",
+                "description": "",
+                "isDefaultBroadcast": false,
+            },
+            "status": {
+                "lifeCycleStatus": status,
+                "privacyStatus": "public",
+                "recordingStatus": "recording",
+                "madeForKids": false,
+                "selfDeclaredMadeForKids": false,
+            },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn threads_follow_broadcast_lifecycle() {
+        let open = HashMap::from([
+            ("ended".to_string(), "1".to_string()),
+            ("still-live".to_string(), "2".to_string()),
+        ]);
+        let broadcasts = vec![broadcast("still-live", "live"), broadcast("new", "live"), broadcast("soon", "testing")];
+
+        let (to_open, to_close) = plan(&open, &broadcasts);
+
+        assert_eq!(to_open.iter().map(|b| b.id.as_str()).collect::<Vec<_>>(), vec!["new"]);
+        assert_eq!(to_close, vec!["ended".to_string()]);
+    }
+
+    #[test]
+    fn forum_tags_resolve_by_name() {
+        let forum = GuildChannelResponse {
+            available_tags: Some(vec![
+                ForumTagResponse { id: "1".into(), name: "Stream".to_string(), ..Default::default() },
+                ForumTagResponse { id: "2".into(), name: "Art".to_string(), ..Default::default() },
+            ]),
+            ..Default::default()
+        };
+
+        assert_eq!(forum_tag_ids(&forum, &["art", "stream"]).unwrap(), vec!["2", "1"]);
+        assert!(matches!(forum_tag_ids(&forum, &["music"]), Err(ThreadError::UnknownTag(name)) if name == "music"));
+    }
+}
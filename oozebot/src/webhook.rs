@@ -0,0 +1,344 @@
+use std::collections::{HashMap, VecDeque};
+
+use futures::{Stream, StreamExt};
+use openapi::models::{
+    IncomingWebhookRequestPartial, IncomingWebhookUpdateRequestPartial, MessageAllowedMentionsRequest, MessageResponse,
+};
+use reqwest::Method;
+use thiserror::Error;
+
+use crate::{
+    embeds::{Colour, EmbedBuilder},
+    rest::{attachments::{describe_attachments, Attachment}, Request, RestClient, RestError},
+    youtube::livechatmessages::LiveChatMessage,
+};
+
+
+const MAX_USERNAME: usize = 80;
+/// How many relayed messages are remembered so YouTube deletions can be mirrored.
+const RELAY_HISTORY: usize = 1000;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("Not a Discord webhook url: {0}")]
+pub struct InvalidWebhookUrl(pub String);
+
+/// Executes and edits messages through an incoming webhook, authenticated by the
+/// token in its url rather than a bot token.
+#[derive(Debug, Clone)]
+pub struct WebhookClient {
+    rest: RestClient,
+    id: String,
+    token: String,
+}
+
+/// Splits `https://discord.com/api/webhooks/{id}/{token}` into its id and token.
+fn parse_webhook_url(url: &str) -> Option<(String, String)> {
+    let path = url.split(['?', '#']).next()?;
+    let mut segments = path.split('/').skip_while(|segment| *segment != "webhooks").skip(1);
+    let id = segments.next().filter(|id| !id.is_empty() && id.bytes().all(|b| b.is_ascii_digit()))?;
+    let token = segments.next().filter(|token| !token.is_empty())?;
+
+    Some((id.to_string(), token.to_string()))
+}
+
+impl WebhookClient {
+    pub fn new(url: &str) -> Result<Self, InvalidWebhookUrl> {
+        Self::with_client(&RestClient::new(""), url)
+    }
+
+    /// Shares `rest`'s rate limit buckets and base url. Its bot token is not sent.
+    pub fn with_client(rest: &RestClient, url: &str) -> Result<Self, InvalidWebhookUrl> {
+        let (id, token) = parse_webhook_url(url).ok_or_else(|| InvalidWebhookUrl(url.to_string()))?;
+
+        Ok(WebhookClient {
+            rest: rest.without_token(),
+            id,
+            token,
+        })
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn path(&self) -> String {
+        format!("/webhooks/{}/{}", self.id, self.token)
+    }
+
+    fn in_thread(request: Request, thread_id: Option<&str>) -> Request {
+        match thread_id {
+            Some(thread_id) => request.query("thread_id", thread_id),
+            None => request,
+        }
+    }
+
+    /// Executes the webhook and waits for the created message. In a forum channel
+    /// set `thread_name` to start a new post, or pass `thread_id` to reply in one.
+    pub async fn execute(&self, mut message: IncomingWebhookRequestPartial, thread_id: Option<&str>, files: Vec<Attachment>) -> Result<MessageResponse, RestError> {
+        let request = Request::new(Method::POST, self.path()).query("wait", true);
+        let request = Self::in_thread(request, thread_id);
+
+        if files.is_empty() {
+            return self.rest.send(request.json(&message)?).await
+        }
+
+        describe_attachments(&mut message.attachments, &files);
+        let mut request = request.payload_json(&message)?;
+        for (index, file) in files.into_iter().enumerate() {
+            request = request.file(&format!("files[{index}]"), file);
+        }
+
+        self.rest.send(request).await
+    }
+
+    /// Posts plain text under a custom name and avatar with mentions disabled.
+    pub async fn send_as(&self, content: &str, username: &str, avatar_url: Option<&str>, thread_id: Option<&str>) -> Result<MessageResponse, RestError> {
+        let message = IncomingWebhookRequestPartial {
+            content: Some(Some(content.to_string())),
+            username: Some(Some(sanitize_username(username))),
+            avatar_url: avatar_url.map(|url| Some(url.to_string())),
+            allowed_mentions: Some(Some(Box::new(no_mentions()))),
+            ..Default::default()
+        };
+
+        self.execute(message, thread_id, Vec::new()).await
+    }
+
+    pub async fn get_message(&self, message_id: &str, thread_id: Option<&str>) -> Result<MessageResponse, RestError> {
+        let request = Request::new(Method::GET, format!("{}/messages/{message_id}", self.path()));
+        self.rest.send(Self::in_thread(request, thread_id)).await
+    }
+
+    pub async fn edit_message(&self, message_id: &str, message: &IncomingWebhookUpdateRequestPartial, thread_id: Option<&str>) -> Result<MessageResponse, RestError> {
+        let request = Request::new(Method::PATCH, format!("{}/messages/{message_id}", self.path())).json(message)?;
+        self.rest.send(Self::in_thread(request, thread_id)).await
+    }
+
+    pub async fn delete_message(&self, message_id: &str, thread_id: Option<&str>) -> Result<(), RestError> {
+        let request = Request::new(Method::DELETE, format!("{}/messages/{message_id}", self.path()));
+        self.rest.send(Self::in_thread(request, thread_id)).await
+    }
+}
+
+fn no_mentions() -> MessageAllowedMentionsRequest {
+    MessageAllowedMentionsRequest {
+        parse: Some(Some(Vec::new())),
+        ..Default::default()
+    }
+}
+
+/// Webhook usernames are 1-80 characters and may not contain "discord" or "clyde".
+/// Words are broken up before truncating, so the inserted spaces count toward the limit.
+fn sanitize_username(name: &str) -> String {
+    let mut name = name.trim().to_string();
+    if name.is_empty() {
+        return "Anonymous".to_string()
+    }
+
+    for word in ["discord", "clyde"] {
+        // ASCII lowercasing keeps byte offsets, so indices carry over to `name`.
+        while let Some(index) = name.to_ascii_lowercase().find(word) {
+            name.insert(index + 1, '\u{200B}');
+        }
+    }
+
+    name.chars().take(MAX_USERNAME).collect()
+}
+
+/// What a YouTube chat message turns into on the Discord side.
+#[derive(Debug, Clone, PartialEq)]
+enum Relayed {
+    Post(Box<IncomingWebhookRequestPartial>),
+    Delete(String),
+}
+
+fn relayed(message: &LiveChatMessage) -> Option<Relayed> {
+    let snippet = &message.snippet;
+    let author = &message.authorDetails;
+
+    let (content, embed) = match snippet.type_field.as_str() {
+        "textMessageEvent" => (snippet.displayMessage.clone(), None),
+        "messageDeletedEvent" => {
+            let deleted = snippet.messageDeletedDetails.as_ref()?;
+            return Some(Relayed::Delete(deleted.deletedMessageId.clone()))
+        }
+        "superChatEvent" => {
+            let details = snippet.superChatDetails.as_ref()?;
+            let embed = EmbedBuilder::new()
+                .title(format!("Super Chat {}", details.amountDisplayString))
+                .description(details.userComment.clone())
+                .colour(Colour::YELLOW);
+            (String::new(), Some(embed))
+        }
+        "superStickerEvent" => {
+            let details = snippet.superStickerDetails.as_ref()?;
+            let embed = EmbedBuilder::new()
+                .title(format!("Super Sticker {}", details.amountDisplayString))
+                .description(details.superStickerMetadata.altText.clone())
+                .colour(Colour::YELLOW);
+            (String::new(), Some(embed))
+        }
+        "newSponsorEvent" | "memberMilestoneChatEvent" | "membershipGiftingEvent" | "giftMembershipReceivedEvent" => {
+            let embed = EmbedBuilder::new()
+                .description(snippet.displayMessage.clone())
+                .colour(Colour::GREEN);
+            (String::new(), Some(embed))
+        }
+        _ => return None,
+    };
+
+    let mut message = IncomingWebhookRequestPartial {
+        username: Some(Some(sanitize_username(&author.displayName))),
+        avatar_url: Some(Some(author.profileImageUrl.clone())),
+        allowed_mentions: Some(Some(Box::new(no_mentions()))),
+        ..Default::default()
+    };
+    if !content.is_empty() {
+        message.content = Some(Some(content));
+    }
+    if let Some(embed) = embed {
+        message.embeds = Some(Some(vec![embed.build_split().ok()?.remove(0)]));
+    }
+
+    Some(Relayed::Post(Box::new(message)))
+}
+
+/// Mirrors a YouTube live chat into a Discord channel, posting each message under
+/// the chatter's name and avatar and removing it again when YouTube deletes it.
+#[derive(Debug)]
+pub struct ChatRelay {
+    webhook: WebhookClient,
+    thread_id: Option<String>,
+    relayed: HashMap<String, String>,
+    order: VecDeque<String>,
+}
+
+impl ChatRelay {
+    pub fn new(webhook: WebhookClient) -> Self {
+        ChatRelay {
+            webhook,
+            thread_id: None,
+            relayed: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    /// Posts into a thread of the webhook's channel instead.
+    pub fn in_thread(mut self, thread_id: impl Into<String>) -> Self {
+        self.thread_id = Some(thread_id.into());
+        self
+    }
+
+    pub async fn relay(&mut self, message: &LiveChatMessage) -> Result<(), RestError> {
+        match relayed(message) {
+            Some(Relayed::Post(post)) => {
+                let sent = self.webhook.execute(*post, self.thread_id.as_deref(), Vec::new()).await?;
+                self.remember(message.id.clone(), sent.id.into());
+            }
+            Some(Relayed::Delete(youtube_id)) => {
+                if let Some(discord_id) = self.relayed.remove(&youtube_id) {
+                    self.order.retain(|id| *id != youtube_id);
+                    self.webhook.delete_message(&discord_id, self.thread_id.as_deref()).await?;
+                }
+            }
+            None => {}
+        }
+
+        Ok(())
+    }
+
+    fn remember(&mut self, youtube_id: String, discord_id: String) {
+        if self.order.len() == RELAY_HISTORY
+            && let Some(oldest) = self.order.pop_front()
+        {
+            self.relayed.remove(&oldest);
+        }
+        self.order.push_back(youtube_id.clone());
+        self.relayed.insert(youtube_id, discord_id);
+    }
+
+    /// Relays `messages` until the stream ends. Failed messages are logged and skipped.
+    pub async fn run<S>(mut self, messages: S)
+    where
+        S: Stream<Item = LiveChatMessage>,
+    {
+        tokio::pin!(messages);
+        while let Some(message) = messages.next().await {
+            if let Err(e) = self.relay(&message).await {
+                eprintln!("Failed to relay chat message {}: {}", message.id, e);
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn chat_message(kind: &str, details: serde_json::Value) -> LiveChatMessage {
+        let mut snippet = json!({
+            "type": kind,
+            "liveChatId": "chat",
+            "authorChannelId": "UC1",
+            "publishedAt": "2025-01-01T00:00:00Z",
+            "hasDisplayContent": true,
+            "displayMessage": "hello @everyone",
+        });
+        snippet.as_object_mut().unwrap().extend(details.as_object().unwrap().clone());
+
+        serde_json::from_value(json!({
+            "kind": "youtube#liveChatMessage",
+            "etag": "e",
+            "id": "yt1",
+            "snippet": snippet,
+            "authorDetails": {
+                "channelId": "UC1",
+                "channelUrl": "https://youtube.com/channel/UC1",
+                "displayName": "@DiscordFan",
+                "profileImageUrl": "https://yt3.ggpht.com/avatar",
+                "isVerified": false,
+                "isChatOwner": false,
+                "isChatSponsor": false,
+                "isChatModerator": false,
+            },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn parses_webhook_urls() {
+        assert_eq!(
+            parse_webhook_url("https://discord.com/api/v10/webhooks/123/abc-DEF?wait=true"),
+            Some(("123".to_string(), "abc-DEF".to_string())),
+        );
+        assert_eq!(parse_webhook_url("https://discord.com/api/webhooks/abc/def"), None);
+        assert!(WebhookClient::new("https://example.com").is_err());
+    }
+
+    #[test]
+    fn chat_messages_impersonate_author() {
+        let Some(Relayed::Post(post)) = relayed(&chat_message("textMessageEvent", json!({}))) else {
+            panic!("text message should be posted");
+        };
+        assert_eq!(post.content, Some(Some("hello @everyone".to_string())));
+        assert_eq!(post.username, Some(Some("@D\u{200B}iscordFan".to_string())));
+        assert_eq!(post.avatar_url, Some(Some("https://yt3.ggpht.com/avatar".to_string())));
+        assert_eq!(post.allowed_mentions.flatten().unwrap().parse, Some(Some(vec![])));
+
+        let deleted = chat_message("messageDeletedEvent", json!({
+            "messageDeletedDetails": { "deletedMessageId": "yt0" },
+        }));
+        assert_eq!(relayed(&deleted), Some(Relayed::Delete("yt0".to_string())));
+    }
+
+    #[test]
+    fn long_usernames_stay_within_the_limit() {
+        let name = format!("{}discord", "a".repeat(73));
+        let sanitized = sanitize_username(&name);
+        assert_eq!(sanitized.chars().count(), MAX_USERNAME);
+        assert!(sanitized.ends_with("d\u{200B}iscor"));
+    }
+}
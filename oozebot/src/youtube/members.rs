@@ -0,0 +1,45 @@
+#![allow(non_snake_case)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MemberListResponse {
+    pub kind: String,
+    pub etag: Option<String>,
+    pub nextPageToken: Option<String>,
+    pub items: Vec<Member>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Member {
+    pub kind: String,
+    pub etag: String,
+    pub snippet: MemberSnippet,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemberSnippet {
+    pub creatorChannelId: String,
+    pub memberDetails: MemberDetails,
+    pub membershipsDetails: MembershipsDetails,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemberDetails {
+    pub channelId: String,
+    pub channelUrl: String,
+    pub displayName: String,
+    pub profileImageUrl: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MembershipsDetails {
+    pub highestAccessibleLevel: String,
+    pub highestAccessibleLevelDisplayName: String,
+    pub membershipsDuration: MembershipsDuration,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MembershipsDuration {
+    pub memberSince: String,
+    pub memberTotalDurationMonths: u32,
+}
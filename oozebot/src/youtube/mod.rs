@@ -0,0 +1,163 @@
+use chrono::{DateTime, Utc};
+use reqwest::StatusCode;
+use tokio::{sync::mpsc, task, time::Duration};
+
+use crate::youtube::{
+    livechatmessages::{LiveChatMessage, LiveChatMessageListResponse},
+    livestream::{LiveBroadcast, LiveBroadcastListResponse},
+    members::{Member, MemberListResponse},
+};
+
+pub mod livechatmessages;
+pub mod livestream;
+pub mod members;
+
+
+const YOUTUBE_API_URL: &str = "https://www.googleapis.com/youtube/v3";
+const MIN_RETRY_DELAY: Duration = Duration::from_secs(1);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+pub async fn fetch_live_chat_messages(
+    http: &reqwest::Client,
+    access_token: &str,
+    live_chat_id: &str,
+    page_token: Option<&str>,
+) -> Result<LiveChatMessageListResponse, reqwest::Error> {
+    let mut request = http
+        .get(format!("{YOUTUBE_API_URL}/liveChat/messages"))
+        .bearer_auth(access_token)
+        .query(&[("part", "snippet,authorDetails"), ("liveChatId", live_chat_id)]);
+    if let Some(page_token) = page_token {
+        request = request.query(&[("pageToken", page_token)]);
+    }
+
+    request
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await
+}
+
+/// Like `fetch_live_chat_messages`, but retries failed requests with exponential
+/// backoff. Returns `None` once the chat is gone, which YouTube answers with 403
+/// or 404.
+pub async fn poll_live_chat_messages(
+    http: &reqwest::Client,
+    access_token: &str,
+    live_chat_id: &str,
+    page_token: Option<&str>,
+) -> Option<LiveChatMessageListResponse> {
+    let mut delay = MIN_RETRY_DELAY;
+    loop {
+        match fetch_live_chat_messages(http, access_token, live_chat_id, page_token).await {
+            Ok(response) => return Some(response),
+            Err(e) if matches!(e.status(), Some(StatusCode::FORBIDDEN | StatusCode::NOT_FOUND)) => {
+                eprintln!("Live chat is gone: {}", e);
+                return None
+            }
+            Err(e) => {
+                eprintln!("Error fetching chat messages, retrying in {:?}: {}", delay, e);
+                tokio::time::sleep(delay).await;
+                delay = (delay * 2).min(MAX_RETRY_DELAY);
+            }
+        }
+    }
+}
+
+/// Broadcasts of the channel `access_token` belongs to, across every page.
+/// `broadcast_status` is one of `all`, `upcoming`, `active` or `completed`.
+pub async fn fetch_broadcasts(
+    http: &reqwest::Client,
+    access_token: &str,
+    broadcast_status: &str,
+) -> Result<Vec<LiveBroadcast>, reqwest::Error> {
+    let mut broadcasts = Vec::new();
+    let mut page_token: Option<String> = None;
+
+    loop {
+        let mut request = http
+            .get(format!("{YOUTUBE_API_URL}/liveBroadcasts"))
+            .bearer_auth(access_token)
+            .query(&[
+                ("part", "id,snippet,status,contentDetails"),
+                ("broadcastStatus", broadcast_status),
+                ("broadcastType", "all"),
+                ("maxResults", "50"),
+            ]);
+        if let Some(page_token) = &page_token {
+            request = request.query(&[("pageToken", page_token)]);
+        }
+
+        let response: LiveBroadcastListResponse = request
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        broadcasts.extend(response.items);
+        match response.nextPageToken {
+            Some(next) if !next.is_empty() => page_token = Some(next),
+            _ => return Ok(broadcasts),
+        }
+    }
+}
+
+/// Looks up a viewer's current membership of the channel `access_token` belongs to.
+/// Only the channel owner may list its members.
+pub async fn fetch_membership(
+    http: &reqwest::Client,
+    access_token: &str,
+    member_channel_id: &str,
+) -> Result<Option<Member>, reqwest::Error> {
+    let response: MemberListResponse = http
+        .get(format!("{YOUTUBE_API_URL}/members"))
+        .bearer_auth(access_token)
+        .query(&[("part", "snippet"), ("mode", "all_current"), ("filterByMemberChannelId", member_channel_id)])
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    Ok(response.items.into_iter().find(|member| member.snippet.memberDetails.channelId == member_channel_id))
+}
+
+/// Polls a live chat and forwards messages published after the call, honouring the
+/// polling interval YouTube asks for. The stream ends when the chat goes offline.
+pub fn spawn_live_chat_stream(access_token: String, live_chat_id: String) -> mpsc::Receiver<LiveChatMessage> {
+    let (tx, rx) = mpsc::channel(100);
+
+    task::spawn(async move {
+        let http = reqwest::Client::new();
+        let mut next_page_token: Option<String> = None;
+        let cutoff_time = Utc::now();
+
+        loop {
+            let Some(response) = poll_live_chat_messages(&http, &access_token, &live_chat_id, next_page_token.as_deref()).await else {
+                break
+            };
+
+            for item in response.items {
+                let published = DateTime::parse_from_rfc3339(&item.snippet.publishedAt);
+                if !published.is_ok_and(|time| time.with_timezone(&Utc) >= cutoff_time) {
+                    continue
+                }
+                if tx.send(item).await.is_err() {
+                    return
+                }
+            }
+
+            if response.offlineAt.is_some() {
+                break
+            }
+
+            next_page_token = response.nextPageToken;
+            let interval = response.pollingIntervalMillis.unwrap_or(5000);
+            tokio::time::sleep(Duration::from_millis(interval)).await;
+        }
+    });
+
+    rx
+}